@@ -0,0 +1,185 @@
+//! Supported building blocks for writing custom JSON matchers.
+//!
+//! Everything re-exported here is covered by semver: breaking changes only happen in a major
+//! release. The `__internal_unstable_do_not_depend_on_these` module used by the macros is not,
+//! and custom matchers should never name it.
+//!
+//! A custom matcher implements googletest's [`Matcher<&Value>`](googletest::matcher::Matcher)
+//! (deriving [`MatcherBase`](googletest::matcher::MatcherBase)) plus [`JsonMatcher`]. That is
+//! enough for it to be accepted anywhere a JSON matcher is: as a field in
+//! [`pat!`](crate::json::pat), an element in [`elements_are!`](crate::json::elements_are), the
+//! inner matcher of [`optional!`](crate::json::optional), and so on.
+//!
+//! [`JsonMatcher`] has two optional hooks:
+//! - [`allows_missing`](JsonMatcher::allows_missing): return `true` to let
+//!   [`pat!`](crate::json::pat) accept the field being absent from the object. The matcher is
+//!   not called in that case.
+//! - [`collect_mismatches`](JsonMatcher::collect_mismatches): composite matchers can forward to
+//!   their children so that [`check`](crate::json::check) reports failures at the children's
+//!   paths.
+//!
+//! Patterns are `Clone`, and `Send + Sync` whenever every matcher placed in them is, so they
+//! can be shared across threads. Matchers that are not thread-safe, such as googletest's
+//! `starts_with`, are accepted too; the pattern holding them just stays on its own thread.
+//!
+//! To match raw JSON text or bytes, or values inside `Option`, `Result`, `Map` and `Vec`, wrap
+//! any JSON matcher, custom ones included, in [`parsed`](crate::json::parsed).
+//!
+//! For simple rules, [`JsonPredicateMatcher`] builds a matcher from a closure, and
+//! [`testkit`] helps assert on the descriptions a matcher produces.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use googletest::description::Description;
+//! use googletest::matcher::{Matcher, MatcherBase, MatcherResult};
+//! use googletest::prelude::*;
+//! use googletest_json_serde::extension::{JsonMatcher, describe_json_type};
+//! use googletest_json_serde::json as j;
+//! use serde_json::{Value, json};
+//!
+//! /// Matches money amounts written as `"<units>.<cents>"` strings.
+//! #[derive(MatcherBase)]
+//! struct IsMoney;
+//!
+//! impl Matcher<&Value> for IsMoney {
+//!     fn matches(&self, actual: &Value) -> MatcherResult {
+//!         actual
+//!             .as_str()
+//!             .and_then(|s| s.split_once('.'))
+//!             .is_some_and(|(units, cents)| {
+//!                 units.parse::<u64>().is_ok() && cents.len() == 2 && cents.parse::<u8>().is_ok()
+//!             })
+//!             .into()
+//!     }
+//!
+//!     fn describe(&self, result: MatcherResult) -> Description {
+//!         match result {
+//!             MatcherResult::Match => "is a money amount".into(),
+//!             MatcherResult::NoMatch => "isn't a money amount".into(),
+//!         }
+//!     }
+//!
+//!     fn explain_match(&self, actual: &Value) -> Description {
+//!         match actual {
+//!             Value::String(s) => format!("which is {s:?}, not `<units>.<cents>`").into(),
+//!             other => describe_json_type(other),
+//!         }
+//!     }
+//! }
+//!
+//! impl JsonMatcher for IsMoney {}
+//!
+//! assert_that!(
+//!     json!({"price": "12.50", "refunds": ["1.00"], "tip": null}),
+//!     j::pat!({
+//!         "price": IsMoney,
+//!         "refunds": j::elements_are![IsMoney],
+//!         "tip": j::optional!(IsMoney),
+//!     })
+//! );
+//! ```
+//!
+//! A composite matcher forwards [`collect_mismatches`](JsonMatcher::collect_mismatches) to its
+//! children, and falls back to [`report_mismatch`] when no child is to blame:
+//!
+//! ```rust
+//! use googletest::description::Description;
+//! use googletest::matcher::{Matcher, MatcherBase, MatcherResult};
+//! use googletest::prelude::*;
+//! use googletest_json_serde::extension::{JsonMatcher, JsonMismatch, PathSegment, report_mismatch};
+//! use googletest_json_serde::json as j;
+//! use serde_json::{Value, json};
+//!
+//! /// Matches an array whose first element matches the inner matcher.
+//! #[derive(MatcherBase)]
+//! struct First<M>(M);
+//!
+//! impl<M: JsonMatcher> Matcher<&Value> for First<M> {
+//!     fn matches(&self, actual: &Value) -> MatcherResult {
+//!         actual.get(0).map_or(MatcherResult::NoMatch, |first| self.0.matches(first))
+//!     }
+//!
+//!     fn describe(&self, result: MatcherResult) -> Description {
+//!         format!("has a first element which {}", self.0.describe(result)).into()
+//!     }
+//!
+//!     fn explain_match(&self, actual: &Value) -> Description {
+//!         match actual.get(0) {
+//!             Some(first) => format!("whose first element {}", self.0.explain_match(first)).into(),
+//!             None => "which has no first element".into(),
+//!         }
+//!     }
+//! }
+//!
+//! impl<M: JsonMatcher> JsonMatcher for First<M> {
+//!     fn collect_mismatches(
+//!         &self,
+//!         actual: &Value,
+//!         path: &mut Vec<PathSegment>,
+//!         out: &mut Vec<JsonMismatch>,
+//!     ) {
+//!         match actual.get(0) {
+//!             Some(first) => {
+//!                 path.push(PathSegment::Index(0));
+//!                 self.0.collect_mismatches(first, path, out);
+//!                 path.pop();
+//!             }
+//!             None => report_mismatch(self, actual, path, out),
+//!         }
+//!     }
+//! }
+//!
+//! let pattern = j::pat!({"ids": First(j::pat!({"id": gt(0)})), "tags": First(j::is_string())});
+//! let report = j::check(&json!({"ids": [{"id": 0}], "tags": []}), &pattern);
+//! let paths = report.mismatches().iter().map(|m| m.path_string()).collect::<Vec<_>>();
+//! assert_eq!(paths, ["ids.0.id", "tags"]);
+//! ```
+
+pub use crate::matchers::__internal_unstable_do_not_depend_on_these::{
+    JsonMatcher, JsonPredicateMatcher, NoDescription, PredicateDescription, describe_json_type,
+};
+pub use crate::report::{JsonMismatch, PathSegment, report_mismatch};
+
+/// The matcher built by [`pat!`](crate::json::pat), nameable so a pattern can be stored in a
+/// `static LazyLock` and reused by reference.
+pub use crate::matchers::__internal_unstable_do_not_depend_on_these::JsonObjectMatcher;
+
+/// Helpers for unit-testing custom matchers without going through `assert_that!`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use googletest_json_serde::extension::testkit;
+/// # use googletest_json_serde::json as j;
+/// # use serde_json::json;
+/// let matcher = j::is_string();
+/// assert!(testkit::matches(&matcher, &json!("a")));
+/// assert_eq!(testkit::describe_match(&matcher), "a JSON string");
+/// assert_eq!(testkit::explain(&matcher, &json!(1)), "which is a JSON number");
+/// ```
+pub mod testkit {
+    use super::JsonMatcher;
+    use googletest::matcher::MatcherResult;
+    use serde_json::Value;
+
+    /// Returns true if `matcher` matches `actual`.
+    pub fn matches<M: JsonMatcher + ?Sized>(matcher: &M, actual: &Value) -> bool {
+        matcher.matches(actual).is_match()
+    }
+
+    /// Renders what `matcher` expects, as shown after `Expected:` in a failure.
+    pub fn describe_match<M: JsonMatcher + ?Sized>(matcher: &M) -> String {
+        matcher.describe(MatcherResult::Match).to_string()
+    }
+
+    /// Renders the negated expectation, as shown when the matcher is wrapped in `not(...)`.
+    pub fn describe_no_match<M: JsonMatcher + ?Sized>(matcher: &M) -> String {
+        matcher.describe(MatcherResult::NoMatch).to_string()
+    }
+
+    /// Renders why `actual` does or does not match, as shown after `Actual:` in a failure.
+    pub fn explain<M: JsonMatcher + ?Sized>(matcher: &M, actual: &Value) -> String {
+        matcher.explain_match(actual).to_string()
+    }
+}
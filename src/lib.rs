@@ -5,19 +5,25 @@ pub mod matcher_support;
 #[doc(hidden)]
 pub mod matchers;
 
+pub mod extension;
+pub mod report;
+
 pub mod json {
     #[allow(deprecated)]
     #[doc(inline)]
     pub use super::matchers::{
-        any_value, as_array, as_bool, as_f64, as_i8, as_i16, as_i32, as_i64, as_object, as_string,
-        as_u8, as_u16, as_u32, as_u64, as_usize, contains_each, each, each_is_array,
-        each_is_boolean, each_is_null, each_is_number, each_is_object, each_is_string,
-        elements_are, has_only_paths, has_path_with, has_paths, is_array, is_boolean,
-        is_contained_in, is_empty_array, is_empty_object, is_empty_string, is_false,
-        is_fractional_number, is_integer, is_non_empty_array, is_non_empty_object,
-        is_non_empty_string, is_not_null, is_null, is_number, is_object, is_string, is_true,
-        is_whole_number, len, matches_pattern, optional, pat, predicate, primitive,
-        unordered_elements_are, value,
+        JsonOptions, absent, all_keys, all_leaves, any_value, approx_eq_json, as_array, as_bool,
+        as_f64, as_i8, as_i16, as_i32, as_i64, as_object, as_string, as_u8, as_u16, as_u32, as_u64,
+        as_usize, at, check, contains_each, descendants_matching, deserializes_as, each,
+        each_is_array, each_is_boolean, each_is_null, each_is_number, each_is_object,
+        each_is_string, elements_are, eq_json, eq_json_unordered, has_descendant, has_only_paths,
+        has_path_with, has_paths, is_array, is_boolean, is_contained_in, is_empty_array,
+        is_empty_object, is_empty_string, is_false, is_fractional_number, is_integer,
+        is_non_empty_array, is_non_empty_object, is_non_empty_string, is_not_null, is_null,
+        is_number, is_object, is_string, is_true, is_whole_number, len, matches_pattern,
+        missing_or, no_descendant, no_key_anywhere, nullable, num_eq, optional, options, parsed,
+        pat, pointer, predicate, present_null, primitive, recursive, rest, round_trips, serialized,
+        tagged, unordered_elements_are, value, with_options,
     };
 }
 
@@ -25,12 +31,15 @@ pub mod json {
 #[cfg(doc)]
 #[doc(inline)]
 pub use json::{
-    any_value, as_array, as_bool, as_f64, as_i8, as_i16, as_i32, as_i64, as_object, as_string,
-    as_u8, as_u16, as_u32, as_u64, as_usize, contains_each, each, each_is_array, each_is_boolean,
-    each_is_null, each_is_number, each_is_object, each_is_string, elements_are, has_only_paths,
-    has_path_with, has_paths, is_array, is_boolean, is_contained_in, is_empty_array,
-    is_empty_object, is_empty_string, is_false, is_fractional_number, is_integer,
-    is_non_empty_array, is_non_empty_object, is_non_empty_string, is_not_null, is_null, is_number,
-    is_object, is_string, is_true, is_whole_number, len, matches_pattern, optional, pat, predicate,
-    primitive, unordered_elements_are, value,
+    JsonOptions, absent, all_keys, all_leaves, any_value, approx_eq_json, as_array, as_bool,
+    as_f64, as_i8, as_i16, as_i32, as_i64, as_object, as_string, as_u8, as_u16, as_u32, as_u64,
+    as_usize, at, check, contains_each, descendants_matching, deserializes_as, each, each_is_array,
+    each_is_boolean, each_is_null, each_is_number, each_is_object, each_is_string, elements_are,
+    eq_json, eq_json_unordered, has_descendant, has_only_paths, has_path_with, has_paths, is_array,
+    is_boolean, is_contained_in, is_empty_array, is_empty_object, is_empty_string, is_false,
+    is_fractional_number, is_integer, is_non_empty_array, is_non_empty_object, is_non_empty_string,
+    is_not_null, is_null, is_number, is_object, is_string, is_true, is_whole_number, len,
+    matches_pattern, missing_or, no_descendant, no_key_anywhere, nullable, num_eq, optional,
+    options, parsed, pat, pointer, predicate, present_null, primitive, recursive, rest,
+    round_trips, serialized, tagged, unordered_elements_are, value, with_options,
 };
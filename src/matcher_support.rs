@@ -1,3 +1,7 @@
 pub(crate) mod count_elements;
+pub(crate) mod diff;
+pub(crate) mod json_input;
+pub(crate) mod json_path;
 pub(crate) mod match_matrix;
+pub(crate) mod number;
 pub(crate) mod path;
@@ -0,0 +1,261 @@
+use crate::matcher_support::path::{PathSegment, format_path};
+use googletest::description::Description;
+use serde_json::Value;
+
+const SNIPPET_LIMIT: usize = 80;
+
+/// A single structural difference between an expected and an actual JSON document.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Difference<'a> {
+    /// The path exists in the actual document but not in the expected one.
+    Added {
+        path: Vec<PathSegment>,
+        actual: &'a Value,
+    },
+    /// The path exists in the expected document but not in the actual one.
+    Removed {
+        path: Vec<PathSegment>,
+        expected: &'a Value,
+    },
+    /// The path exists in both documents but holds different values.
+    Changed {
+        path: Vec<PathSegment>,
+        expected: &'a Value,
+        actual: &'a Value,
+    },
+}
+
+impl Difference<'_> {
+    pub(crate) fn path(&self) -> &[PathSegment] {
+        match self {
+            Difference::Added { path, .. }
+            | Difference::Removed { path, .. }
+            | Difference::Changed { path, .. } => path,
+        }
+    }
+
+    pub(crate) fn render(&self) -> String {
+        match self {
+            Difference::Added { path, actual } => {
+                format!("added {}: actual {}", display_path(path), snippet(actual))
+            }
+            Difference::Removed { path, expected } => {
+                format!(
+                    "removed {}: expected {}",
+                    display_path(path),
+                    snippet(expected)
+                )
+            }
+            Difference::Changed {
+                path,
+                expected,
+                actual,
+            } => format!(
+                "changed {}: expected {}, actual {}",
+                display_path(path),
+                snippet(expected),
+                snippet(actual)
+            ),
+        }
+    }
+}
+
+/// Computes the leaf-level differences between `expected` and `actual`, sorted by path.
+///
+/// Objects are compared key by key and arrays index by index. Any other pair of values
+/// (including a type change) is reported as a single `Changed` entry at that path.
+pub(crate) fn diff<'a>(expected: &'a Value, actual: &'a Value) -> Vec<Difference<'a>> {
+    diff_by(expected, actual, &|expected, actual| expected == actual)
+}
+
+/// Like [`diff`], but compares non-container values with `leaves_equal` instead of `==`.
+pub(crate) fn diff_by<'a>(
+    expected: &'a Value,
+    actual: &'a Value,
+    leaves_equal: &dyn Fn(&Value, &Value) -> bool,
+) -> Vec<Difference<'a>> {
+    let mut out = Vec::new();
+    diff_inner(expected, actual, leaves_equal, &mut Vec::new(), &mut out);
+    out.sort_by(|a, b| a.path().cmp(b.path()));
+    out
+}
+
+fn diff_inner<'a>(
+    expected: &'a Value,
+    actual: &'a Value,
+    leaves_equal: &dyn Fn(&Value, &Value) -> bool,
+    current: &mut Vec<PathSegment>,
+    out: &mut Vec<Difference<'a>>,
+) {
+    match (expected, actual) {
+        (Value::Object(expected_map), Value::Object(actual_map)) => {
+            for (key, expected_value) in expected_map {
+                current.push(PathSegment::Field(key.clone()));
+                match actual_map.get(key) {
+                    Some(actual_value) => {
+                        diff_inner(expected_value, actual_value, leaves_equal, current, out)
+                    }
+                    None => out.push(Difference::Removed {
+                        path: current.clone(),
+                        expected: expected_value,
+                    }),
+                }
+                current.pop();
+            }
+            for (key, actual_value) in actual_map {
+                if !expected_map.contains_key(key) {
+                    current.push(PathSegment::Field(key.clone()));
+                    out.push(Difference::Added {
+                        path: current.clone(),
+                        actual: actual_value,
+                    });
+                    current.pop();
+                }
+            }
+        }
+        (Value::Array(expected_arr), Value::Array(actual_arr)) => {
+            let longest = expected_arr.len().max(actual_arr.len());
+            for idx in 0..longest {
+                current.push(PathSegment::Index(idx));
+                match (expected_arr.get(idx), actual_arr.get(idx)) {
+                    (Some(e), Some(a)) => diff_inner(e, a, leaves_equal, current, out),
+                    (Some(e), None) => out.push(Difference::Removed {
+                        path: current.clone(),
+                        expected: e,
+                    }),
+                    (None, Some(a)) => out.push(Difference::Added {
+                        path: current.clone(),
+                        actual: a,
+                    }),
+                    (None, None) => unreachable!("index is below the longest length"),
+                }
+                current.pop();
+            }
+        }
+        _ => {
+            if !leaves_equal(expected, actual) {
+                out.push(Difference::Changed {
+                    path: current.clone(),
+                    expected,
+                    actual,
+                });
+            }
+        }
+    }
+}
+
+/// Renders a list of differences as a matcher explanation.
+pub(crate) fn describe_differences(differences: &[Difference<'_>]) -> Description {
+    describe_rendered_differences(
+        &differences
+            .iter()
+            .map(Difference::render)
+            .collect::<Vec<_>>(),
+    )
+}
+
+/// Renders already formatted difference lines as a matcher explanation.
+pub(crate) fn describe_rendered_differences(lines: &[String]) -> Description {
+    match lines {
+        [] => Description::new().text("which is equal to the expected JSON"),
+        [single] => Description::new().text(format!(
+            "which differs from the expected JSON at 1 path:\n  {single}"
+        )),
+        many => Description::new().text(format!(
+            "which differs from the expected JSON at {} paths:\n{}",
+            many.len(),
+            many.iter()
+                .map(|line| format!("  {line}"))
+                .collect::<Vec<_>>()
+                .join("\n")
+        )),
+    }
+}
+
+/// Formats a path for explanations, naming the document root explicitly.
+pub(crate) fn display_path(path: &[PathSegment]) -> String {
+    if path.is_empty() {
+        "(root)".to_string()
+    } else {
+        format!("`{}`", format_path(path))
+    }
+}
+
+/// Renders a compact, length-limited JSON snippet for explanations.
+pub(crate) fn snippet(value: &Value) -> String {
+    let rendered = value.to_string();
+    if rendered.chars().count() <= SNIPPET_LIMIT {
+        rendered
+    } else {
+        let truncated: String = rendered.chars().take(SNIPPET_LIMIT).collect();
+        format!("{truncated}…")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn f(name: &str) -> PathSegment {
+        PathSegment::Field(name.to_string())
+    }
+
+    #[test]
+    fn diff_is_empty_for_equal_documents() {
+        let value = json!({"a": [1, {"b": null}]});
+        assert!(diff(&value, &value.clone()).is_empty());
+    }
+
+    #[test]
+    fn diff_reports_added_removed_and_changed_leaves() {
+        let expected = json!({"user": {"id": 7, "name": "Ada"}, "tags": ["a"]});
+        let actual = json!({"user": {"name": "Bob"}, "tags": ["a", "b"], "extra": true});
+        let differences = diff(&expected, &actual);
+        assert_eq!(
+            differences,
+            vec![
+                Difference::Added {
+                    path: vec![f("extra")],
+                    actual: &json!(true),
+                },
+                Difference::Added {
+                    path: vec![f("tags"), PathSegment::Index(1)],
+                    actual: &json!("b"),
+                },
+                Difference::Removed {
+                    path: vec![f("user"), f("id")],
+                    expected: &json!(7),
+                },
+                Difference::Changed {
+                    path: vec![f("user"), f("name")],
+                    expected: &json!("Ada"),
+                    actual: &json!("Bob"),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_reports_type_change_at_container_path() {
+        let expected = json!({"a": {"b": 1}});
+        let actual = json!({"a": [1]});
+        let differences = diff(&expected, &actual);
+        assert_eq!(differences.len(), 1);
+        assert_eq!(differences[0].path(), &[f("a")]);
+    }
+
+    #[test]
+    fn snippet_truncates_long_values() {
+        let long = json!("x".repeat(200));
+        let rendered = snippet(&long);
+        assert!(rendered.ends_with('…'));
+        assert_eq!(rendered.chars().count(), SNIPPET_LIMIT + 1);
+    }
+
+    #[test]
+    fn display_path_names_root() {
+        assert_eq!(display_path(&[]), "(root)");
+        assert_eq!(display_path(&[f("a.b")]), r"`a\.b`");
+    }
+}
@@ -0,0 +1,156 @@
+use serde_json::{Map, Value};
+use std::borrow::Cow;
+use std::fmt::Debug;
+
+/// How many characters of the offending line are shown on each side of the caret.
+const SNIPPET_CONTEXT: usize = 40;
+
+/// An actual value that [`parsed`](crate::json::parsed) accepts in place of a
+/// `&serde_json::Value`: raw JSON text or bytes, or a common container of a `Value`.
+///
+/// Text and bytes are parsed, and maps and vectors of values are copied into a new `Value`,
+/// on every call; `Option` and `Result` lend the `Value` they hold.
+pub trait JsonInput: Copy + Debug {
+    /// The JSON value to match, or why there is none, e.g. "was None".
+    fn to_json(&self) -> Result<Cow<'_, Value>, String>;
+}
+
+impl JsonInput for &str {
+    fn to_json(&self) -> Result<Cow<'_, Value>, String> {
+        parse(self.as_bytes())
+    }
+}
+
+impl JsonInput for &String {
+    fn to_json(&self) -> Result<Cow<'_, Value>, String> {
+        parse(self.as_bytes())
+    }
+}
+
+impl JsonInput for &[u8] {
+    fn to_json(&self) -> Result<Cow<'_, Value>, String> {
+        parse(self)
+    }
+}
+
+impl<const N: usize> JsonInput for &[u8; N] {
+    fn to_json(&self) -> Result<Cow<'_, Value>, String> {
+        parse(self.as_slice())
+    }
+}
+
+impl JsonInput for &Vec<u8> {
+    fn to_json(&self) -> Result<Cow<'_, Value>, String> {
+        parse(self)
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl JsonInput for &bytes::Bytes {
+    fn to_json(&self) -> Result<Cow<'_, Value>, String> {
+        parse(self)
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl JsonInput for &bytes::BytesMut {
+    fn to_json(&self) -> Result<Cow<'_, Value>, String> {
+        parse(self)
+    }
+}
+
+impl JsonInput for &Option<Value> {
+    fn to_json(&self) -> Result<Cow<'_, Value>, String> {
+        self.as_ref().map(Cow::Borrowed).ok_or_else(was_none)
+    }
+}
+
+impl JsonInput for Option<&Value> {
+    fn to_json(&self) -> Result<Cow<'_, Value>, String> {
+        self.map(Cow::Borrowed).ok_or_else(was_none)
+    }
+}
+
+impl<E: Debug> JsonInput for &Result<Value, E> {
+    fn to_json(&self) -> Result<Cow<'_, Value>, String> {
+        match self {
+            Ok(value) => Ok(Cow::Borrowed(value)),
+            Err(err) => Err(format!("was Err({err:?})")),
+        }
+    }
+}
+
+/// Deep-clones the fields into a `Value::Object` each time it is matched.
+impl JsonInput for &Map<String, Value> {
+    fn to_json(&self) -> Result<Cow<'_, Value>, String> {
+        Ok(Cow::Owned(Value::Object((*self).clone())))
+    }
+}
+
+/// Deep-clones the elements into a `Value::Array` each time it is matched.
+impl JsonInput for &Vec<Value> {
+    fn to_json(&self) -> Result<Cow<'_, Value>, String> {
+        Ok(Cow::Owned(Value::Array(self.to_vec())))
+    }
+}
+
+/// Deep-clones the elements into a `Value::Array` each time it is matched.
+impl JsonInput for &[Value] {
+    fn to_json(&self) -> Result<Cow<'_, Value>, String> {
+        Ok(Cow::Owned(Value::Array(self.to_vec())))
+    }
+}
+
+fn was_none() -> String {
+    "was None".to_string()
+}
+
+fn parse(source: &[u8]) -> Result<Cow<'_, Value>, String> {
+    serde_json::from_slice(source)
+        .map(Cow::Owned)
+        .map_err(|err| explain_parse_error(source, &err))
+}
+
+/// Explains a parse error with its location and the offending line, e.g.
+///
+/// ```text
+/// which is not valid JSON: expected `:` at line 1, column 7
+///   {"id" 7}
+///         ^
+/// ```
+fn explain_parse_error(source: &[u8], err: &serde_json::Error) -> String {
+    let message = err.to_string();
+    let location = format!(" at line {} column {}", err.line(), err.column());
+    let message = message.strip_suffix(&location).unwrap_or(&message);
+    if err.line() == 0 {
+        return format!("which is not valid JSON: {message}");
+    }
+    format!(
+        "which is not valid JSON: {message} at line {}, column {}\n{}",
+        err.line(),
+        err.column(),
+        snippet(source, err.line(), err.column())
+    )
+}
+
+/// The given line of `source`, shortened around `column`, with a caret under that column.
+///
+/// `line` and `column` are 1-based as reported by serde_json, which counts columns in bytes.
+fn snippet(source: &[u8], line: usize, column: usize) -> String {
+    let text = source
+        .split(|&byte| byte == b'\n')
+        .nth(line - 1)
+        .unwrap_or_default();
+    let text = text.strip_suffix(b"\r").unwrap_or(text);
+    let caret_byte = column.saturating_sub(1).min(text.len());
+    let caret = String::from_utf8_lossy(&text[..caret_byte]).chars().count();
+    let chars = String::from_utf8_lossy(text).chars().collect::<Vec<_>>();
+
+    let start = caret.saturating_sub(SNIPPET_CONTEXT);
+    let end = (caret + SNIPPET_CONTEXT).min(chars.len());
+    let prefix = if start > 0 { "..." } else { "" };
+    let suffix = if end < chars.len() { "..." } else { "" };
+    let shown = chars[start..end].iter().collect::<String>();
+    let indent = " ".repeat(prefix.len() + caret - start);
+    format!("  {prefix}{shown}{suffix}\n  {indent}^")
+}
@@ -0,0 +1,761 @@
+//! A small JSONPath subset: child and bracket selectors, wildcards, recursive descent, array
+//! slices, unions, and simple filter predicates over the current node.
+
+use crate::matcher_support::path::PathSegment;
+use serde_json::Value;
+use std::cmp::Ordering;
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct JsonPath {
+    steps: Vec<Step>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Step {
+    Child(Selector),
+    Descendant(Selector),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Selector {
+    Name(String),
+    Index(i64),
+    Wildcard,
+    Slice {
+        start: Option<i64>,
+        end: Option<i64>,
+        step: i64,
+    },
+    Union(Vec<Selector>),
+    Filter(Filter),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Filter {
+    Or(Vec<Filter>),
+    And(Vec<Filter>),
+    Not(Box<Filter>),
+    Exists(Vec<PathSegment>),
+    Compare(Vec<PathSegment>, CompareOp, Value),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum CompareOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+/// A node selected by a query, with its concrete location in the document.
+pub(crate) type Node<'a> = (Vec<PathSegment>, &'a Value);
+
+impl JsonPath {
+    pub(crate) fn parse(query: &str) -> Result<Self, String> {
+        Parser {
+            query,
+            chars: query.char_indices().collect(),
+            pos: 0,
+        }
+        .parse()
+    }
+
+    /// Evaluates the query against `root`, returning the selected nodes in document order.
+    pub(crate) fn select<'a>(&self, root: &'a Value) -> Vec<Node<'a>> {
+        let mut nodes = vec![(Vec::new(), root)];
+        for step in &self.steps {
+            let mut next = Vec::new();
+            for (path, value) in nodes {
+                match step {
+                    Step::Child(selector) => apply(selector, path, value, &mut next),
+                    Step::Descendant(selector) => {
+                        let mut stack = vec![(path, value)];
+                        while let Some((path, value)) = stack.pop() {
+                            apply(selector, path.clone(), value, &mut next);
+                            for child in children(&path, value).into_iter().rev() {
+                                stack.push(child);
+                            }
+                        }
+                    }
+                }
+            }
+            nodes = next;
+        }
+        nodes
+    }
+}
+
+fn children<'a>(path: &[PathSegment], value: &'a Value) -> Vec<Node<'a>> {
+    let child = |segment: PathSegment| {
+        let mut path = path.to_vec();
+        path.push(segment);
+        path
+    };
+    match value {
+        Value::Object(map) => map
+            .iter()
+            .map(|(k, v)| (child(PathSegment::Field(k.clone())), v))
+            .collect(),
+        Value::Array(arr) => arr
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (child(PathSegment::Index(i)), v))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn apply<'a>(
+    selector: &Selector,
+    path: Vec<PathSegment>,
+    value: &'a Value,
+    out: &mut Vec<Node<'a>>,
+) {
+    let mut push = |segment: PathSegment, node: &'a Value| {
+        let mut path = path.clone();
+        path.push(segment);
+        out.push((path, node));
+    };
+    match (selector, value) {
+        (Selector::Name(name), Value::Object(map)) => {
+            if let Some(v) = map.get(name) {
+                push(PathSegment::Field(name.clone()), v);
+            }
+        }
+        (Selector::Index(idx), Value::Array(arr)) => {
+            if let Some(i) = normalize_index(*idx, arr.len()) {
+                push(PathSegment::Index(i), &arr[i]);
+            }
+        }
+        (Selector::Wildcard, _) => {
+            for (child_path, child) in children(&path, value) {
+                out.push((child_path, child));
+            }
+        }
+        (Selector::Slice { start, end, step }, Value::Array(arr)) => {
+            for i in slice_indices(*start, *end, *step, arr.len()) {
+                push(PathSegment::Index(i), &arr[i]);
+            }
+        }
+        (Selector::Union(selectors), _) => {
+            for selector in selectors {
+                apply(selector, path.clone(), value, out);
+            }
+        }
+        (Selector::Filter(filter), _) => {
+            for (child_path, child) in children(&path, value) {
+                if filter.test(child) {
+                    out.push((child_path, child));
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn normalize_index(idx: i64, len: usize) -> Option<usize> {
+    let len = i64::try_from(len).ok()?;
+    let idx = if idx < 0 { idx + len } else { idx };
+    (0..len).contains(&idx).then_some(idx as usize)
+}
+
+fn slice_indices(start: Option<i64>, end: Option<i64>, step: i64, len: usize) -> Vec<usize> {
+    let len = len as i64;
+    let clamp = |bound: i64, low: i64, high: i64| {
+        let bound = if bound < 0 { bound + len } else { bound };
+        bound.clamp(low, high)
+    };
+    let mut out = Vec::new();
+    if step > 0 {
+        let mut i = start.map_or(0, |s| clamp(s, 0, len));
+        let end = end.map_or(len, |e| clamp(e, 0, len));
+        while i < end {
+            out.push(i as usize);
+            let Some(next) = i.checked_add(step) else {
+                break;
+            };
+            i = next;
+        }
+    } else {
+        let mut i = start.map_or(len - 1, |s| clamp(s, -1, len - 1));
+        let end = end.map_or(-1, |e| clamp(e, -1, len - 1));
+        while i > end {
+            out.push(i as usize);
+            let Some(next) = i.checked_add(step) else {
+                break;
+            };
+            i = next;
+        }
+    }
+    out
+}
+
+impl Filter {
+    fn test(&self, node: &Value) -> bool {
+        match self {
+            Filter::Or(filters) => filters.iter().any(|f| f.test(node)),
+            Filter::And(filters) => filters.iter().all(|f| f.test(node)),
+            Filter::Not(filter) => !filter.test(node),
+            Filter::Exists(path) => lookup(node, path).is_some(),
+            Filter::Compare(path, op, expected) => {
+                lookup(node, path).is_some_and(|actual| compare(actual, *op, expected))
+            }
+        }
+    }
+}
+
+fn lookup<'a>(value: &'a Value, path: &[PathSegment]) -> Option<&'a Value> {
+    path.iter()
+        .try_fold(value, |current, segment| match (segment, current) {
+            (PathSegment::Field(name), Value::Object(map)) => map.get(name),
+            (PathSegment::Index(idx), Value::Array(arr)) => arr.get(*idx),
+            _ => None,
+        })
+}
+
+fn compare(actual: &Value, op: CompareOp, expected: &Value) -> bool {
+    let ordering = match (actual, expected) {
+        (Value::Number(a), Value::Number(b)) => a.as_f64().partial_cmp(&b.as_f64()),
+        (Value::String(a), Value::String(b)) => Some(a.cmp(b)),
+        (a, b) if a == b => Some(Ordering::Equal),
+        _ => None,
+    };
+    match op {
+        CompareOp::Eq => ordering == Some(Ordering::Equal),
+        CompareOp::Ne => ordering != Some(Ordering::Equal),
+        CompareOp::Lt => ordering == Some(Ordering::Less),
+        CompareOp::Le => matches!(ordering, Some(Ordering::Less | Ordering::Equal)),
+        CompareOp::Gt => ordering == Some(Ordering::Greater),
+        CompareOp::Ge => matches!(ordering, Some(Ordering::Greater | Ordering::Equal)),
+    }
+}
+
+/// Formats a concrete path as a normalized JSONPath, e.g. `$.orders[0]['a.b']`.
+pub(crate) fn format_json_path(path: &[PathSegment]) -> String {
+    let mut out = String::from("$");
+    for segment in path {
+        match segment {
+            PathSegment::Field(name) if is_identifier(name) => {
+                out.push('.');
+                out.push_str(name);
+            }
+            PathSegment::Field(name) => {
+                out.push_str("['");
+                out.push_str(&name.replace('\\', "\\\\").replace('\'', "\\'"));
+                out.push_str("']");
+            }
+            PathSegment::Index(idx) => out.push_str(&format!("[{idx}]")),
+        }
+    }
+    out
+}
+
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_alphabetic() || c == '_')
+        && chars.all(|c| c.is_alphanumeric() || c == '_' || c == '-')
+}
+
+struct Parser<'q> {
+    query: &'q str,
+    chars: Vec<(usize, char)>,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn error(&self, message: &str) -> String {
+        let offset = self
+            .chars
+            .get(self.pos)
+            .map_or(self.query.len(), |(i, _)| *i);
+        format!(
+            "Invalid JSONPath {:?}: {message} at position {offset}",
+            self.query
+        )
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).map(|(_, c)| *c)
+    }
+
+    fn peek_at(&self, ahead: usize) -> Option<char> {
+        self.chars.get(self.pos + ahead).map(|(_, c)| *c)
+    }
+
+    fn eat(&mut self, expected: char) -> bool {
+        if self.peek() == Some(expected) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), String> {
+        if self.eat(expected) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected '{expected}'")))
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    fn parse(mut self) -> Result<JsonPath, String> {
+        self.skip_whitespace();
+        self.expect('$')?;
+        let mut steps = Vec::new();
+        loop {
+            match self.peek() {
+                None => break,
+                Some('.') if self.peek_at(1) == Some('.') => {
+                    self.pos += 2;
+                    let selector = if self.peek() == Some('[') {
+                        self.parse_bracket()?
+                    } else {
+                        self.parse_dot_selector()?
+                    };
+                    steps.push(Step::Descendant(selector));
+                }
+                Some('.') => {
+                    self.pos += 1;
+                    steps.push(Step::Child(self.parse_dot_selector()?));
+                }
+                Some('[') => steps.push(Step::Child(self.parse_bracket()?)),
+                Some(c) if c.is_whitespace() => {
+                    self.skip_whitespace();
+                    if self.peek().is_some() {
+                        return Err(self.error("unexpected whitespace"));
+                    }
+                }
+                Some(_) => return Err(self.error("expected '.', '..' or '['")),
+            }
+        }
+        Ok(JsonPath { steps })
+    }
+
+    fn parse_dot_selector(&mut self) -> Result<Selector, String> {
+        if self.eat('*') {
+            return Ok(Selector::Wildcard);
+        }
+        let name = self.parse_identifier();
+        if name.is_empty() {
+            return Err(self.error("expected a member name"));
+        }
+        Ok(Selector::Name(name))
+    }
+
+    fn parse_identifier(&mut self) -> String {
+        let mut name = String::new();
+        while let Some(c) = self.peek() {
+            if c == '.' || c == '[' || c.is_whitespace() || "=!<>&|)".contains(c) {
+                break;
+            }
+            name.push(c);
+            self.pos += 1;
+        }
+        name
+    }
+
+    fn parse_bracket(&mut self) -> Result<Selector, String> {
+        self.expect('[')?;
+        self.skip_whitespace();
+        let selector = if self.eat('*') {
+            Selector::Wildcard
+        } else if self.peek() == Some('?') {
+            self.pos += 1;
+            self.skip_whitespace();
+            self.expect('(')?;
+            let filter = self.parse_or()?;
+            self.skip_whitespace();
+            self.expect(')')?;
+            Selector::Filter(filter)
+        } else {
+            let mut selectors = vec![self.parse_union_member()?];
+            self.skip_whitespace();
+            while self.eat(',') {
+                self.skip_whitespace();
+                selectors.push(self.parse_union_member()?);
+                self.skip_whitespace();
+            }
+            if selectors.len() == 1 {
+                selectors.remove(0)
+            } else {
+                Selector::Union(selectors)
+            }
+        };
+        self.skip_whitespace();
+        self.expect(']')?;
+        Ok(selector)
+    }
+
+    fn parse_union_member(&mut self) -> Result<Selector, String> {
+        match self.peek() {
+            Some('\'' | '"') => Ok(Selector::Name(self.parse_string()?)),
+            _ => {
+                let start = self.parse_optional_int()?;
+                if !self.eat(':') {
+                    return start
+                        .map(Selector::Index)
+                        .ok_or_else(|| self.error("expected an index, slice or quoted name"));
+                }
+                let end = self.parse_optional_int()?;
+                let step = if self.eat(':') {
+                    self.parse_optional_int()?.unwrap_or(1)
+                } else {
+                    1
+                };
+                if step == 0 {
+                    return Err(self.error("slice step must not be zero"));
+                }
+                Ok(Selector::Slice { start, end, step })
+            }
+        }
+    }
+
+    fn parse_optional_int(&mut self) -> Result<Option<i64>, String> {
+        self.skip_whitespace();
+        let mut digits = String::new();
+        if self.peek() == Some('-') {
+            digits.push('-');
+            self.pos += 1;
+        }
+        while let Some(c) = self.peek().filter(char::is_ascii_digit) {
+            digits.push(c);
+            self.pos += 1;
+        }
+        self.skip_whitespace();
+        match digits.as_str() {
+            "" => Ok(None),
+            "-" => Err(self.error("expected digits")),
+            _ => digits
+                .parse()
+                .map(Some)
+                .map_err(|_| self.error("index out of range")),
+        }
+    }
+
+    fn parse_string(&mut self) -> Result<String, String> {
+        let quote = self.peek().ok_or_else(|| self.error("expected a quote"))?;
+        self.pos += 1;
+        let mut out = String::new();
+        loop {
+            match self.peek() {
+                None => return Err(self.error("unterminated string")),
+                Some('\\') => {
+                    self.pos += 1;
+                    let escaped = self
+                        .peek()
+                        .ok_or_else(|| self.error("unterminated string"))?;
+                    out.push(escaped);
+                    self.pos += 1;
+                }
+                Some(c) if c == quote => {
+                    self.pos += 1;
+                    return Ok(out);
+                }
+                Some(c) => {
+                    out.push(c);
+                    self.pos += 1;
+                }
+            }
+        }
+    }
+
+    fn parse_or(&mut self) -> Result<Filter, String> {
+        let mut filters = vec![self.parse_and()?];
+        loop {
+            self.skip_whitespace();
+            if self.peek() == Some('|') && self.peek_at(1) == Some('|') {
+                self.pos += 2;
+                filters.push(self.parse_and()?);
+            } else {
+                break;
+            }
+        }
+        Ok(if filters.len() == 1 {
+            filters.remove(0)
+        } else {
+            Filter::Or(filters)
+        })
+    }
+
+    fn parse_and(&mut self) -> Result<Filter, String> {
+        let mut filters = vec![self.parse_predicate()?];
+        loop {
+            self.skip_whitespace();
+            if self.peek() == Some('&') && self.peek_at(1) == Some('&') {
+                self.pos += 2;
+                filters.push(self.parse_predicate()?);
+            } else {
+                break;
+            }
+        }
+        Ok(if filters.len() == 1 {
+            filters.remove(0)
+        } else {
+            Filter::And(filters)
+        })
+    }
+
+    fn parse_predicate(&mut self) -> Result<Filter, String> {
+        self.skip_whitespace();
+        if self.peek() == Some('!') && self.peek_at(1) != Some('=') {
+            self.pos += 1;
+            return Ok(Filter::Not(Box::new(self.parse_predicate()?)));
+        }
+        if self.eat('(') {
+            let inner = self.parse_or()?;
+            self.skip_whitespace();
+            self.expect(')')?;
+            return Ok(inner);
+        }
+        self.expect('@')?;
+        let path = self.parse_relative_path()?;
+        self.skip_whitespace();
+        let op = match (self.peek(), self.peek_at(1)) {
+            (Some('='), Some('=')) => Some((CompareOp::Eq, 2)),
+            (Some('!'), Some('=')) => Some((CompareOp::Ne, 2)),
+            (Some('<'), Some('=')) => Some((CompareOp::Le, 2)),
+            (Some('>'), Some('=')) => Some((CompareOp::Ge, 2)),
+            (Some('<'), _) => Some((CompareOp::Lt, 1)),
+            (Some('>'), _) => Some((CompareOp::Gt, 1)),
+            _ => None,
+        };
+        let Some((op, width)) = op else {
+            return Ok(Filter::Exists(path));
+        };
+        self.pos += width;
+        self.skip_whitespace();
+        let literal = self.parse_literal()?;
+        Ok(Filter::Compare(path, op, literal))
+    }
+
+    fn parse_relative_path(&mut self) -> Result<Vec<PathSegment>, String> {
+        let mut path = Vec::new();
+        loop {
+            match self.peek() {
+                Some('.') => {
+                    self.pos += 1;
+                    let name = self.parse_identifier();
+                    if name.is_empty() {
+                        return Err(self.error("expected a member name"));
+                    }
+                    path.push(PathSegment::Field(name));
+                }
+                Some('[') => {
+                    self.pos += 1;
+                    self.skip_whitespace();
+                    let segment = match self.peek() {
+                        Some('\'' | '"') => PathSegment::Field(self.parse_string()?),
+                        _ => {
+                            let idx = self
+                                .parse_optional_int()?
+                                .and_then(|i| usize::try_from(i).ok())
+                                .ok_or_else(|| self.error("expected a non-negative index"))?;
+                            PathSegment::Index(idx)
+                        }
+                    };
+                    self.skip_whitespace();
+                    self.expect(']')?;
+                    path.push(segment);
+                }
+                _ => return Ok(path),
+            }
+        }
+    }
+
+    fn parse_literal(&mut self) -> Result<Value, String> {
+        match self.peek() {
+            Some('\'' | '"') => Ok(Value::String(self.parse_string()?)),
+            _ => {
+                let start = self.pos;
+                while self
+                    .peek()
+                    .is_some_and(|c| c.is_ascii_alphanumeric() || "+-.".contains(c))
+                {
+                    self.pos += 1;
+                }
+                let token: String = self.chars[start..self.pos].iter().map(|(_, c)| c).collect();
+                match token.as_str() {
+                    "true" => Ok(Value::Bool(true)),
+                    "false" => Ok(Value::Bool(false)),
+                    "null" => Ok(Value::Null),
+                    _ => serde_json::from_str::<serde_json::Number>(&token)
+                        .map(Value::Number)
+                        .map_err(|_| {
+                            self.pos = start;
+                            self.error("expected a string, number, boolean or null literal")
+                        }),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn select(query: &str, value: &Value) -> Vec<(String, Value)> {
+        JsonPath::parse(query)
+            .unwrap()
+            .select(value)
+            .into_iter()
+            .map(|(path, v)| (format_json_path(&path), v.clone()))
+            .collect()
+    }
+
+    fn store() -> Value {
+        json!({
+            "orders": [
+                {"id": 1, "status": "open", "total": 10},
+                {"id": 2, "status": "closed", "total": 5},
+                {"id": 3, "status": "open", "total": 7, "note": "gift"}
+            ],
+            "owner": {"name": "Ada", "total": 1}
+        })
+    }
+
+    #[test]
+    fn selects_children_and_indices() {
+        let value = store();
+        assert_eq!(
+            select("$.owner.name", &value),
+            vec![("$.owner.name".into(), json!("Ada"))]
+        );
+        assert_eq!(
+            select("$['orders'][-1].id", &value),
+            vec![("$.orders[2].id".into(), json!(3))]
+        );
+    }
+
+    #[test]
+    fn selects_wildcards_and_unions() {
+        let value = store();
+        let ids: Vec<_> = select("$.orders[*].id", &value)
+            .into_iter()
+            .map(|(_, v)| v)
+            .collect();
+        assert_eq!(ids, vec![json!(1), json!(2), json!(3)]);
+        let picked: Vec<_> = select("$.orders[0,2].id", &value)
+            .into_iter()
+            .map(|(p, _)| p)
+            .collect();
+        assert_eq!(picked, vec!["$.orders[0].id", "$.orders[2].id"]);
+    }
+
+    #[test]
+    fn selects_recursive_descent_in_document_order() {
+        let value = store();
+        let totals: Vec<_> = select("$..total", &value)
+            .into_iter()
+            .map(|(p, _)| p)
+            .collect();
+        assert_eq!(
+            totals,
+            vec![
+                "$.orders[0].total",
+                "$.orders[1].total",
+                "$.orders[2].total",
+                "$.owner.total"
+            ]
+        );
+    }
+
+    #[test]
+    fn selects_slices() {
+        let value = json!([0, 1, 2, 3, 4, 5]);
+        let values = |q| -> Vec<Value> { select(q, &value).into_iter().map(|(_, v)| v).collect() };
+        assert_eq!(values("$[1:3]"), vec![json!(1), json!(2)]);
+        assert_eq!(values("$[:2]"), vec![json!(0), json!(1)]);
+        assert_eq!(values("$[-2:]"), vec![json!(4), json!(5)]);
+        assert_eq!(values("$[::2]"), vec![json!(0), json!(2), json!(4)]);
+        assert_eq!(values("$[::-2]"), vec![json!(5), json!(3), json!(1)]);
+    }
+
+    #[test]
+    fn slices_with_extreme_bounds_and_steps_do_not_overflow() {
+        let value = json!([0, 1, 2, 3, 4, 5]);
+        let values = |q| -> Vec<Value> { select(q, &value).into_iter().map(|(_, v)| v).collect() };
+        assert_eq!(values("$[1::9223372036854775807]"), vec![json!(1)]);
+        assert_eq!(values("$[4::-9223372036854775808]"), vec![json!(4)]);
+        assert_eq!(
+            values("$[-9223372036854775808:9223372036854775807:3]"),
+            vec![json!(0), json!(3)]
+        );
+        assert_eq!(
+            values("$[9223372036854775807:-9223372036854775808:-4]"),
+            vec![json!(5), json!(1)]
+        );
+    }
+
+    #[test]
+    fn selects_with_filters() {
+        let value = store();
+        let open: Vec<_> = select("$.orders[?(@.status == 'open')].total", &value)
+            .into_iter()
+            .map(|(_, v)| v)
+            .collect();
+        assert_eq!(open, vec![json!(10), json!(7)]);
+        let big_open: Vec<_> = select(
+            "$.orders[?(@.status == \"open\" && @.total > 8)].id",
+            &value,
+        )
+        .into_iter()
+        .map(|(_, v)| v)
+        .collect();
+        assert_eq!(big_open, vec![json!(1)]);
+        let with_note: Vec<_> = select("$.orders[?(@.note)].id", &value)
+            .into_iter()
+            .map(|(_, v)| v)
+            .collect();
+        assert_eq!(with_note, vec![json!(3)]);
+        let without_note: Vec<_> = select("$.orders[?(!@.note || @.id == 3)].id", &value)
+            .into_iter()
+            .map(|(_, v)| v)
+            .collect();
+        assert_eq!(without_note, vec![json!(1), json!(2), json!(3)]);
+    }
+
+    #[test]
+    fn filter_numbers_compare_across_representations() {
+        let value = json!([{"v": 1.0}, {"v": 2}]);
+        assert_eq!(select("$[?(@.v == 1)]", &value).len(), 1);
+        assert_eq!(select("$[?(@.v >= 1.5)]", &value).len(), 1);
+    }
+
+    #[test]
+    fn parse_reports_errors_with_position() {
+        let err = JsonPath::parse("$.orders[").unwrap_err();
+        assert!(err.contains("at position 9"), "{err}");
+        assert!(
+            JsonPath::parse("orders")
+                .unwrap_err()
+                .contains("expected '$'")
+        );
+        assert!(JsonPath::parse("$[::0]").unwrap_err().contains("step"));
+        assert!(
+            JsonPath::parse("$[?(@.a == nope)]")
+                .unwrap_err()
+                .contains("literal")
+        );
+    }
+
+    #[test]
+    fn format_json_path_quotes_non_identifiers() {
+        let path = vec![
+            PathSegment::Field("a.b".into()),
+            PathSegment::Index(0),
+            PathSegment::Field("it's".into()),
+        ];
+        assert_eq!(format_json_path(&path), r"$['a.b'][0]['it\'s']");
+    }
+}
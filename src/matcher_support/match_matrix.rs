@@ -96,9 +96,9 @@ pub mod internal {
     }
 
     impl MatchMatrix {
-        pub(crate) fn generate<'a>(
+        pub(crate) fn generate<'a, C: JsonMatcher>(
             actual: impl IntoIterator<Item = &'a serde_json::Value>,
-            expected: &[Box<dyn JsonMatcher>],
+            expected: &[C],
         ) -> Self {
             let expected_len = expected.len();
             let actual = actual.into_iter();
@@ -116,6 +116,26 @@ pub mod internal {
             }
         }
 
+        /// Builds the graph from a cell function instead of matchers, for callers that compare
+        /// elements directly.
+        pub(crate) fn from_fn(
+            actual_len: usize,
+            expected_len: usize,
+            mut cell: impl FnMut(usize, usize) -> MatcherResult,
+        ) -> Self {
+            let graph = (0..actual_len)
+                .flat_map(|actual_idx| {
+                    (0..expected_len).map(move |expected_idx| (actual_idx, expected_idx))
+                })
+                .map(|(actual_idx, expected_idx)| cell(actual_idx, expected_idx))
+                .collect();
+            MatchMatrix {
+                graph,
+                actual_len,
+                expected_len,
+            }
+        }
+
         fn matrix_index(&self, actual_idx: usize, expected_idx: usize) -> usize {
             actual_idx * self.expected_len + expected_idx
         }
@@ -526,10 +546,10 @@ pub mod internal {
                 .collect()
         }
 
-        pub(crate) fn get_explanation<'a>(
+        pub(crate) fn get_explanation<'a, C: JsonMatcher>(
             &self,
             actual: impl IntoIterator<Item = &'a serde_json::Value>,
-            expected: &[Box<dyn JsonMatcher>],
+            expected: &[C],
             requirements: Requirements,
         ) -> Option<Description> {
             let actual: Vec<_> = actual.into_iter().collect();
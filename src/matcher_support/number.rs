@@ -0,0 +1,46 @@
+use crate::matchers::JsonOptions;
+use serde_json::Number;
+
+/// Whether two JSON numbers have the same value, whatever their representation: `1`, `1.0`
+/// and `1e0` are all equal. Integers are compared exactly, without going through `f64`.
+pub(crate) fn same_value(a: &Number, b: &Number) -> bool {
+    match (integer(a), integer(b)) {
+        (Some(a), Some(b)) => a == b,
+        (Some(int), None) => b.as_f64().is_some_and(|float| float_equals_int(float, int)),
+        (None, Some(int)) => a.as_f64().is_some_and(|float| float_equals_int(float, int)),
+        (None, None) => a.as_f64() == b.as_f64(),
+    }
+}
+
+/// `n` as an `i64`, also accepting whole floats such as `3.0` while numbers are coerced.
+pub(crate) fn as_i64(n: &Number) -> Option<i64> {
+    n.as_i64()
+        .or_else(|| coerced(n).and_then(|i| i64::try_from(i).ok()))
+}
+
+/// `n` as a `u64`, also accepting whole floats such as `3.0` while numbers are coerced.
+pub(crate) fn as_u64(n: &Number) -> Option<u64> {
+    n.as_u64()
+        .or_else(|| coerced(n).and_then(|i| u64::try_from(i).ok()))
+}
+
+fn integer(n: &Number) -> Option<i128> {
+    n.as_i64()
+        .map(i128::from)
+        .or_else(|| n.as_u64().map(i128::from))
+}
+
+/// The integer value of a whole float when the current options coerce numbers.
+fn coerced(n: &Number) -> Option<i128> {
+    if !JsonOptions::current().coerce_numbers() {
+        return None;
+    }
+    let float = n.as_f64()?;
+    let int = float as i128;
+    float_equals_int(float, int).then_some(int)
+}
+
+fn float_equals_int(float: f64, int: i128) -> bool {
+    // Casts saturate, so a float beyond the i128 range never equals the clamped value.
+    float.fract() == 0.0 && float as i128 == int && (int as f64) == float
+}
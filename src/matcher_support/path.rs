@@ -1,16 +1,114 @@
 use serde_json::Value;
 use std::collections::BTreeSet;
 
-#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
-pub(crate) enum PathSegment {
+/// One step of a concrete location in a JSON document.
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum PathSegment {
+    /// An object key.
     Field(String),
+    /// An array index.
     Index(usize),
 }
 
+impl PathSegment {
+    /// Returns true if this segment of a user-supplied path selects the concrete `segment`.
+    ///
+    /// A numeric segment is an index into arrays and, as in RFC 6901, a key into objects.
+    pub(crate) fn selects(&self, segment: &PathSegment) -> bool {
+        match (self, segment) {
+            (PathSegment::Index(idx), PathSegment::Field(key)) => *key == idx.to_string(),
+            _ => self == segment,
+        }
+    }
+}
+
+/// A segment of a user-supplied path, which may contain wildcards.
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub(crate) enum PatternSegment {
+    /// A literal field name or array index.
+    Segment(PathSegment),
+    /// `*`: any single object key or array index.
+    Wildcard,
+    /// `**`: any sequence of zero or more keys or indices.
+    RecursiveWildcard,
+}
+
+/// The notation a path was written in, used to echo paths back in explanations.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub(crate) enum PathSyntax {
+    /// Dot notation with `\` escapes, e.g. `user.addresses.0.zip`.
+    #[default]
+    Dot,
+    /// RFC 6901 JSON Pointer, e.g. `/user/addresses/0/zip`.
+    Pointer,
+}
+
+impl PathSyntax {
+    pub(crate) fn format(self, path: &[PathSegment]) -> String {
+        match self {
+            PathSyntax::Dot => format_path(path),
+            PathSyntax::Pointer => format_pointer(path),
+        }
+    }
+
+    pub(crate) fn format_pattern(self, pattern: &[PatternSegment]) -> String {
+        match self {
+            PathSyntax::Dot => format_pattern(pattern),
+            PathSyntax::Pointer => pattern
+                .iter()
+                .map(|segment| match segment {
+                    PatternSegment::Segment(s) => format!("/{}", escape_pointer_token(s)),
+                    PatternSegment::Wildcard => "/*".to_string(),
+                    PatternSegment::RecursiveWildcard => "/**".to_string(),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// A user-supplied path together with how it should be parsed.
+#[doc(hidden)]
+#[derive(Clone, Copy, Debug)]
+pub enum PathSpec<'a> {
+    /// Pointer syntax when the path starts with `/`, dot notation otherwise.
+    Auto(&'a str),
+    /// Always RFC 6901 JSON Pointer syntax.
+    Pointer(&'a str),
+}
+
 #[derive(Clone, Debug)]
 pub(crate) struct ParsedPath {
     pub(crate) raw: String,
-    pub(crate) segments: Vec<PathSegment>,
+    pub(crate) segments: Vec<PatternSegment>,
+    pub(crate) syntax: PathSyntax,
+}
+
+impl ParsedPath {
+    /// Returns the literal path when the pattern contains no wildcards.
+    pub(crate) fn concrete(&self) -> Option<Vec<PathSegment>> {
+        self.segments
+            .iter()
+            .map(|segment| match segment {
+                PatternSegment::Segment(s) => Some(s.clone()),
+                PatternSegment::Wildcard | PatternSegment::RecursiveWildcard => None,
+            })
+            .collect()
+    }
+
+    /// Returns true if the concrete `path` is selected by this pattern.
+    pub(crate) fn matches(&self, path: &[PathSegment]) -> bool {
+        pattern_matches(&self.segments, path)
+    }
+
+    /// Formats a concrete path in the same syntax this path was written in.
+    pub(crate) fn display(&self, path: &[PathSegment]) -> String {
+        self.syntax.format(path)
+    }
+
+    /// Formats this pattern in canonical form, in the syntax it was written in.
+    pub(crate) fn display_pattern(&self) -> String {
+        self.syntax.format_pattern(&self.segments)
+    }
 }
 
 pub(crate) struct ParsedPaths {
@@ -18,11 +116,16 @@ pub(crate) struct ParsedPaths {
     pub(crate) errors: Vec<String>,
 }
 
+#[cfg(test)]
 pub(crate) fn parse_expected_paths(paths: &[&str]) -> ParsedPaths {
+    parse_path_specs(paths.iter().map(|p| PathSpec::Auto(p)))
+}
+
+pub(crate) fn parse_path_specs<'a>(specs: impl IntoIterator<Item = PathSpec<'a>>) -> ParsedPaths {
     let mut parsed = Vec::new();
     let mut errors = Vec::new();
-    for path in paths {
-        match parse_path(path) {
+    for spec in specs {
+        match parse_path_spec(spec) {
             Ok(path) => parsed.push(path),
             Err(err) => errors.push(err),
         }
@@ -30,75 +133,153 @@ pub(crate) fn parse_expected_paths(paths: &[&str]) -> ParsedPaths {
     ParsedPaths { parsed, errors }
 }
 
-fn parse_path(path: &str) -> Result<ParsedPath, String> {
+pub(crate) fn parse_path_spec(spec: PathSpec<'_>) -> Result<ParsedPath, String> {
+    match spec {
+        PathSpec::Auto(path) if path.starts_with('/') => parse_pointer(path),
+        PathSpec::Auto(path) => parse_dot_path(path),
+        PathSpec::Pointer(path) => parse_pointer(path),
+    }
+}
+
+fn parse_pointer(path: &str) -> Result<ParsedPath, String> {
+    if path.is_empty() {
+        return Err(format!(
+            "Invalid pointer {path:?}: the empty pointer refers to the whole document"
+        ));
+    }
+    let Some(rest) = path.strip_prefix('/') else {
+        return Err(format!("Invalid pointer {path:?}: must start with '/'"));
+    };
+    let mut segments = Vec::new();
+    for token in rest.split('/') {
+        let mut unescaped = String::new();
+        let mut chars = token.chars();
+        while let Some(ch) = chars.next() {
+            match ch {
+                '~' => match chars.next() {
+                    Some('0') => unescaped.push('~'),
+                    Some('1') => unescaped.push('/'),
+                    _ => {
+                        return Err(format!(
+                            "Invalid pointer {path:?}: '~' must be followed by '0' or '1'"
+                        ));
+                    }
+                },
+                _ => unescaped.push(ch),
+            }
+        }
+        let is_index = unescaped == "0"
+            || (!unescaped.starts_with('0') && unescaped.bytes().all(|b| b.is_ascii_digit()));
+        let segment = match unescaped.parse::<usize>() {
+            Ok(idx) if is_index => PathSegment::Index(idx),
+            _ => PathSegment::Field(unescaped),
+        };
+        segments.push(PatternSegment::Segment(segment));
+    }
+    Ok(ParsedPath {
+        raw: path.to_string(),
+        segments,
+        syntax: PathSyntax::Pointer,
+    })
+}
+
+fn parse_dot_path(path: &str) -> Result<ParsedPath, String> {
     let mut segments = Vec::new();
     let mut current = String::new();
+    let mut escaped = false;
     let mut chars = path.chars().peekable();
     while let Some(ch) = chars.next() {
         match ch {
             '\\' => {
                 if let Some(next) = chars.next() {
                     current.push(next);
+                    escaped = true;
                 } else {
                     return Err(format!("Invalid path {path:?}: trailing escape"));
                 }
             }
             '.' => {
-                push_segment(path, &mut segments, &mut current)?;
+                push_segment(path, &mut segments, &mut current, escaped)?;
+                escaped = false;
             }
             _ => current.push(ch),
         }
     }
-    push_segment(path, &mut segments, &mut current)?;
+    push_segment(path, &mut segments, &mut current, escaped)?;
     Ok(ParsedPath {
         raw: path.to_string(),
         segments,
+        syntax: PathSyntax::Dot,
     })
 }
 
 fn push_segment(
     path: &str,
-    segments: &mut Vec<PathSegment>,
+    segments: &mut Vec<PatternSegment>,
     current: &mut String,
+    escaped: bool,
 ) -> Result<(), String> {
     if current.is_empty() {
         return Err(format!("Invalid path {path:?}: empty segment"));
     }
-    if let Ok(idx) = current.parse::<usize>() {
-        segments.push(PathSegment::Index(idx));
-    } else {
-        segments.push(PathSegment::Field(current.clone()));
-    }
+    let segment = match current.as_str() {
+        "*" if !escaped => PatternSegment::Wildcard,
+        "**" if !escaped => PatternSegment::RecursiveWildcard,
+        _ => match current.parse::<usize>() {
+            Ok(idx) => PatternSegment::Segment(PathSegment::Index(idx)),
+            Err(_) => PatternSegment::Segment(PathSegment::Field(current.clone())),
+        },
+    };
+    segments.push(segment);
     current.clear();
     Ok(())
 }
 
-pub(crate) fn collect_paths(value: &Value) -> BTreeSet<Vec<PathSegment>> {
-    let mut paths = BTreeSet::new();
-    collect_paths_inner(value, &mut Vec::new(), &mut paths);
-    paths
+fn pattern_matches(pattern: &[PatternSegment], path: &[PathSegment]) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+        Some((PatternSegment::RecursiveWildcard, rest)) => {
+            (0..=path.len()).any(|skip| pattern_matches(rest, &path[skip..]))
+        }
+        Some((head, rest)) => match path.split_first() {
+            None => false,
+            Some((segment, path_rest)) => {
+                let head_matches = match head {
+                    PatternSegment::Segment(expected) => expected.selects(segment),
+                    PatternSegment::Wildcard => true,
+                    PatternSegment::RecursiveWildcard => unreachable!("handled above"),
+                };
+                head_matches && pattern_matches(rest, path_rest)
+            }
+        },
+    }
+}
+
+/// Visits every descendant of `value` (excluding the root) together with its concrete path.
+pub(crate) fn walk<'a>(value: &'a Value, visit: &mut impl FnMut(&[PathSegment], &'a Value)) {
+    walk_inner(value, &mut Vec::new(), visit);
 }
 
-fn collect_paths_inner(
-    value: &Value,
+fn walk_inner<'a>(
+    value: &'a Value,
     current: &mut Vec<PathSegment>,
-    out: &mut BTreeSet<Vec<PathSegment>>,
+    visit: &mut impl FnMut(&[PathSegment], &'a Value),
 ) {
     if !current.is_empty() {
-        out.insert(current.clone());
+        visit(current, value);
     }
     match value {
         Value::Object(map) => {
             for (k, v) in map {
                 current.push(PathSegment::Field(k.clone()));
-                collect_paths_inner(v, current, out);
+                walk_inner(v, current, visit);
                 current.pop();
             }
         }
         Value::Array(arr) => {
             for (idx, v) in arr.iter().enumerate() {
                 current.push(PathSegment::Index(idx));
-                collect_paths_inner(v, current, out);
+                walk_inner(v, current, visit);
                 current.pop();
             }
         }
@@ -106,17 +287,72 @@ fn collect_paths_inner(
     }
 }
 
+/// Returns every descendant selected by `pattern`, ordered by path.
+pub(crate) fn resolve_pattern<'a>(
+    value: &'a Value,
+    pattern: &ParsedPath,
+) -> Vec<(Vec<PathSegment>, &'a Value)> {
+    let mut out = Vec::new();
+    walk(value, &mut |path, node| {
+        if pattern.matches(path) {
+            out.push((path.to_vec(), node));
+        }
+    });
+    out.sort_by(|(a, _), (b, _)| a.cmp(b));
+    out
+}
+
+pub(crate) fn collect_paths(value: &Value) -> BTreeSet<Vec<PathSegment>> {
+    let mut paths = BTreeSet::new();
+    walk(value, &mut |path, _| {
+        paths.insert(path.to_vec());
+    });
+    paths
+}
+
 pub(crate) fn format_path(path: &[PathSegment]) -> String {
     path.iter()
+        .map(format_segment)
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+pub(crate) fn format_pattern(pattern: &[PatternSegment]) -> String {
+    pattern
+        .iter()
         .map(|segment| match segment {
-            PathSegment::Field(f) => escape_field(f),
-            PathSegment::Index(i) => i.to_string(),
+            PatternSegment::Segment(s) => format_segment(s),
+            PatternSegment::Wildcard => "*".to_string(),
+            PatternSegment::RecursiveWildcard => "**".to_string(),
         })
         .collect::<Vec<_>>()
         .join(".")
 }
 
+pub(crate) fn format_pointer(path: &[PathSegment]) -> String {
+    path.iter()
+        .map(|segment| format!("/{}", escape_pointer_token(segment)))
+        .collect()
+}
+
+fn escape_pointer_token(segment: &PathSegment) -> String {
+    match segment {
+        PathSegment::Field(f) => f.replace('~', "~0").replace('/', "~1"),
+        PathSegment::Index(i) => i.to_string(),
+    }
+}
+
+fn format_segment(segment: &PathSegment) -> String {
+    match segment {
+        PathSegment::Field(f) => escape_field(f),
+        PathSegment::Index(i) => i.to_string(),
+    }
+}
+
 fn escape_field(field: &str) -> String {
+    if field == "*" || field == "**" {
+        return field.replace('*', "\\*");
+    }
     let mut out = String::new();
     for ch in field.chars() {
         match ch {
@@ -147,9 +383,9 @@ mod tests {
     fn parse_path_accepts_nested_fields_and_indices() {
         let ParsedPaths { parsed, errors } = parse_expected_paths(&["user.id", "items.0.id"]);
         assert!(errors.is_empty());
-        assert_eq!(parsed[0].segments, vec![f("user"), f("id")]);
+        assert_eq!(parsed[0].concrete().unwrap(), vec![f("user"), f("id")]);
         assert_eq!(
-            parsed[1].segments,
+            parsed[1].concrete().unwrap(),
             vec![f("items"), PathSegment::Index(0), f("id")]
         );
     }
@@ -158,7 +394,7 @@ mod tests {
     fn parse_path_supports_escaped_dot() {
         let ParsedPaths { parsed, errors } = parse_expected_paths(&[r"user\.name"]);
         assert!(errors.is_empty());
-        assert_eq!(parsed[0].segments, vec![f("user.name")]);
+        assert_eq!(parsed[0].concrete().unwrap(), vec![f("user.name")]);
     }
 
     #[test]
@@ -199,4 +435,137 @@ mod tests {
         let path = vec![f("user\\name")];
         assert_eq!(format_path(&path), r"user\\name");
     }
+
+    #[test]
+    fn parse_path_recognizes_wildcards() {
+        let ParsedPaths { parsed, errors } = parse_expected_paths(&["items.*.price", "**.id"]);
+        assert!(errors.is_empty());
+        assert_eq!(
+            parsed[0].segments,
+            vec![
+                PatternSegment::Segment(f("items")),
+                PatternSegment::Wildcard,
+                PatternSegment::Segment(f("price")),
+            ]
+        );
+        assert_eq!(
+            parsed[1].segments,
+            vec![
+                PatternSegment::RecursiveWildcard,
+                PatternSegment::Segment(f("id"))
+            ]
+        );
+        assert!(parsed[0].concrete().is_none());
+    }
+
+    #[test]
+    fn parse_path_treats_escaped_star_as_literal() {
+        let ParsedPaths { parsed, errors } = parse_expected_paths(&[r"a.\*", r"a*b"]);
+        assert!(errors.is_empty());
+        assert_eq!(parsed[0].concrete().unwrap(), vec![f("a"), f("*")]);
+        assert_eq!(parsed[1].concrete().unwrap(), vec![f("a*b")]);
+    }
+
+    #[test]
+    fn pattern_matching_supports_single_and_recursive_wildcards() {
+        let ParsedPaths { parsed, .. } = parse_expected_paths(&["items.*.id", "**.id", "a.**"]);
+        let item_id = vec![f("items"), PathSegment::Index(3), f("id")];
+        assert!(parsed[0].matches(&item_id));
+        assert!(!parsed[0].matches(&[f("items"), f("id")]));
+        assert!(parsed[1].matches(&item_id));
+        assert!(parsed[1].matches(&[f("id")]));
+        assert!(!parsed[1].matches(&[f("idx")]));
+        assert!(parsed[2].matches(&[f("a"), f("b"), f("c")]));
+        assert!(parsed[2].matches(&[f("a")]));
+    }
+
+    #[test]
+    fn resolve_pattern_returns_sorted_concrete_nodes() {
+        let value = json!({"items": [{"price": 2}, {"price": 1}, {"name": "x"}]});
+        let ParsedPaths { parsed, .. } = parse_expected_paths(&["items.*.price"]);
+        let resolved = resolve_pattern(&value, &parsed[0]);
+        let paths: Vec<_> = resolved.iter().map(|(p, _)| format_path(p)).collect();
+        assert_eq!(paths, vec!["items.0.price", "items.1.price"]);
+        assert_eq!(resolved[1].1, &json!(1));
+    }
+
+    #[test]
+    fn format_path_escapes_wildcard_field_names() {
+        assert_eq!(format_path(&[f("*"), f("**")]), r"\*.\*\*");
+        assert_eq!(
+            format_pattern(&[PatternSegment::Wildcard, PatternSegment::Segment(f("*"))]),
+            r"*.\*"
+        );
+    }
+
+    fn pointer(path: &str) -> Result<ParsedPath, String> {
+        parse_path_spec(PathSpec::Auto(path))
+    }
+
+    #[test]
+    fn parse_path_detects_pointer_syntax() {
+        let parsed = pointer("/user/addresses/0/zip").unwrap();
+        assert_eq!(parsed.syntax, PathSyntax::Pointer);
+        assert_eq!(
+            parsed.concrete().unwrap(),
+            vec![f("user"), f("addresses"), PathSegment::Index(0), f("zip")]
+        );
+    }
+
+    #[test]
+    fn parse_pointer_unescapes_tokens() {
+        let parsed = pointer("/a~1b/m~0n/x.y/*").unwrap();
+        assert_eq!(
+            parsed.concrete().unwrap(),
+            vec![f("a/b"), f("m~n"), f("x.y"), f("*")]
+        );
+    }
+
+    #[test]
+    fn parse_pointer_keeps_non_canonical_numbers_as_fields() {
+        let parsed = pointer("/01/0/").unwrap();
+        assert_eq!(
+            parsed.concrete().unwrap(),
+            vec![f("01"), PathSegment::Index(0), f("")]
+        );
+    }
+
+    #[test]
+    fn parse_pointer_rejects_invalid_escape_and_missing_slash() {
+        assert!(
+            pointer("/a~2")
+                .unwrap_err()
+                .contains("'~' must be followed")
+        );
+        assert!(
+            parse_path_spec(PathSpec::Pointer("a/b"))
+                .unwrap_err()
+                .contains("must start with '/'")
+        );
+        assert!(
+            parse_path_spec(PathSpec::Pointer(""))
+                .unwrap_err()
+                .contains("whole document")
+        );
+    }
+
+    #[test]
+    fn numeric_segments_select_object_keys_and_array_indices() {
+        let parsed = pointer("/items/42").unwrap();
+        assert!(parsed.matches(&[f("items"), PathSegment::Index(42)]));
+        assert!(parsed.matches(&[f("items"), f("42")]));
+        assert!(!parsed.matches(&[f("items"), f("042")]));
+        let value = json!({"items": {"42": 1, "7": 2}});
+        let ParsedPaths { parsed, .. } = parse_expected_paths(&["items.42"]);
+        let resolved = resolve_pattern(&value, &parsed[0]);
+        assert_eq!(resolved, vec![(vec![f("items"), f("42")], &json!(1))]);
+    }
+
+    #[test]
+    fn format_pointer_escapes_tokens() {
+        let path = vec![f("a/b"), f("m~n"), PathSegment::Index(2)];
+        assert_eq!(format_pointer(&path), "/a~1b/m~0n/2");
+        assert_eq!(PathSyntax::Pointer.format(&path), "/a~1b/m~0n/2");
+        assert_eq!(PathSyntax::Dot.format(&path), "a/b.m~n.2");
+    }
 }
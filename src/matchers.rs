@@ -1,17 +1,30 @@
+mod approx_eq_json_matcher;
+mod array_pattern_matcher;
 mod as_matcher;
+mod at_matcher;
+mod descendant_matcher;
 mod each_matcher;
 mod elements_are_matcher;
+mod eq_json_matcher;
+mod eq_json_unordered_matcher;
 mod json_matcher;
 mod len_matcher;
 mod matches_pattern_matcher;
+mod num_eq_matcher;
 mod optional_matcher;
+mod options_matcher;
+mod parsed_matcher;
 mod path_matcher;
+mod presence_matcher;
 mod primitive_matcher;
+mod recursive_matcher;
+mod serialized_matcher;
+mod tagged_matcher;
 mod unordered_elements_are_matcher;
 
 pub use as_matcher::{
     as_array, as_bool, as_f64, as_i8, as_i16, as_i32, as_i64, as_object, as_string, as_u8, as_u16,
-    as_u32, as_u64, as_usize,
+    as_u32, as_u64, as_usize, deserializes_as, round_trips,
 };
 #[allow(deprecated)]
 pub use json_matcher::{
@@ -20,39 +33,95 @@ pub use json_matcher::{
     is_not_null, is_null, is_number, is_object, is_string, is_true, is_whole_number, predicate,
 };
 mod each_is_matcher;
+pub use crate::report::check;
+pub use descendant_matcher::no_key_anywhere;
 pub use each_is_matcher::{
     each_is_array, each_is_boolean, each_is_null, each_is_number, each_is_object, each_is_string,
 };
-pub use path_matcher::{has_only_paths, has_paths};
+pub use matches_pattern_matcher::all_keys;
+pub use num_eq_matcher::num_eq;
+pub use options_matcher::{JsonOptions, options};
+pub use parsed_matcher::parsed;
+pub use path_matcher::{has_only_paths, has_paths, pointer};
+pub use presence_matcher::{absent, present_null};
+pub use recursive_matcher::recursive;
+pub use serialized_matcher::serialized;
 
 #[allow(deprecated)]
 #[doc(inline)]
 pub use crate::{
-    __json_contains_each as contains_each, __json_each as each,
-    __json_elements_are as elements_are, __json_has_path_with as has_path_with,
-    __json_is_contained_in as is_contained_in, __json_len as len, __json_matches_pattern as pat,
-    __json_matches_pattern as matches_pattern, __json_optional as optional,
-    __json_primitive as primitive, __json_unordered_elements_are as unordered_elements_are,
-    __json_value as value,
+    __json_all_leaves as all_leaves, __json_approx_eq_json as approx_eq_json, __json_at as at,
+    __json_contains_each as contains_each, __json_descendants_matching as descendants_matching,
+    __json_each as each, __json_elements_are as elements_are, __json_eq_json as eq_json,
+    __json_eq_json_unordered as eq_json_unordered, __json_has_descendant as has_descendant,
+    __json_has_path_with as has_path_with, __json_is_contained_in as is_contained_in,
+    __json_len as len, __json_matches_pattern as pat, __json_matches_pattern as matches_pattern,
+    __json_missing_or as missing_or, __json_no_descendant as no_descendant,
+    __json_nullable as nullable, __json_optional as optional, __json_primitive as primitive,
+    __json_rest as rest, __json_tagged as tagged,
+    __json_unordered_elements_are as unordered_elements_are, __json_value as value,
+    __json_with_options as with_options,
 };
 
 #[doc(hidden)]
 pub mod __internal_unstable_do_not_depend_on_these {
+    pub use super::approx_eq_json_matcher::internal::JsonApproxEqJsonMatcher;
+    pub use super::array_pattern_matcher::internal::JsonArrayPatternMatcher;
     pub use super::as_matcher::internal::JsonAsMatcher;
+    pub use super::as_matcher::internal::JsonDeserializesAsMatcher;
+    pub use super::as_matcher::internal::JsonRoundTripMatcher;
+    pub use super::at_matcher::internal::JsonAtMatcher;
+    pub use super::descendant_matcher::internal::JsonDescendantMatcher;
+    pub use super::descendant_matcher::internal::JsonNoKeyAnywhereMatcher;
     pub use super::each_matcher::internal::JsonEachMatcher;
     pub use super::elements_are_matcher::internal::JsonElementsAre;
+    pub use super::eq_json_matcher::internal::JsonEqJsonMatcher;
+    pub use super::eq_json_unordered_matcher::internal::JsonEqJsonUnorderedMatcher;
+    pub use super::json_matcher::internal::ChildArg;
+    pub use super::json_matcher::internal::Children;
     pub use super::json_matcher::internal::IntoJsonMatcher;
+    pub use super::json_matcher::internal::Join;
     pub use super::json_matcher::internal::JsonMatcher;
     pub use super::json_matcher::internal::JsonPredicateMatcher;
     pub use super::json_matcher::internal::Literal;
+    pub use super::json_matcher::internal::LocalMatcher;
     pub use super::json_matcher::internal::NoDescription;
     pub use super::json_matcher::internal::PredicateDescription;
+    pub use super::json_matcher::internal::SharedMatcher;
+    pub use super::json_matcher::internal::ViaLocal;
+    pub use super::json_matcher::internal::ViaShared;
     pub use super::json_matcher::internal::describe_json_type;
     pub use super::len_matcher::internal::JsonLenMatcher;
+    pub use super::matches_pattern_matcher::internal::AllKeys;
+    pub use super::matches_pattern_matcher::internal::FieldKey;
+    pub use super::matches_pattern_matcher::internal::FieldKeyArg;
+    pub use super::matches_pattern_matcher::internal::IntoFieldKey;
     pub use super::matches_pattern_matcher::internal::JsonObjectMatcher;
+    pub use super::matches_pattern_matcher::internal::KeyPattern;
+    pub use super::matches_pattern_matcher::internal::LocalKeyMatcher;
+    pub use super::matches_pattern_matcher::internal::ObjectPattern;
+    pub use super::matches_pattern_matcher::internal::RestPolicy;
+    pub use super::matches_pattern_matcher::internal::SharedKeyMatcher;
+    pub use super::matches_pattern_matcher::internal::ViaKeyName;
+    pub use super::matches_pattern_matcher::internal::ViaLocalKeyMatcher;
+    pub use super::matches_pattern_matcher::internal::ViaSharedKeyMatcher;
+    pub use super::num_eq_matcher::internal::JsonNumEqMatcher;
+    pub use super::num_eq_matcher::internal::JsonNumber;
     pub use super::optional_matcher::internal::JsonOptionalMatcher;
+    pub use super::options_matcher::internal::JsonWithOptionsMatcher;
+    pub use super::parsed_matcher::internal::JsonParsedMatcher;
+    pub use super::path_matcher::JsonPointer;
+    pub use super::path_matcher::internal::JsonPathArg;
     pub use super::path_matcher::internal::JsonPathWithMatcher;
+    pub use super::presence_matcher::internal::JsonAbsentMatcher;
+    pub use super::presence_matcher::internal::JsonPresenceMatcher;
     pub use super::primitive_matcher::internal::JsonPrimitiveMatcher;
+    pub use super::recursive_matcher::internal::JsonRecursiveMatcher;
+    pub use super::recursive_matcher::internal::JsonRecursiveRef;
+    pub use super::serialized_matcher::internal::JsonSerializedMatcher;
+    pub use super::tagged_matcher::internal::JsonTaggedMatcher;
     pub use super::unordered_elements_are_matcher::internal::JsonUnorderedElementsAreMatcher;
+    pub use crate::matcher_support::json_input::JsonInput;
     pub use crate::matcher_support::match_matrix::internal::Requirements;
+    pub use crate::matcher_support::path::PathSpec;
 }
@@ -0,0 +1,193 @@
+/// Matches a JSON document that equals the expected one, with numeric tolerance at every number.
+///
+/// Objects and arrays are compared structurally as in [`eq_json!`](crate::json::eq_json). Two
+/// numbers are equal when they have the same value, whatever their integer/float
+/// representation, or when they differ by at most `abs` or by at most `rel` times the larger
+/// magnitude. Either tolerance may be omitted; without any, numbers may differ by a relative
+/// `4 * f64::EPSILON`, enough to absorb rounding in serialisation.
+///
+/// On mismatch, the explanation lists every added, removed or changed path, and the delta of
+/// each number outside the tolerance.
+///
+/// # Examples
+///
+/// ```rust
+/// # use googletest::prelude::*;
+/// # use googletest_json_serde::json as j;
+/// # use serde_json::json;
+/// let prediction = json!({"scores": [0.1 + 0.2, 0.7], "model": "v2"});
+/// assert_that!(
+///     prediction,
+///     j::approx_eq_json!(json!({"scores": [0.3, 0.7], "model": "v2"}), abs = 1e-6, rel = 1e-9)
+/// );
+/// assert_that!(json!({"price": 10.0}), j::approx_eq_json!(json!({"price": 10})));
+/// ```
+///
+/// ```rust,should_panic
+/// # use googletest::prelude::*;
+/// # use googletest_json_serde::json as j;
+/// # use serde_json::json;
+/// // Fails with:
+/// //   which differs from the expected JSON at 1 path:
+/// //     changed `scores.1`: expected 0.5, actual 0.75, delta 2.5e-1
+/// assert_that!(
+///     json!({"scores": [0.3, 0.75]}),
+///     j::approx_eq_json!(json!({"scores": [0.3, 0.5]}), abs = 1e-6)
+/// );
+/// ```
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __json_approx_eq_json {
+    (@tolerances $matcher:expr;) => {
+        $matcher
+    };
+    (@tolerances $matcher:expr; , $(,)?) => {
+        $matcher
+    };
+    (@tolerances $matcher:expr; , abs = $value:expr $(, $($rest:tt)*)?) => {
+        $crate::__json_approx_eq_json!(@tolerances $matcher.abs($value); $(, $($rest)*)?)
+    };
+    (@tolerances $matcher:expr; , rel = $value:expr $(, $($rest:tt)*)?) => {
+        $crate::__json_approx_eq_json!(@tolerances $matcher.rel($value); $(, $($rest)*)?)
+    };
+    // Error case: only `abs` and `rel` are tolerances.
+    (@tolerances $matcher:expr; , $($rest:tt)*) => {
+        compile_error!("j::approx_eq_json! accepts only `abs = <f64>` and `rel = <f64>` after the expected JSON")
+    };
+    ($expected:expr $(, $($tolerances:tt)*)?) => {
+        $crate::__json_approx_eq_json!(
+            @tolerances
+            $crate::matchers::__internal_unstable_do_not_depend_on_these::JsonApproxEqJsonMatcher::new(
+                $expected,
+            );
+            $(, $($tolerances)*)?
+        )
+    };
+}
+
+#[doc(hidden)]
+pub mod internal {
+    use crate::matcher_support::diff::{
+        Difference, describe_rendered_differences, diff_by, display_path,
+    };
+    use crate::matcher_support::number;
+    use crate::matchers::json_matcher::internal::JsonMatcher;
+    use googletest::description::Description;
+    use googletest::matcher::{Matcher, MatcherBase, MatcherResult};
+    use serde_json::Value;
+    use std::borrow::Borrow;
+
+    const DEFAULT_REL: f64 = 4.0 * f64::EPSILON;
+
+    /// Compares documents structurally with numeric tolerance; see `j::approx_eq_json!`.
+    #[doc(hidden)]
+    #[derive(Clone, MatcherBase)]
+    pub struct JsonApproxEqJsonMatcher {
+        expected: Value,
+        abs: f64,
+        rel: f64,
+    }
+
+    impl JsonApproxEqJsonMatcher {
+        pub fn new(expected: impl Borrow<Value>) -> Self {
+            Self {
+                expected: expected.borrow().clone(),
+                abs: 0.0,
+                rel: DEFAULT_REL,
+            }
+        }
+
+        /// Accepts numbers that differ by at most `tolerance`.
+        pub fn abs(mut self, tolerance: f64) -> Self {
+            self.abs = tolerance.abs();
+            self
+        }
+
+        /// Accepts numbers that differ by at most `tolerance` times the larger magnitude.
+        pub fn rel(mut self, tolerance: f64) -> Self {
+            self.rel = tolerance.abs();
+            self
+        }
+
+        fn leaves_equal(&self, expected: &Value, actual: &Value) -> bool {
+            match (expected, actual) {
+                (Value::Number(e), Value::Number(a)) => {
+                    number::same_value(e, a)
+                        || match (e.as_f64(), a.as_f64()) {
+                            (Some(e), Some(a)) => {
+                                let delta = (a - e).abs();
+                                delta <= self.abs || delta <= self.rel * e.abs().max(a.abs())
+                            }
+                            _ => false,
+                        }
+                }
+                _ => expected == actual,
+            }
+        }
+
+        fn differences<'a>(&'a self, actual: &'a Value) -> Vec<Difference<'a>> {
+            diff_by(&self.expected, actual, &|expected, actual| {
+                self.leaves_equal(expected, actual)
+            })
+        }
+    }
+
+    fn render(difference: &Difference<'_>) -> String {
+        match difference {
+            Difference::Changed {
+                path,
+                expected: Value::Number(e),
+                actual: Value::Number(a),
+            } => match (e.as_f64(), a.as_f64()) {
+                (Some(e_f64), Some(a_f64)) => format!(
+                    "changed {}: expected {e}, actual {a}, delta {:e}",
+                    display_path(path),
+                    (a_f64 - e_f64).abs()
+                ),
+                _ => difference.render(),
+            },
+            other => other.render(),
+        }
+    }
+
+    impl JsonMatcher for JsonApproxEqJsonMatcher {}
+
+    impl Matcher<&Value> for JsonApproxEqJsonMatcher {
+        fn matches(&self, actual: &Value) -> MatcherResult {
+            self.differences(actual).is_empty().into()
+        }
+
+        fn describe(&self, result: MatcherResult) -> Description {
+            let verb = match result {
+                MatcherResult::Match => "is",
+                MatcherResult::NoMatch => "isn't",
+            };
+            let mut tolerances = Vec::new();
+            if self.abs > 0.0 {
+                tolerances.push(format!("abs {:e}", self.abs));
+            }
+            if self.rel > 0.0 {
+                tolerances.push(format!("rel {:e}", self.rel));
+            }
+            if tolerances.is_empty() {
+                format!("{verb} numerically equal to JSON {}", self.expected).into()
+            } else {
+                format!(
+                    "{verb} equal to JSON {} with numbers within {}",
+                    self.expected,
+                    tolerances.join(" or ")
+                )
+                .into()
+            }
+        }
+
+        fn explain_match(&self, actual: &Value) -> Description {
+            let lines = self
+                .differences(actual)
+                .iter()
+                .map(render)
+                .collect::<Vec<_>>();
+            describe_rendered_differences(&lines)
+        }
+    }
+}
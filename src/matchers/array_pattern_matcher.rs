@@ -0,0 +1,195 @@
+// Array patterns are written inside `j::pat!`; see `matches_pattern_matcher.rs`.
+
+#[doc(hidden)]
+pub mod internal {
+    use crate::matcher_support::path::PathSegment;
+    use crate::matchers::elements_are_matcher::internal::JsonElementsAre;
+    use crate::matchers::json_matcher::internal::{JsonMatcher, SharedMatcher};
+    use crate::report::{JsonMismatch, report_mismatch};
+    use googletest::description::Description;
+    use googletest::matcher::{Matcher, MatcherBase, MatcherResult};
+    use serde_json::Value;
+
+    /// Where the listed elements must sit in an open array pattern.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    enum Anchor {
+        /// `[a, b, ..]`
+        Start,
+        /// `[.., a, b]`
+        End,
+        /// `[.., a, b, ..]`
+        Anywhere,
+    }
+
+    #[derive(Clone)]
+    enum Shape<C> {
+        Exact(JsonElementsAre<C>),
+        Open { elements: Vec<C>, anchor: Anchor },
+    }
+
+    /// An array pattern from `j::pat!`, e.g. `[a, b]`, `[a, ..]`, `[.., a]` or `[.., a, ..]`.
+    #[doc(hidden)]
+    #[derive(Clone, MatcherBase)]
+    pub struct JsonArrayPatternMatcher<C = SharedMatcher> {
+        shape: Shape<C>,
+    }
+
+    impl<C: JsonMatcher> JsonArrayPatternMatcher<C> {
+        pub fn new(elements: Vec<C>, open_start: bool, open_end: bool) -> Self {
+            let anchor = match (open_start, open_end) {
+                (false, false) => {
+                    return Self {
+                        shape: Shape::Exact(JsonElementsAre::new(elements)),
+                    };
+                }
+                (false, true) => Anchor::Start,
+                (true, false) => Anchor::End,
+                (true, true) => Anchor::Anywhere,
+            };
+            Self {
+                shape: Shape::Open { elements, anchor },
+            }
+        }
+    }
+
+    /// Returns the offset at which `elements` must match `arr`, or `None` if the array is too
+    /// short. `Anywhere` has no fixed offset and is handled separately.
+    fn fixed_offset(anchor: Anchor, arr: &[Value], expected_len: usize) -> Option<usize> {
+        let slack = arr.len().checked_sub(expected_len)?;
+        match anchor {
+            Anchor::Start => Some(0),
+            Anchor::End => Some(slack),
+            Anchor::Anywhere => None,
+        }
+    }
+
+    fn window_matches<C: JsonMatcher>(elements: &[C], window: &[Value]) -> bool {
+        elements
+            .iter()
+            .zip(window)
+            .all(|(matcher, item)| matcher.matches(item).is_match())
+    }
+
+    impl<C: JsonMatcher> Matcher<&Value> for JsonArrayPatternMatcher<C> {
+        fn matches(&self, actual: &Value) -> MatcherResult {
+            let (elements, anchor) = match &self.shape {
+                Shape::Exact(inner) => return inner.matches(actual),
+                Shape::Open { elements, anchor } => (elements, *anchor),
+            };
+            let Value::Array(arr) = actual else {
+                return MatcherResult::NoMatch;
+            };
+            if arr.len() < elements.len() {
+                return MatcherResult::NoMatch;
+            }
+            match fixed_offset(anchor, arr, elements.len()) {
+                Some(offset) => window_matches(elements, &arr[offset..]).into(),
+                None if elements.is_empty() => MatcherResult::Match,
+                None => arr
+                    .windows(elements.len())
+                    .any(|window| window_matches(elements, window))
+                    .into(),
+            }
+        }
+
+        fn describe(&self, result: MatcherResult) -> Description {
+            let (elements, anchor) = match &self.shape {
+                Shape::Exact(inner) => return Matcher::<&Value>::describe(inner, result),
+                Shape::Open { elements, anchor } => (elements, *anchor),
+            };
+            let verb = if result.into() { "has" } else { "doesn't have" };
+            let position = match anchor {
+                Anchor::Start => "starting with",
+                Anchor::End => "ending with",
+                Anchor::Anywhere => "containing the consecutive",
+            };
+            let inner = elements
+                .iter()
+                .map(|m| m.describe(MatcherResult::Match))
+                .collect::<Description>()
+                .enumerate()
+                .indent();
+            format!("{verb} JSON array {position} elements:\n{inner}").into()
+        }
+
+        fn explain_match(&self, actual: &Value) -> Description {
+            let (elements, anchor) = match &self.shape {
+                Shape::Exact(inner) => return inner.explain_match(actual),
+                Shape::Open { elements, anchor } => (elements, *anchor),
+            };
+            let Value::Array(arr) = actual else {
+                return Description::new().text("where the type is not array");
+            };
+            if arr.len() < elements.len() {
+                return format!(
+                    "whose size is {}, fewer than the {} expected elements",
+                    arr.len(),
+                    elements.len()
+                )
+                .into();
+            }
+            let Some(offset) = fixed_offset(anchor, arr, elements.len()) else {
+                return if self.matches(actual).is_match() {
+                    "which contains the expected elements".into()
+                } else if elements.len() == 1 {
+                    "where no element matches".into()
+                } else {
+                    format!("where no {} consecutive elements match", elements.len()).into()
+                };
+            };
+            let mismatches = elements
+                .iter()
+                .zip(&arr[offset..])
+                .enumerate()
+                .filter(|(_, (matcher, item))| matcher.matches(item).is_no_match())
+                .map(|(index, (matcher, item))| {
+                    format!(
+                        "element #{} is {item:?}, {}",
+                        offset + index,
+                        matcher.explain_match(item)
+                    )
+                })
+                .collect::<Vec<_>>();
+            match mismatches.len() {
+                0 => "whose elements all match".into(),
+                1 => format!("where {}", mismatches.into_iter().collect::<Description>()).into(),
+                _ => format!(
+                    "where:\n{}",
+                    mismatches
+                        .into_iter()
+                        .collect::<Description>()
+                        .bullet_list()
+                        .indent()
+                )
+                .into(),
+            }
+        }
+    }
+
+    impl<C: JsonMatcher> JsonMatcher for JsonArrayPatternMatcher<C> {
+        fn collect_mismatches(
+            &self,
+            actual: &Value,
+            path: &mut Vec<PathSegment>,
+            out: &mut Vec<JsonMismatch>,
+        ) {
+            let (elements, anchor) = match &self.shape {
+                Shape::Exact(inner) => return inner.collect_mismatches(actual, path, out),
+                Shape::Open { elements, anchor } => (elements, *anchor),
+            };
+            let offset = match actual {
+                Value::Array(arr) => fixed_offset(anchor, arr, elements.len()),
+                _ => None,
+            };
+            // Per-element attribution only makes sense when the position is fixed.
+            let (Some(offset), Value::Array(arr)) = (offset, actual) else {
+                return report_mismatch(self, actual, path, out);
+            };
+            for (index, (matcher, item)) in elements.iter().zip(&arr[offset..]).enumerate() {
+                path.push(PathSegment::Index(offset + index));
+                matcher.collect_mismatches(item, path, out);
+                path.pop();
+            }
+        }
+    }
+}
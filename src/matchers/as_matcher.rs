@@ -1,6 +1,11 @@
-use crate::matchers::as_matcher::internal::JsonAsMatcher;
+use crate::matchers::as_matcher::internal::{
+    JsonAsMatcher, JsonDeserializesAsMatcher, JsonRoundTripMatcher,
+};
 use googletest::matcher::Matcher;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
 use serde_json::{Map, Value};
+use std::fmt::Debug;
 
 /// Matches a JSON string value against a native string matcher.
 ///
@@ -160,20 +165,133 @@ where
     JsonAsMatcher::new(inner)
 }
 
+/// Deserializes the JSON value into `T` and matches the result against a native matcher.
+///
+/// Use it to assert on typed DTOs with googletest's struct matchers such as
+/// `matches_pattern!`. When deserialization fails, the explanation includes serde's error and
+/// the path of the offending field, and [`j::check`](crate::json::check) reports the mismatch
+/// at that path.
+///
+/// # Examples
+///
+/// ```rust
+/// # use googletest::prelude::*;
+/// # use googletest_json_serde::json as j;
+/// # use serde::Deserialize;
+/// # use serde_json::json;
+/// #[derive(Debug, Deserialize)]
+/// struct User {
+///     id: u64,
+///     name: String,
+/// }
+///
+/// assert_that!(
+///     json!({"id": 7, "name": "Ada"}),
+///     j::deserializes_as::<User>(matches_pattern!(User { id: eq(&7), name: starts_with("A") }))
+/// );
+/// assert_that!(
+///     json!({"id": "7", "name": "Ada"}),
+///     not(j::deserializes_as::<User>(anything()))
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Fails when the value does not deserialize as `T` or when the deserialized value does not
+/// satisfy the inner matcher.
+pub fn deserializes_as<T>(
+    inner: impl for<'a> Matcher<&'a T>,
+) -> JsonDeserializesAsMatcher<T, impl for<'a> Matcher<&'a T>>
+where
+    T: DeserializeOwned + Debug,
+{
+    JsonDeserializesAsMatcher::new(inner)
+}
+
+/// Matches a JSON value that deserializes into `T` and serializes back to the same JSON.
+///
+/// Catches schema drift such as fields `T` does not know about, renamed fields, or values that
+/// change shape on the way through. The explanation lists each path that was dropped, added or
+/// changed by the round trip.
+///
+/// Call `.ignoring_paths(&[...])` to skip paths (wildcards allowed, as in
+/// [`j::has_paths`](crate::json::has_paths)) and `.treating_null_as_missing()` to accept fields
+/// that are null on one side and absent on the other, e.g. `Option` fields with
+/// `skip_serializing_if`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use googletest::prelude::*;
+/// # use googletest_json_serde::json as j;
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_json::json;
+/// #[derive(Debug, Deserialize, Serialize)]
+/// struct User {
+///     id: u64,
+///     #[serde(skip_serializing_if = "Option::is_none")]
+///     nickname: Option<String>,
+/// }
+///
+/// assert_that!(json!({"id": 7}), j::round_trips::<User>());
+/// assert_that!(json!({"id": 7, "role": "admin"}), not(j::round_trips::<User>()));
+/// assert_that!(
+///     json!({"id": 7, "nickname": null}),
+///     j::round_trips::<User>().treating_null_as_missing()
+/// );
+/// ```
+///
+/// ```rust,should_panic
+/// # use googletest::prelude::*;
+/// # use googletest_json_serde::json as j;
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_json::json;
+/// # #[derive(Debug, Deserialize, Serialize)]
+/// # struct User {
+/// #     id: u64,
+/// # }
+/// // Fails with:
+/// //   which changes after a round trip through rust_out::User at 1 path:
+/// //     removed `role`: expected "admin"
+/// assert_that!(json!({"id": 7, "role": "admin"}), j::round_trips::<User>());
+/// ```
+///
+/// # Errors
+///
+/// Fails when an ignored path is invalid, when the value does not deserialize as `T`, when
+/// `T` fails to serialize, or when the serialized value differs from the original.
+pub fn round_trips<T>() -> JsonRoundTripMatcher<T>
+where
+    T: DeserializeOwned + Serialize,
+{
+    JsonRoundTripMatcher::new()
+}
+
 #[doc(hidden)]
 pub mod internal {
+    use crate::matcher_support::diff::{Difference, diff_by};
+    use crate::matcher_support::number;
+    use crate::matcher_support::path::{
+        ParsedPath, ParsedPaths, PathSegment, format_path, parse_path_specs,
+    };
     use crate::matchers::__internal_unstable_do_not_depend_on_these::describe_json_type;
     use crate::matchers::json_matcher::internal::JsonMatcher;
+    use crate::matchers::path_matcher::internal::JsonPathArg;
+    use crate::report::{JsonMismatch, report_mismatch};
     use googletest::description::Description;
     use googletest::matcher::{Matcher, MatcherBase, MatcherResult};
+    use serde::Serialize;
+    use serde::de::DeserializeOwned;
     use serde_json::{Map, Value};
+    use std::any::type_name;
+    use std::fmt::Debug;
     use std::marker::PhantomData;
 
     #[doc(hidden)]
-    #[derive(MatcherBase)]
+    #[derive(Clone, MatcherBase)]
     pub struct JsonAsMatcher<M, T> {
         inner: M,
-        pub(super) phantom: PhantomData<T>,
+        pub(super) phantom: PhantomData<fn() -> T>,
     }
 
     impl<M, T> JsonAsMatcher<M, T> {
@@ -369,4 +487,316 @@ pub mod internal {
             )
         }
     }
+
+    /// Deserializes the value into `T` before applying a native matcher; see
+    /// `j::deserializes_as`.
+    #[doc(hidden)]
+    #[derive(Clone, MatcherBase)]
+    pub struct JsonDeserializesAsMatcher<T, M> {
+        inner: M,
+        phantom: PhantomData<fn() -> T>,
+    }
+
+    impl<T, M> JsonDeserializesAsMatcher<T, M> {
+        pub fn new(inner: M) -> Self {
+            Self {
+                inner,
+                phantom: PhantomData,
+            }
+        }
+    }
+
+    /// A deserialization failure located within the actual value.
+    struct DeserializeError {
+        path: Vec<PathSegment>,
+        message: String,
+    }
+
+    fn deserialize<T: DeserializeOwned>(actual: &Value) -> Result<T, DeserializeError> {
+        serde_path_to_error::deserialize(actual).map_err(|err| {
+            let path = err
+                .path()
+                .iter()
+                .filter_map(|segment| match segment {
+                    serde_path_to_error::Segment::Seq { index } => Some(PathSegment::Index(*index)),
+                    serde_path_to_error::Segment::Map { key } => {
+                        Some(PathSegment::Field(key.clone()))
+                    }
+                    serde_path_to_error::Segment::Enum { .. }
+                    | serde_path_to_error::Segment::Unknown => None,
+                })
+                .collect();
+            DeserializeError {
+                path,
+                message: err.into_inner().to_string(),
+            }
+        })
+    }
+
+    impl DeserializeError {
+        fn explain<T>(&self) -> String {
+            if self.path.is_empty() {
+                format!(
+                    "which fails to deserialize as {}: {}",
+                    type_name::<T>(),
+                    self.message
+                )
+            } else {
+                format!(
+                    "which fails to deserialize as {} at `{}`: {}",
+                    type_name::<T>(),
+                    format_path(&self.path),
+                    self.message
+                )
+            }
+        }
+
+        /// Records the failure at the serde path, relative to `path`.
+        fn report<T>(self, actual: &Value, path: &[PathSegment], out: &mut Vec<JsonMismatch>) {
+            let located = self
+                .path
+                .iter()
+                .try_fold(actual, |value, segment| match segment {
+                    PathSegment::Field(key) => value.get(key),
+                    PathSegment::Index(index) => value.get(index),
+                });
+            out.push(JsonMismatch::new(
+                [path, &self.path].concat(),
+                format!("deserializes as {}", type_name::<T>()),
+                located,
+                self.message,
+            ));
+        }
+    }
+
+    impl<T, M> Matcher<&Value> for JsonDeserializesAsMatcher<T, M>
+    where
+        T: DeserializeOwned + Debug,
+        M: for<'a> Matcher<&'a T>,
+    {
+        fn matches(&self, actual: &Value) -> MatcherResult {
+            deserialize::<T>(actual)
+                .map_or(MatcherResult::NoMatch, |value| self.inner.matches(&value))
+        }
+
+        fn describe(&self, result: MatcherResult) -> Description {
+            format!(
+                "deserializes as {} which {}",
+                type_name::<T>(),
+                self.inner.describe(result)
+            )
+            .into()
+        }
+
+        fn explain_match(&self, actual: &Value) -> Description {
+            match deserialize::<T>(actual) {
+                Ok(value) => self.inner.explain_match(&value),
+                Err(err) => err.explain::<T>().into(),
+            }
+        }
+    }
+
+    impl<T, M> JsonMatcher for JsonDeserializesAsMatcher<T, M>
+    where
+        T: DeserializeOwned + Debug,
+        M: for<'a> Matcher<&'a T>,
+    {
+        fn collect_mismatches(
+            &self,
+            actual: &Value,
+            path: &mut Vec<PathSegment>,
+            out: &mut Vec<JsonMismatch>,
+        ) {
+            match deserialize::<T>(actual) {
+                Ok(_) => report_mismatch(self, actual, path, out),
+                Err(err) => err.report::<T>(actual, path, out),
+            }
+        }
+    }
+
+    /// How a value changed when it was deserialized and serialized again.
+    enum RoundTrip<'a> {
+        Invalid(&'a [String]),
+        Deserialize(DeserializeError),
+        Serialize(String),
+        Changed(Value),
+    }
+
+    /// Deserializes the value into `T` and compares it with its re-serialized form; see
+    /// `j::round_trips`.
+    #[doc(hidden)]
+    #[derive(Clone, MatcherBase)]
+    pub struct JsonRoundTripMatcher<T> {
+        ignored: Vec<ParsedPath>,
+        invalid: Vec<String>,
+        null_as_missing: bool,
+        phantom: PhantomData<fn() -> T>,
+    }
+
+    impl<T> Default for JsonRoundTripMatcher<T> {
+        fn default() -> Self {
+            Self {
+                ignored: Vec::new(),
+                invalid: Vec::new(),
+                null_as_missing: false,
+                phantom: PhantomData,
+            }
+        }
+    }
+
+    impl<T> JsonRoundTripMatcher<T> {
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Skips differences at or below the given paths; wildcards are allowed.
+        pub fn ignoring_paths<P: JsonPathArg>(mut self, paths: &[P]) -> Self {
+            let ParsedPaths { parsed, errors } = parse_path_specs(paths.iter().map(P::path_spec));
+            self.ignored.extend(parsed);
+            self.invalid.extend(errors);
+            self
+        }
+
+        /// Accepts fields that are null on one side of the round trip and absent on the other.
+        pub fn treating_null_as_missing(mut self) -> Self {
+            self.null_as_missing = true;
+            self
+        }
+
+        fn is_ignored(&self, difference: &Difference<'_>) -> bool {
+            let path = difference.path();
+            let ignored_path =
+                (0..=path.len()).any(|len| self.ignored.iter().any(|p| p.matches(&path[..len])));
+            let null_vs_missing = self.null_as_missing
+                && match difference {
+                    Difference::Added { actual, .. } => actual.is_null(),
+                    Difference::Removed { expected, .. } => expected.is_null(),
+                    Difference::Changed { .. } => false,
+                };
+            ignored_path || null_vs_missing
+        }
+    }
+
+    /// Compares leaves by value, so that `10` read into an `f64` and written back as `10.0` is
+    /// unchanged.
+    fn leaves_equal(original: &Value, reserialized: &Value) -> bool {
+        match (original, reserialized) {
+            (Value::Number(a), Value::Number(b)) => number::same_value(a, b),
+            _ => original == reserialized,
+        }
+    }
+
+    impl<T: DeserializeOwned + Serialize> JsonRoundTripMatcher<T> {
+        fn round_trip(&self, actual: &Value) -> Result<(), RoundTrip<'_>> {
+            if !self.invalid.is_empty() {
+                return Err(RoundTrip::Invalid(&self.invalid));
+            }
+            let value = deserialize::<T>(actual).map_err(RoundTrip::Deserialize)?;
+            let reserialized =
+                serde_json::to_value(value).map_err(|err| RoundTrip::Serialize(err.to_string()))?;
+            let unchanged = diff_by(actual, &reserialized, &leaves_equal)
+                .iter()
+                .all(|difference| self.is_ignored(difference));
+            if unchanged {
+                Ok(())
+            } else {
+                Err(RoundTrip::Changed(reserialized))
+            }
+        }
+
+        fn differences<'a>(
+            &self,
+            actual: &'a Value,
+            reserialized: &'a Value,
+        ) -> Vec<Difference<'a>> {
+            diff_by(actual, reserialized, &leaves_equal)
+                .into_iter()
+                .filter(|difference| !self.is_ignored(difference))
+                .collect()
+        }
+    }
+
+    impl<T: DeserializeOwned + Serialize> Matcher<&Value> for JsonRoundTripMatcher<T> {
+        fn matches(&self, actual: &Value) -> MatcherResult {
+            self.round_trip(actual).is_ok().into()
+        }
+
+        fn describe(&self, result: MatcherResult) -> Description {
+            match result {
+                MatcherResult::Match => {
+                    format!("round-trips through {} unchanged", type_name::<T>()).into()
+                }
+                MatcherResult::NoMatch => {
+                    format!("doesn't round-trip through {} unchanged", type_name::<T>()).into()
+                }
+            }
+        }
+
+        fn explain_match(&self, actual: &Value) -> Description {
+            let reserialized = match self.round_trip(actual) {
+                Ok(()) => {
+                    return format!("which round-trips through {}", type_name::<T>()).into();
+                }
+                Err(RoundTrip::Invalid(errors)) => {
+                    return format!("invalid paths {errors:?}").into();
+                }
+                Err(RoundTrip::Deserialize(err)) => return err.explain::<T>().into(),
+                Err(RoundTrip::Serialize(err)) => {
+                    return format!(
+                        "which fails to serialize back from {}: {err}",
+                        type_name::<T>()
+                    )
+                    .into();
+                }
+                Err(RoundTrip::Changed(reserialized)) => reserialized,
+            };
+            let differences = self.differences(actual, &reserialized);
+            let count = match differences.len() {
+                1 => "1 path".to_string(),
+                n => format!("{n} paths"),
+            };
+            format!(
+                "which changes after a round trip through {} at {count}:\n{}",
+                type_name::<T>(),
+                differences
+                    .iter()
+                    .map(|d| format!("  {}", d.render()))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            )
+            .into()
+        }
+    }
+
+    impl<T: DeserializeOwned + Serialize> JsonMatcher for JsonRoundTripMatcher<T> {
+        fn collect_mismatches(
+            &self,
+            actual: &Value,
+            path: &mut Vec<PathSegment>,
+            out: &mut Vec<JsonMismatch>,
+        ) {
+            match self.round_trip(actual) {
+                Ok(()) => {}
+                Err(RoundTrip::Deserialize(err)) => err.report::<T>(actual, path, out),
+                Err(RoundTrip::Invalid(_) | RoundTrip::Serialize(_)) => {
+                    report_mismatch(self, actual, path, out)
+                }
+                Err(RoundTrip::Changed(reserialized)) => {
+                    for difference in self.differences(actual, &reserialized) {
+                        let original = match &difference {
+                            Difference::Removed { expected, .. }
+                            | Difference::Changed { expected, .. } => Some(*expected),
+                            Difference::Added { .. } => None,
+                        };
+                        out.push(JsonMismatch::new(
+                            [path.as_slice(), difference.path()].concat(),
+                            format!("round-trips through {}", type_name::<T>()),
+                            original,
+                            difference.render(),
+                        ));
+                    }
+                }
+            }
+        }
+    }
 }
@@ -0,0 +1,204 @@
+/// Matches the nodes selected by a JSONPath query against a JSON matcher.
+///
+/// The query is evaluated against the actual value and every selected node is collected, in
+/// document order, into a JSON array that is passed to the inner matcher. Combine it with
+/// array matchers such as [`each!`](crate::json::each), [`len!`](crate::json::len) or
+/// [`elements_are!`](crate::json::elements_are). Explanations name the selected path of each
+/// failing element, and [`check`](crate::json::check) reports element failures at those paths.
+///
+/// Supported syntax:
+/// - Root `$`, child `.name` / `['name']`, index `[0]` / `[-1]`, and unions `[0,2]` / `['a','b']`
+/// - Wildcards `.*` / `[*]` and recursive descent `..name` / `..*`
+/// - Slices `[start:end:step]`
+/// - Filters `[?(@.field op literal)]` with `==`, `!=`, `<`, `<=`, `>`, `>=`, existence
+///   checks `[?(@.field)]`, `!`, `&&`, `||` and parentheses
+///
+/// # Examples
+///
+/// ```rust
+/// # use googletest::prelude::*;
+/// # use googletest_json_serde::json as j;
+/// # use serde_json::json;
+/// let value = json!({
+///     "orders": [
+///         {"status": "open", "total": 12},
+///         {"status": "closed", "total": 0},
+///         {"status": "open", "total": 3}
+///     ]
+/// });
+/// assert_that!(value, j::at!("$.orders[?(@.status == 'open')].total", j::each!(gt(0))));
+/// assert_that!(value, j::at!("$..total", j::len!(3)));
+/// assert_that!(value, j::at!("$.orders[-1].total", j::elements_are![3]));
+/// ```
+///
+/// ```rust,should_panic
+/// # use googletest::prelude::*;
+/// # use googletest_json_serde::json as j;
+/// # use serde_json::json;
+/// // Fails with:
+/// //   which selected 2 nodes at `$.orders[0].total`, `$.orders[1].total`, ...
+/// //   where element #1 is `$.orders[1].total`
+/// assert_that!(
+///     json!({"orders": [{"total": 12}, {"total": 0}]}),
+///     j::at!("$.orders[*].total", j::each!(gt(0)))
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Fails when the query cannot be parsed; the explanation names the offending position.
+///
+/// # Supported Inputs
+/// - Literal JSON-compatible values
+/// - Direct `serde_json::Value`
+/// - Native googletest matchers
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __json_at {
+    ($query:expr, $inner:expr $(,)?) => {
+        $crate::matchers::__internal_unstable_do_not_depend_on_these::JsonAtMatcher::new(
+            $query,
+            $crate::__json_into_matcher!($inner),
+        )
+    };
+}
+
+#[doc(hidden)]
+pub mod internal {
+    use crate::matcher_support::json_path::{JsonPath, Node, format_json_path};
+    use crate::matcher_support::path::PathSegment;
+    use crate::matchers::json_matcher::internal::{JsonMatcher, SharedMatcher};
+    use crate::report::{JsonMismatch, report_mismatch};
+    use googletest::description::Description;
+    use googletest::matcher::{Matcher, MatcherBase, MatcherResult};
+    use serde_json::Value;
+
+    #[doc(hidden)]
+    #[derive(Clone, MatcherBase)]
+    pub struct JsonAtMatcher<C = SharedMatcher> {
+        raw: String,
+        query: Result<JsonPath, String>,
+        inner: C,
+    }
+
+    impl<C: JsonMatcher> JsonAtMatcher<C> {
+        pub fn new(query: impl Into<String>, inner: C) -> Self {
+            let raw = query.into();
+            let query = JsonPath::parse(&raw);
+            Self { raw, query, inner }
+        }
+
+        /// Runs the inner matcher on the selected nodes and moves each mismatch from its
+        /// element of the selection to the path of the node it came from.
+        ///
+        /// Each mismatch comes with the index of its element; also returns whether any mismatch
+        /// concerns the selection as a whole.
+        fn inner_mismatches(&self, nodes: &[Node<'_>]) -> (Vec<(usize, JsonMismatch)>, bool) {
+            let mut mismatches = Vec::new();
+            self.inner
+                .collect_mismatches(&selection(nodes), &mut Vec::new(), &mut mismatches);
+            let mut located = Vec::new();
+            let mut whole = false;
+            for mismatch in mismatches {
+                match mismatch.path().split_first() {
+                    Some((PathSegment::Index(idx), rest)) if *idx < nodes.len() => {
+                        let mut node_path = nodes[*idx].0.clone();
+                        node_path.extend_from_slice(rest);
+                        located.push((*idx, mismatch.relocated(node_path)));
+                    }
+                    _ => whole = true,
+                }
+            }
+            (located, whole)
+        }
+    }
+
+    impl<C: JsonMatcher> JsonMatcher for JsonAtMatcher<C> {
+        fn collect_mismatches(
+            &self,
+            actual: &Value,
+            path: &mut Vec<PathSegment>,
+            out: &mut Vec<JsonMismatch>,
+        ) {
+            if self.matches(actual).is_match() {
+                return;
+            }
+            let Ok(query) = &self.query else {
+                return report_mismatch(self, actual, path, out);
+            };
+            let nodes = query.select(actual);
+            let (located, whole) = self.inner_mismatches(&nodes);
+            for (_, mismatch) in located {
+                let mut located_path = path.clone();
+                located_path.extend_from_slice(mismatch.path());
+                out.push(mismatch.relocated(located_path));
+            }
+            // Mismatches of the selection as a whole, e.g. its length, belong to the query.
+            if whole {
+                report_mismatch(self, actual, path, out);
+            }
+        }
+    }
+
+    /// The selected nodes as the JSON array the inner matcher sees.
+    fn selection(nodes: &[Node<'_>]) -> Value {
+        Value::Array(nodes.iter().map(|(_, v)| (*v).clone()).collect())
+    }
+
+    impl<C: JsonMatcher> Matcher<&Value> for JsonAtMatcher<C> {
+        fn matches(&self, actual: &Value) -> MatcherResult {
+            let Ok(query) = &self.query else {
+                return MatcherResult::NoMatch;
+            };
+            self.inner.matches(&selection(&query.select(actual)))
+        }
+
+        fn describe(&self, result: MatcherResult) -> Description {
+            format!(
+                "has nodes at JSONPath `{}`, which {}",
+                self.raw,
+                self.inner.describe(result)
+            )
+            .into()
+        }
+
+        fn explain_match(&self, actual: &Value) -> Description {
+            let query = match &self.query {
+                Ok(query) => query,
+                Err(err) => return Description::new().text(format!("which is an {err}")),
+            };
+            let nodes = query.select(actual);
+            let paths = nodes
+                .iter()
+                .map(|(path, _)| format!("`{}`", format_json_path(path)))
+                .collect::<Vec<_>>();
+            let located = match paths.len() {
+                0 => "which selected no nodes".to_string(),
+                1 => format!("which selected 1 node at {}", paths[0]),
+                n => format!("which selected {n} nodes at {}", paths.join(", ")),
+            };
+            let explanation = format!(
+                "{located}, {}",
+                self.inner.explain_match(&selection(&nodes))
+            );
+            if nodes.len() < 2 || self.inner.matches(&selection(&nodes)).is_match() {
+                return explanation.into();
+            }
+            let mut failing = self
+                .inner_mismatches(&nodes)
+                .0
+                .into_iter()
+                .map(|(idx, _)| idx)
+                .collect::<Vec<_>>();
+            failing.dedup();
+            if failing.is_empty() {
+                return explanation.into();
+            }
+            let failing = failing
+                .iter()
+                .map(|&idx| format!("element #{idx} is `{}`", format_json_path(&nodes[idx].0)))
+                .collect::<Vec<_>>();
+            format!("{explanation}\nwhere {}", failing.join(", ")).into()
+        }
+    }
+}
@@ -0,0 +1,495 @@
+//! Matchers that scan every node below the root of a JSON document.
+//!
+//! Descendants are all values reachable from the root through object fields and array
+//! elements, excluding the root itself. Explanations list the matched or failing nodes by
+//! path in dot notation.
+//!
+//! | Matcher | passes when |
+//! | --- | --- |
+//! | `j::no_descendant!(m)` | no descendant matches `m` |
+//! | `j::has_descendant!(m)` | some descendant matches `m` |
+//! | `j::descendants_matching!(m, n)` | the number of descendants matching `m` matches `n` |
+//! | `j::all_leaves!(m)` | every scalar leaf matches `m` |
+//! | `j::no_key_anywhere(&[..])` | no object at any depth has one of the keys |
+
+use crate::matchers::__internal_unstable_do_not_depend_on_these::JsonNoKeyAnywhereMatcher;
+
+/// Matches a JSON value where no descendant, at any depth, satisfies the given matcher.
+///
+/// Every offending node is listed by path, and [`j::check`](crate::json::check) reports one
+/// mismatch per node.
+///
+/// # Examples
+///
+/// ```rust
+/// # use googletest::prelude::*;
+/// # use googletest_json_serde::json as j;
+/// # use serde_json::json;
+/// let response = json!({"user": {"name": "Ada", "cards": ["****1111"]}});
+/// assert_that!(response, j::no_descendant!(matches_regex("[0-9]{16}")));
+/// ```
+///
+/// ```rust,should_panic
+/// # use googletest::prelude::*;
+/// # use googletest_json_serde::json as j;
+/// # use serde_json::json;
+/// // Fails with:
+/// //   which has 1 matching descendant at `user.cards.0`
+/// assert_that!(
+///     json!({"user": {"cards": ["4111111111111111"]}}),
+///     j::no_descendant!(matches_regex("[0-9]{16}"))
+/// );
+/// ```
+///
+/// # Supported Inputs
+/// - Literal JSON-compatible values
+/// - Direct `serde_json::Value`
+/// - Native googletest matchers
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __json_no_descendant {
+    ($inner:expr $(,)?) => {{
+        $crate::matchers::__internal_unstable_do_not_depend_on_these::JsonDescendantMatcher::none(
+            $crate::__json_into_matcher!($inner),
+        )
+    }};
+}
+
+/// Matches a JSON value where at least one descendant, at any depth, satisfies the given matcher.
+///
+/// # Examples
+///
+/// ```rust
+/// # use googletest::prelude::*;
+/// # use googletest_json_serde::json as j;
+/// # use serde_json::json;
+/// let value = json!({"order": {"lines": [{"sku": "A-1"}, {"sku": "B-2", "gift": true}]}});
+/// assert_that!(value, j::has_descendant!(j::pat!({"gift": true, ..})));
+/// assert_that!(value, not(j::has_descendant!("C-3")));
+/// ```
+///
+/// # Supported Inputs
+/// - Literal JSON-compatible values
+/// - Direct `serde_json::Value`
+/// - Native googletest matchers
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __json_has_descendant {
+    ($inner:expr $(,)?) => {{
+        $crate::matchers::__internal_unstable_do_not_depend_on_these::JsonDescendantMatcher::any(
+            $crate::__json_into_matcher!($inner),
+        )
+    }};
+}
+
+/// Matches a JSON value whose scalar leaves (null, boolean, number and string values) all
+/// satisfy the given matcher.
+///
+/// Arrays and objects are traversed but never passed to the matcher; a scalar root is its own
+/// only leaf. A document without leaves matches vacuously. Each failing leaf is explained by
+/// path, and [`j::check`](crate::json::check) reports the inner matcher's mismatches there.
+///
+/// # Examples
+///
+/// ```rust
+/// # use googletest::prelude::*;
+/// # use googletest_json_serde::json as j;
+/// # use serde_json::json;
+/// let trimmed = j::predicate(|v| v.as_str().is_none_or(|s| s.trim() == s));
+/// assert_that!(json!({"name": "Ada", "tags": ["x", 1, null]}), j::all_leaves!(trimmed));
+/// ```
+///
+/// ```rust,should_panic
+/// # use googletest::prelude::*;
+/// # use googletest_json_serde::json as j;
+/// # use serde_json::json;
+/// // Fails with:
+/// //   which has 1 failing leaf at `tags.1`, which isn't equal to String("y")
+/// assert_that!(json!({"tags": ["y", " y"]}), j::all_leaves!("y"));
+/// ```
+///
+/// # Supported Inputs
+/// - Literal JSON-compatible values
+/// - Direct `serde_json::Value`
+/// - Native googletest matchers
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __json_all_leaves {
+    ($inner:expr $(,)?) => {{
+        $crate::matchers::__internal_unstable_do_not_depend_on_these::JsonDescendantMatcher::all_leaves(
+            $crate::__json_into_matcher!($inner),
+        )
+    }};
+}
+
+/// Matches a JSON value by the number of descendants, at any depth, that satisfy the given
+/// matcher.
+///
+/// The count may be a literal or a matcher such as `ge(3)`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use googletest::prelude::*;
+/// # use googletest_json_serde::json as j;
+/// # use serde_json::json;
+/// let value = json!({"items": [{"id": 1}, {"id": 2}, {"id": 3, "children": [{"id": 4}]}]});
+/// assert_that!(value, j::descendants_matching!(j::pat!({"id": j::is_integer(), ..}), 4));
+/// assert_that!(value, j::descendants_matching!(j::is_object(), ge(3)));
+/// ```
+///
+/// # Supported Inputs
+/// - Literal JSON-compatible values
+/// - Direct `serde_json::Value`
+/// - Native googletest matchers
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __json_descendants_matching {
+    ($inner:expr, $count:literal $(,)?) => {{
+        $crate::matchers::__internal_unstable_do_not_depend_on_these::JsonDescendantMatcher::count(
+            $crate::__json_into_matcher!($inner),
+            $crate::__json_into_matcher!(::googletest::matchers::eq($count)),
+        )
+    }};
+    ($inner:expr, $count:expr $(,)?) => {{
+        $crate::matchers::__internal_unstable_do_not_depend_on_these::JsonDescendantMatcher::count(
+            $crate::__json_into_matcher!($inner),
+            $crate::__json_into_matcher!($count),
+        )
+    }};
+}
+
+/// Matches a JSON value where no object, at any depth, has one of the given keys.
+///
+/// Every offending field is listed by path, and [`j::check`](crate::json::check) reports one
+/// mismatch per field.
+///
+/// # Examples
+///
+/// ```rust
+/// # use googletest::prelude::*;
+/// # use googletest_json_serde::json as j;
+/// # use serde_json::json;
+/// let response = json!({"user": {"name": "Ada", "sessions": [{"id": 1}]}});
+/// assert_that!(response, j::no_key_anywhere(&["password", "secret"]));
+/// assert_that!(
+///     json!({"user": {"sessions": [{"secret": "x"}]}}),
+///     not(j::no_key_anywhere(&["password", "secret"]))
+/// );
+/// ```
+pub fn no_key_anywhere(keys: &[&str]) -> JsonNoKeyAnywhereMatcher {
+    JsonNoKeyAnywhereMatcher::new(keys.iter().map(|key| key.to_string()).collect())
+}
+
+#[doc(hidden)]
+pub mod internal {
+    use crate::matcher_support::diff::display_path;
+    use crate::matcher_support::path::{PathSegment, walk};
+    use crate::matchers::json_matcher::internal::{JsonMatcher, SharedMatcher};
+    use crate::report::{JsonMismatch, report_mismatch};
+    use googletest::description::Description;
+    use googletest::matcher::{Matcher, MatcherBase, MatcherResult};
+    use serde_json::Value;
+
+    /// Lists `paths` after a count of `singular`/`plural` nodes.
+    fn located(singular: &str, plural: &str, paths: &[Vec<PathSegment>]) -> String {
+        let listed = paths
+            .iter()
+            .map(|path| display_path(path))
+            .collect::<Vec<_>>()
+            .join(", ");
+        match paths.len() {
+            0 => format!("which has no {plural}"),
+            1 => format!("which has 1 {singular} at {listed}"),
+            n => format!("which has {n} {plural} at {listed}"),
+        }
+    }
+
+    #[derive(Clone)]
+    enum Quantifier<D> {
+        /// No descendant may match; see `j::no_descendant!`.
+        None,
+        /// At least one descendant must match; see `j::has_descendant!`.
+        Any,
+        /// Every scalar leaf must match; see `j::all_leaves!`.
+        AllLeaves,
+        /// The number of matching descendants must match; see `j::descendants_matching!`.
+        Count(D),
+    }
+
+    /// Applies a matcher to every node below the root under a quantifier.
+    #[doc(hidden)]
+    #[derive(Clone, MatcherBase)]
+    pub struct JsonDescendantMatcher<C = SharedMatcher, D = SharedMatcher> {
+        inner: C,
+        quantifier: Quantifier<D>,
+    }
+
+    impl<C: JsonMatcher> JsonDescendantMatcher<C> {
+        pub fn none(inner: C) -> Self {
+            Self {
+                inner,
+                quantifier: Quantifier::None,
+            }
+        }
+
+        pub fn any(inner: C) -> Self {
+            Self {
+                inner,
+                quantifier: Quantifier::Any,
+            }
+        }
+
+        pub fn all_leaves(inner: C) -> Self {
+            Self {
+                inner,
+                quantifier: Quantifier::AllLeaves,
+            }
+        }
+    }
+
+    impl<C: JsonMatcher, D: JsonMatcher> JsonDescendantMatcher<C, D> {
+        pub fn count(inner: C, count: D) -> Self {
+            Self {
+                inner,
+                quantifier: Quantifier::Count(count),
+            }
+        }
+
+        /// The nodes the quantifier ranges over: scalar leaves (including a scalar root) for
+        /// `AllLeaves`, every descendant otherwise.
+        fn nodes<'a>(&self, actual: &'a Value) -> Vec<(Vec<PathSegment>, &'a Value)> {
+            let leaves_only = matches!(self.quantifier, Quantifier::AllLeaves);
+            let is_leaf = |value: &Value| !value.is_array() && !value.is_object();
+            let mut nodes = Vec::new();
+            if leaves_only && is_leaf(actual) {
+                nodes.push((Vec::new(), actual));
+            }
+            walk(actual, &mut |path, value| {
+                if !leaves_only || is_leaf(value) {
+                    nodes.push((path.to_vec(), value));
+                }
+            });
+            nodes
+        }
+
+        /// The nodes for which the inner matcher returns `result`.
+        fn nodes_where<'a>(
+            &self,
+            actual: &'a Value,
+            result: MatcherResult,
+        ) -> Vec<(Vec<PathSegment>, &'a Value)> {
+            self.nodes(actual)
+                .into_iter()
+                .filter(|(_, value)| self.inner.matches(value) == result)
+                .collect()
+        }
+
+        fn paths_where(&self, actual: &Value, result: MatcherResult) -> Vec<Vec<PathSegment>> {
+            self.nodes_where(actual, result)
+                .into_iter()
+                .map(|(path, _)| path)
+                .collect()
+        }
+    }
+
+    impl<C: JsonMatcher, D: JsonMatcher> Matcher<&Value> for JsonDescendantMatcher<C, D> {
+        fn matches(&self, actual: &Value) -> MatcherResult {
+            match &self.quantifier {
+                Quantifier::None => self.nodes_where(actual, MatcherResult::Match).is_empty(),
+                Quantifier::Any => !self.nodes_where(actual, MatcherResult::Match).is_empty(),
+                Quantifier::AllLeaves => {
+                    self.nodes_where(actual, MatcherResult::NoMatch).is_empty()
+                }
+                Quantifier::Count(count) => {
+                    let found = self.nodes_where(actual, MatcherResult::Match).len();
+                    return count.matches(&Value::from(found));
+                }
+            }
+            .into()
+        }
+
+        fn describe(&self, result: MatcherResult) -> Description {
+            let inner = self.inner.describe(MatcherResult::Match);
+            match (&self.quantifier, result) {
+                (Quantifier::None, MatcherResult::Match)
+                | (Quantifier::Any, MatcherResult::NoMatch) => {
+                    format!("has no descendant that {inner}").into()
+                }
+                (Quantifier::None, MatcherResult::NoMatch)
+                | (Quantifier::Any, MatcherResult::Match) => {
+                    format!("has a descendant that {inner}").into()
+                }
+                (Quantifier::AllLeaves, MatcherResult::Match) => {
+                    format!("has only scalar leaves that {inner}").into()
+                }
+                (Quantifier::AllLeaves, MatcherResult::NoMatch) => format!(
+                    "has a scalar leaf that {}",
+                    self.inner.describe(MatcherResult::NoMatch)
+                )
+                .into(),
+                (Quantifier::Count(count), result) => format!(
+                    "has a number of descendants that {inner}, which {}",
+                    count.describe(result)
+                )
+                .into(),
+            }
+        }
+
+        fn explain_match(&self, actual: &Value) -> Description {
+            match &self.quantifier {
+                Quantifier::None | Quantifier::Any => located(
+                    "matching descendant",
+                    "matching descendants",
+                    &self.paths_where(actual, MatcherResult::Match),
+                )
+                .into(),
+                Quantifier::Count(count) => {
+                    let paths = self.paths_where(actual, MatcherResult::Match);
+                    let found = Value::from(paths.len());
+                    format!(
+                        "{}, {}",
+                        located("matching descendant", "matching descendants", &paths),
+                        count.explain_match(&found)
+                    )
+                    .into()
+                }
+                Quantifier::AllLeaves => {
+                    let failing = self.nodes_where(actual, MatcherResult::NoMatch);
+                    match failing.as_slice() {
+                        [] => located("failing leaf", "failing leaves", &[]).into(),
+                        [(path, value)] => format!(
+                            "which has 1 failing leaf at {}, {}",
+                            display_path(path),
+                            self.inner.explain_match(value)
+                        )
+                        .into(),
+                        _ => format!(
+                            "which has {} failing leaves:\n{}",
+                            failing.len(),
+                            failing
+                                .iter()
+                                .map(|(path, value)| format!(
+                                    "{}: {}",
+                                    display_path(path),
+                                    self.inner.explain_match(value)
+                                ))
+                                .collect::<Description>()
+                                .bullet_list()
+                                .indent()
+                        )
+                        .into(),
+                    }
+                }
+            }
+        }
+    }
+
+    impl<C: JsonMatcher, D: JsonMatcher> JsonMatcher for JsonDescendantMatcher<C, D> {
+        fn collect_mismatches(
+            &self,
+            actual: &Value,
+            path: &mut Vec<PathSegment>,
+            out: &mut Vec<JsonMismatch>,
+        ) {
+            match &self.quantifier {
+                Quantifier::None => {
+                    for (relative, value) in self.nodes_where(actual, MatcherResult::Match) {
+                        out.push(JsonMismatch::new(
+                            [path.as_slice(), &relative].concat(),
+                            self.inner.describe(MatcherResult::NoMatch),
+                            Some(value),
+                            "forbidden value",
+                        ));
+                    }
+                }
+                Quantifier::AllLeaves => {
+                    for (relative, value) in self.nodes_where(actual, MatcherResult::NoMatch) {
+                        let depth = path.len();
+                        path.extend(relative);
+                        self.inner.collect_mismatches(value, path, out);
+                        path.truncate(depth);
+                    }
+                }
+                Quantifier::Any | Quantifier::Count(_) => report_mismatch(self, actual, path, out),
+            }
+        }
+    }
+
+    /// Fails when any object at any depth has a forbidden key; see `j::no_key_anywhere`.
+    #[doc(hidden)]
+    #[derive(Clone, MatcherBase)]
+    pub struct JsonNoKeyAnywhereMatcher {
+        keys: Vec<String>,
+    }
+
+    impl JsonNoKeyAnywhereMatcher {
+        pub fn new(keys: Vec<String>) -> Self {
+            Self { keys }
+        }
+
+        fn offenders<'a>(&self, actual: &'a Value) -> Vec<(Vec<PathSegment>, &'a Value)> {
+            let mut found = Vec::new();
+            walk(actual, &mut |path, value| {
+                if let Some(PathSegment::Field(key)) = path.last()
+                    && self.keys.contains(key)
+                {
+                    found.push((path.to_vec(), value));
+                }
+            });
+            found
+        }
+
+        fn quoted_keys(&self) -> String {
+            self.keys
+                .iter()
+                .map(|key| format!("'{key}'"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        }
+    }
+
+    impl Matcher<&Value> for JsonNoKeyAnywhereMatcher {
+        fn matches(&self, actual: &Value) -> MatcherResult {
+            self.offenders(actual).is_empty().into()
+        }
+
+        fn describe(&self, result: MatcherResult) -> Description {
+            match result {
+                MatcherResult::Match => {
+                    format!("has none of the keys {} at any depth", self.quoted_keys()).into()
+                }
+                MatcherResult::NoMatch => {
+                    format!("has one of the keys {} at some depth", self.quoted_keys()).into()
+                }
+            }
+        }
+
+        fn explain_match(&self, actual: &Value) -> Description {
+            let paths = self
+                .offenders(actual)
+                .into_iter()
+                .map(|(path, _)| path)
+                .collect::<Vec<_>>();
+            located("forbidden key", "forbidden keys", &paths).into()
+        }
+    }
+
+    impl JsonMatcher for JsonNoKeyAnywhereMatcher {
+        fn collect_mismatches(
+            &self,
+            actual: &Value,
+            path: &mut Vec<PathSegment>,
+            out: &mut Vec<JsonMismatch>,
+        ) {
+            for (relative, value) in self.offenders(actual) {
+                out.push(JsonMismatch::new(
+                    [path.as_slice(), &relative].concat(),
+                    "no such field",
+                    Some(value),
+                    "forbidden key",
+                ));
+            }
+        }
+    }
+}
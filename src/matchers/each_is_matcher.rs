@@ -16,8 +16,8 @@ fn describe_kind(value: &Value) -> &'static str {
 
 fn build_each_is_type(
     kind: &'static str,
-    predicate: impl Fn(&Value) -> bool + Copy + 'static,
-) -> JsonPredicateMatcher<impl Fn(&Value) -> bool, String, &'static str> {
+    predicate: impl Fn(&Value) -> bool + Copy + Send + Sync + 'static,
+) -> JsonPredicateMatcher<impl Fn(&Value) -> bool + Clone + Send + Sync, String, &'static str> {
     JsonPredicateMatcher::new(
         move |v| match v {
             Value::Array(a) => a.iter().all(predicate),
@@ -54,7 +54,8 @@ fn build_each_is_type(
 /// assert_that!(json!( ["a", "b" ]), j::each_is_string());
 /// assert_that!(json!([1, "b"]), not(j::each_is_string()));
 /// ```
-pub fn each_is_string() -> JsonPredicateMatcher<impl Fn(&Value) -> bool, String, &'static str> {
+pub fn each_is_string()
+-> JsonPredicateMatcher<impl Fn(&Value) -> bool + Clone + Send + Sync, String, &'static str> {
     build_each_is_type("JSON string", |v| v.is_string())
 }
 
@@ -69,7 +70,8 @@ pub fn each_is_string() -> JsonPredicateMatcher<impl Fn(&Value) -> bool, String,
 /// assert_that!(json!([1, 2, 3]), j::each_is_number());
 /// assert_that!(json!([1, "b"]), not(j::each_is_number()));
 /// ```
-pub fn each_is_number() -> JsonPredicateMatcher<impl Fn(&Value) -> bool, String, &'static str> {
+pub fn each_is_number()
+-> JsonPredicateMatcher<impl Fn(&Value) -> bool + Clone + Send + Sync, String, &'static str> {
     build_each_is_type("JSON number", |v| v.is_number())
 }
 
@@ -84,7 +86,8 @@ pub fn each_is_number() -> JsonPredicateMatcher<impl Fn(&Value) -> bool, String,
 /// assert_that!(json!([true, false]), j::each_is_boolean());
 /// assert_that!(json!([true, 1]), not(j::each_is_boolean()));
 /// ```
-pub fn each_is_boolean() -> JsonPredicateMatcher<impl Fn(&Value) -> bool, String, &'static str> {
+pub fn each_is_boolean()
+-> JsonPredicateMatcher<impl Fn(&Value) -> bool + Clone + Send + Sync, String, &'static str> {
     build_each_is_type("JSON boolean", |v| v.is_boolean())
 }
 
@@ -99,7 +102,8 @@ pub fn each_is_boolean() -> JsonPredicateMatcher<impl Fn(&Value) -> bool, String
 /// assert_that!(json!([null, null]), j::each_is_null());
 /// assert_that!(json!([null, true]), not(j::each_is_null()));
 /// ```
-pub fn each_is_null() -> JsonPredicateMatcher<impl Fn(&Value) -> bool, String, &'static str> {
+pub fn each_is_null()
+-> JsonPredicateMatcher<impl Fn(&Value) -> bool + Clone + Send + Sync, String, &'static str> {
     build_each_is_type("JSON null", |v| v.is_null())
 }
 
@@ -114,7 +118,8 @@ pub fn each_is_null() -> JsonPredicateMatcher<impl Fn(&Value) -> bool, String, &
 /// assert_that!(json!([[1], [2]]), j::each_is_array());
 /// assert_that!(json!([[1], {"a": 1}]), not(j::each_is_array()));
 /// ```
-pub fn each_is_array() -> JsonPredicateMatcher<impl Fn(&Value) -> bool, String, &'static str> {
+pub fn each_is_array()
+-> JsonPredicateMatcher<impl Fn(&Value) -> bool + Clone + Send + Sync, String, &'static str> {
     build_each_is_type("JSON array", |v| v.is_array())
 }
 
@@ -129,6 +134,7 @@ pub fn each_is_array() -> JsonPredicateMatcher<impl Fn(&Value) -> bool, String,
 /// assert_that!(json!([{ "a": 1 }, { "b": 2 }]), j::each_is_object());
 /// assert_that!(json!([{ "a": 1 }, [1]]), not(j::each_is_object()));
 /// ```
-pub fn each_is_object() -> JsonPredicateMatcher<impl Fn(&Value) -> bool, String, &'static str> {
+pub fn each_is_object()
+-> JsonPredicateMatcher<impl Fn(&Value) -> bool + Clone + Send + Sync, String, &'static str> {
     build_each_is_type("JSON object", |v| v.is_object())
 }
@@ -25,30 +25,48 @@
 macro_rules! __json_each {
     ($inner:expr) => {
         $crate::matchers::__internal_unstable_do_not_depend_on_these::JsonEachMatcher::new(
-            $crate::matchers::__internal_unstable_do_not_depend_on_these::IntoJsonMatcher::into_json_matcher($inner)
+            $crate::__json_into_matcher!($inner),
         )
     };
 }
 
 pub mod internal {
-    use crate::matchers::__internal_unstable_do_not_depend_on_these::JsonMatcher;
+    use crate::matcher_support::path::PathSegment;
+    use crate::matchers::__internal_unstable_do_not_depend_on_these::{JsonMatcher, SharedMatcher};
+    use crate::report::{JsonMismatch, report_mismatch};
     use googletest::description::Description;
     use googletest::matcher::{Matcher, MatcherBase, MatcherResult};
     use serde_json::Value;
 
-    #[derive(MatcherBase)]
-    pub struct JsonEachMatcher {
-        inner: Box<dyn JsonMatcher>,
+    #[derive(Clone, MatcherBase)]
+    pub struct JsonEachMatcher<C = SharedMatcher> {
+        inner: C,
     }
 
-    impl JsonEachMatcher {
-        pub fn new(inner: Box<dyn JsonMatcher>) -> Self {
+    impl<C: JsonMatcher> JsonEachMatcher<C> {
+        pub fn new(inner: C) -> Self {
             Self { inner }
         }
     }
 
-    impl JsonMatcher for JsonEachMatcher {}
-    impl Matcher<&Value> for JsonEachMatcher {
+    impl<C: JsonMatcher> JsonMatcher for JsonEachMatcher<C> {
+        fn collect_mismatches(
+            &self,
+            actual: &Value,
+            path: &mut Vec<PathSegment>,
+            out: &mut Vec<JsonMismatch>,
+        ) {
+            let Value::Array(arr) = actual else {
+                return report_mismatch(self, actual, path, out);
+            };
+            for (index, item) in arr.iter().enumerate() {
+                path.push(PathSegment::Index(index));
+                self.inner.collect_mismatches(item, path, out);
+                path.pop();
+            }
+        }
+    }
+    impl<C: JsonMatcher> Matcher<&Value> for JsonEachMatcher<C> {
         fn matches(&self, actual: &Value) -> MatcherResult {
             let arr = match actual {
                 Value::Array(a) => a,
@@ -47,11 +47,11 @@
 macro_rules! __json_elements_are {
     // Preferred bracketed form: __json_elements_are!([ m1, m2, ... ])
     ([$($matcher:expr),* $(,)?]) => {{
-        $crate::matchers::__internal_unstable_do_not_depend_on_these::JsonElementsAre::new(vec![
-            $(
-                $crate::matchers::__internal_unstable_do_not_depend_on_these::IntoJsonMatcher::into_json_matcher($matcher)
-            ),*
-        ])
+        $crate::matchers::__internal_unstable_do_not_depend_on_these::JsonElementsAre::new(
+            $crate::matchers::__internal_unstable_do_not_depend_on_these::Children::new()
+                $(.push($crate::__json_into_matcher!($matcher)))*
+                .into_vec(),
+        )
     }};
     // Convenience: allow unbracketed list and forward to the bracketed arm.
     ($($matcher:expr),* $(,)?) => {{
@@ -61,26 +61,47 @@ macro_rules! __json_elements_are {
 
 #[doc(hidden)]
 pub mod internal {
-    use crate::matchers::json_matcher::internal::JsonMatcher;
+    use crate::matcher_support::path::PathSegment;
+    use crate::matchers::json_matcher::internal::{JsonMatcher, SharedMatcher};
+    use crate::report::{JsonMismatch, report_mismatch};
     use googletest::description::Description;
     use googletest::matcher::{Matcher, MatcherBase, MatcherResult};
     use serde_json::Value;
 
     #[doc(hidden)]
-    #[derive(MatcherBase)]
-    pub struct JsonElementsAre {
-        elements: Vec<Box<dyn JsonMatcher>>,
+    #[derive(Clone, MatcherBase)]
+    pub struct JsonElementsAre<C = SharedMatcher> {
+        elements: Vec<C>,
     }
 
-    impl JsonMatcher for JsonElementsAre {}
+    impl<C: JsonMatcher> JsonMatcher for JsonElementsAre<C> {
+        fn collect_mismatches(
+            &self,
+            actual: &Value,
+            path: &mut Vec<PathSegment>,
+            out: &mut Vec<JsonMismatch>,
+        ) {
+            match actual {
+                // Per-element attribution only makes sense when the lengths line up.
+                Value::Array(arr) if arr.len() == self.elements.len() => {
+                    for (index, (item, matcher)) in arr.iter().zip(&self.elements).enumerate() {
+                        path.push(PathSegment::Index(index));
+                        matcher.collect_mismatches(item, path, out);
+                        path.pop();
+                    }
+                }
+                _ => report_mismatch(self, actual, path, out),
+            }
+        }
+    }
 
-    impl JsonElementsAre {
-        pub fn new(elements: Vec<Box<dyn JsonMatcher>>) -> Self {
+    impl<C: JsonMatcher> JsonElementsAre<C> {
+        pub fn new(elements: Vec<C>) -> Self {
             Self { elements }
         }
     }
 
-    impl Matcher<&Value> for JsonElementsAre {
+    impl<C: JsonMatcher> Matcher<&Value> for JsonElementsAre<C> {
         fn matches(&self, actual: &Value) -> MatcherResult {
             match actual {
                 Value::Array(arr) => {
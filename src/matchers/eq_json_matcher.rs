@@ -0,0 +1,84 @@
+/// Matches a JSON value that is structurally equal to the expected document.
+///
+/// On mismatch, the explanation lists every leaf that was added, removed, or changed, using
+/// the same dot-path notation as [`has_paths`](crate::json::has_paths).
+///
+/// # Examples
+///
+/// ```rust
+/// # use googletest::prelude::*;
+/// # use googletest_json_serde::json as j;
+/// # use serde_json::json;
+/// let expected = json!({"user": {"id": 7, "name": "Ada"}});
+/// assert_that!(json!({"user": {"id": 7, "name": "Ada"}}), j::eq_json!(&expected));
+/// assert_that!(json!({"user": {"id": 7}}), not(j::eq_json!(expected)));
+/// ```
+///
+/// ```rust,should_panic
+/// # use googletest::prelude::*;
+/// # use googletest_json_serde::json as j;
+/// # use serde_json::json;
+/// // Fails with:
+/// //   which differs from the expected JSON at 1 path:
+/// //     changed `user.name`: expected "Ada", actual "Bob"
+/// assert_that!(
+///     json!({"user": {"id": 7, "name": "Bob"}}),
+///     j::eq_json!(json!({"user": {"id": 7, "name": "Ada"}}))
+/// );
+/// ```
+///
+/// # Supported Inputs
+/// - Owned `serde_json::Value`
+/// - Borrowed `&serde_json::Value`
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __json_eq_json {
+    ($expected:expr $(,)?) => {
+        $crate::matchers::__internal_unstable_do_not_depend_on_these::JsonEqJsonMatcher::new(
+            $expected,
+        )
+    };
+}
+
+#[doc(hidden)]
+pub mod internal {
+    use crate::matcher_support::diff::{describe_differences, diff};
+    use crate::matchers::json_matcher::internal::JsonMatcher;
+    use googletest::description::Description;
+    use googletest::matcher::{Matcher, MatcherBase, MatcherResult};
+    use serde_json::Value;
+    use std::borrow::Borrow;
+
+    #[doc(hidden)]
+    #[derive(Clone, MatcherBase)]
+    pub struct JsonEqJsonMatcher {
+        expected: Value,
+    }
+
+    impl JsonEqJsonMatcher {
+        pub fn new(expected: impl Borrow<Value>) -> Self {
+            Self {
+                expected: expected.borrow().clone(),
+            }
+        }
+    }
+
+    impl JsonMatcher for JsonEqJsonMatcher {}
+
+    impl Matcher<&Value> for JsonEqJsonMatcher {
+        fn matches(&self, actual: &Value) -> MatcherResult {
+            (*actual == self.expected).into()
+        }
+
+        fn describe(&self, result: MatcherResult) -> Description {
+            match result {
+                MatcherResult::Match => format!("is equal to JSON {}", self.expected).into(),
+                MatcherResult::NoMatch => format!("isn't equal to JSON {}", self.expected).into(),
+            }
+        }
+
+        fn explain_match(&self, actual: &Value) -> Description {
+            describe_differences(&diff(&self.expected, actual))
+        }
+    }
+}
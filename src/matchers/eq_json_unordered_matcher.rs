@@ -0,0 +1,313 @@
+/// Matches a JSON document equal to the expected one when arrays are compared as multisets.
+///
+/// Objects are compared key by key as in [`eq_json!`](crate::json::eq_json), but every array
+/// only needs the same elements in any order. Elements are paired with the same bipartite
+/// matching as [`unordered_elements_are!`](crate::json::unordered_elements_are), so nested
+/// arrays are unordered too and duplicates must appear equally often on both sides.
+///
+/// Call `.unordered_at(&[...])` to ignore the order only of the arrays at the given paths
+/// (wildcards allowed, as in [`j::has_paths`](crate::json::has_paths)); other arrays are then
+/// compared index by index. Inside an unordered array, match its elements with `*`.
+///
+/// On mismatch, the explanation lists the differing paths and, for each unordered array, the
+/// actual and expected elements that had no counterpart.
+///
+/// # Examples
+///
+/// ```rust
+/// # use googletest::prelude::*;
+/// # use googletest_json_serde::json as j;
+/// # use serde_json::json;
+/// let actual = json!({"tags": ["b", "a"], "orders": [{"id": 2, "lines": [3, 1]}, {"id": 1}]});
+/// assert_that!(
+///     actual,
+///     j::eq_json_unordered!(json!({
+///         "tags": ["a", "b"],
+///         "orders": [{"id": 1}, {"id": 2, "lines": [1, 3]}],
+///     }))
+/// );
+/// assert_that!(
+///     actual,
+///     not(j::eq_json_unordered!(json!({
+///         "tags": ["a", "b"],
+///         "orders": [{"id": 1}, {"id": 2, "lines": [1, 3]}],
+///     }))
+///     .unordered_at(&["tags", "orders"]))
+/// );
+/// ```
+///
+/// ```rust,should_panic
+/// # use googletest::prelude::*;
+/// # use googletest_json_serde::json as j;
+/// # use serde_json::json;
+/// // Fails with:
+/// //   which differs from the expected JSON at 2 paths:
+/// //     unmatched actual element #0 in `tags`: "c"
+/// //     unmatched expected element #1 in `tags`: "b"
+/// assert_that!(
+///     json!({"tags": ["c", "a"]}),
+///     j::eq_json_unordered!(json!({"tags": ["a", "b"]}))
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Fails when a path given to `.unordered_at` is invalid.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __json_eq_json_unordered {
+    ($expected:expr $(,)?) => {
+        $crate::matchers::__internal_unstable_do_not_depend_on_these::JsonEqJsonUnorderedMatcher::new(
+            $expected,
+        )
+    };
+}
+
+#[doc(hidden)]
+pub mod internal {
+    use crate::matcher_support::diff::{
+        Difference, describe_rendered_differences, display_path, snippet,
+    };
+    use crate::matcher_support::match_matrix::internal::{BestMatch, MatchMatrix};
+    use crate::matcher_support::path::{ParsedPath, ParsedPaths, PathSegment, parse_path_specs};
+    use crate::matchers::json_matcher::internal::JsonMatcher;
+    use crate::matchers::path_matcher::internal::JsonPathArg;
+    use googletest::description::Description;
+    use googletest::matcher::{Matcher, MatcherBase, MatcherResult};
+    use serde_json::Value;
+    use std::borrow::Borrow;
+
+    /// Compares documents structurally, ignoring array order; see `j::eq_json_unordered!`.
+    #[doc(hidden)]
+    #[derive(Clone, MatcherBase)]
+    pub struct JsonEqJsonUnorderedMatcher {
+        expected: Value,
+        /// The arrays compared without order, or `None` for every array.
+        unordered: Option<Vec<ParsedPath>>,
+        invalid: Vec<String>,
+    }
+
+    impl JsonEqJsonUnorderedMatcher {
+        pub fn new(expected: impl Borrow<Value>) -> Self {
+            Self {
+                expected: expected.borrow().clone(),
+                unordered: None,
+                invalid: Vec::new(),
+            }
+        }
+
+        /// Ignores the order only of the arrays at the given paths; wildcards are allowed.
+        pub fn unordered_at<P: JsonPathArg>(mut self, paths: &[P]) -> Self {
+            let ParsedPaths { parsed, errors } = parse_path_specs(paths.iter().map(P::path_spec));
+            self.unordered.get_or_insert_with(Vec::new).extend(parsed);
+            self.invalid.extend(errors);
+            self
+        }
+
+        fn is_unordered(&self, path: &[PathSegment]) -> bool {
+            self.unordered
+                .as_ref()
+                .is_none_or(|paths| paths.iter().any(|p| p.matches(path)))
+        }
+
+        fn equal(&self, expected: &Value, actual: &Value, path: &mut Vec<PathSegment>) -> bool {
+            match (expected, actual) {
+                (Value::Object(expected_map), Value::Object(actual_map)) => {
+                    expected_map.len() == actual_map.len()
+                        && expected_map.iter().all(|(key, expected_value)| {
+                            actual_map.get(key).is_some_and(|actual_value| {
+                                path.push(PathSegment::Field(key.clone()));
+                                let equal = self.equal(expected_value, actual_value, path);
+                                path.pop();
+                                equal
+                            })
+                        })
+                }
+                (Value::Array(expected_arr), Value::Array(actual_arr))
+                    if expected_arr.len() == actual_arr.len() =>
+                {
+                    if self.is_unordered(path) {
+                        let best = self.pair_elements(expected_arr, actual_arr, path);
+                        best.is_full_match() && best.get_unmatched_expected().is_empty()
+                    } else {
+                        expected_arr.iter().zip(actual_arr).enumerate().all(
+                            |(idx, (expected_value, actual_value))| {
+                                path.push(PathSegment::Index(idx));
+                                let equal = self.equal(expected_value, actual_value, path);
+                                path.pop();
+                                equal
+                            },
+                        )
+                    }
+                }
+                _ => expected == actual,
+            }
+        }
+
+        /// Pairs equal elements of an unordered array. Element paths use the expected index.
+        fn pair_elements(
+            &self,
+            expected: &[Value],
+            actual: &[Value],
+            path: &mut Vec<PathSegment>,
+        ) -> BestMatch {
+            MatchMatrix::from_fn(actual.len(), expected.len(), |actual_idx, expected_idx| {
+                path.push(PathSegment::Index(expected_idx));
+                let equal = self.equal(&expected[expected_idx], &actual[actual_idx], path);
+                path.pop();
+                equal.into()
+            })
+            .find_best_match()
+        }
+
+        fn collect_differences(
+            &self,
+            expected: &Value,
+            actual: &Value,
+            path: &mut Vec<PathSegment>,
+            out: &mut Vec<(Vec<PathSegment>, String)>,
+        ) {
+            match (expected, actual) {
+                (Value::Object(expected_map), Value::Object(actual_map)) => {
+                    for (key, expected_value) in expected_map {
+                        path.push(PathSegment::Field(key.clone()));
+                        match actual_map.get(key) {
+                            Some(actual_value) => {
+                                self.collect_differences(expected_value, actual_value, path, out)
+                            }
+                            None => push_difference(
+                                out,
+                                Difference::Removed {
+                                    path: path.clone(),
+                                    expected: expected_value,
+                                },
+                            ),
+                        }
+                        path.pop();
+                    }
+                    for (key, actual_value) in actual_map {
+                        if !expected_map.contains_key(key) {
+                            path.push(PathSegment::Field(key.clone()));
+                            push_difference(
+                                out,
+                                Difference::Added {
+                                    path: path.clone(),
+                                    actual: actual_value,
+                                },
+                            );
+                            path.pop();
+                        }
+                    }
+                }
+                (Value::Array(expected_arr), Value::Array(actual_arr))
+                    if self.is_unordered(path) =>
+                {
+                    let best = self.pair_elements(expected_arr, actual_arr, path);
+                    for actual_idx in best.get_unmatched_actual() {
+                        out.push((
+                            path.clone(),
+                            format!(
+                                "unmatched actual element #{actual_idx} in {}: {}",
+                                display_path(path),
+                                snippet(&actual_arr[actual_idx])
+                            ),
+                        ));
+                    }
+                    for expected_idx in best.get_unmatched_expected() {
+                        out.push((
+                            path.clone(),
+                            format!(
+                                "unmatched expected element #{expected_idx} in {}: {}",
+                                display_path(path),
+                                snippet(&expected_arr[expected_idx])
+                            ),
+                        ));
+                    }
+                }
+                (Value::Array(expected_arr), Value::Array(actual_arr)) => {
+                    let longest = expected_arr.len().max(actual_arr.len());
+                    for idx in 0..longest {
+                        path.push(PathSegment::Index(idx));
+                        match (expected_arr.get(idx), actual_arr.get(idx)) {
+                            (Some(e), Some(a)) => self.collect_differences(e, a, path, out),
+                            (Some(e), None) => push_difference(
+                                out,
+                                Difference::Removed {
+                                    path: path.clone(),
+                                    expected: e,
+                                },
+                            ),
+                            (None, Some(a)) => push_difference(
+                                out,
+                                Difference::Added {
+                                    path: path.clone(),
+                                    actual: a,
+                                },
+                            ),
+                            (None, None) => unreachable!("index is below the longest length"),
+                        }
+                        path.pop();
+                    }
+                }
+                _ => {
+                    if expected != actual {
+                        push_difference(
+                            out,
+                            Difference::Changed {
+                                path: path.clone(),
+                                expected,
+                                actual,
+                            },
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    fn push_difference(out: &mut Vec<(Vec<PathSegment>, String)>, difference: Difference<'_>) {
+        out.push((difference.path().to_vec(), difference.render()));
+    }
+
+    impl JsonMatcher for JsonEqJsonUnorderedMatcher {}
+
+    impl Matcher<&Value> for JsonEqJsonUnorderedMatcher {
+        fn matches(&self, actual: &Value) -> MatcherResult {
+            (self.invalid.is_empty() && self.equal(&self.expected, actual, &mut Vec::new())).into()
+        }
+
+        fn describe(&self, result: MatcherResult) -> Description {
+            let verb = match result {
+                MatcherResult::Match => "is",
+                MatcherResult::NoMatch => "isn't",
+            };
+            match &self.unordered {
+                None => format!(
+                    "{verb} equal to JSON {} ignoring array order",
+                    self.expected
+                )
+                .into(),
+                Some(paths) => format!(
+                    "{verb} equal to JSON {} ignoring the order of arrays at {:?}",
+                    self.expected,
+                    paths.iter().map(|p| &p.raw).collect::<Vec<_>>()
+                )
+                .into(),
+            }
+        }
+
+        fn explain_match(&self, actual: &Value) -> Description {
+            if !self.invalid.is_empty() {
+                return format!("invalid paths {:?}", self.invalid).into();
+            }
+            let mut differences = Vec::new();
+            self.collect_differences(&self.expected, actual, &mut Vec::new(), &mut differences);
+            differences.sort_by(|(a, _), (b, _)| a.cmp(b));
+            let lines = differences
+                .into_iter()
+                .map(|(_, line)| line)
+                .collect::<Vec<_>>();
+            describe_rendered_differences(&lines)
+        }
+    }
+}
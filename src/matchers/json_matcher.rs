@@ -52,7 +52,8 @@ where
 /// assert_that!(json!(null), j::is_null());
 /// assert_that!(json!("value"), not(j::is_null()));
 /// ```
-pub fn is_null() -> JsonPredicateMatcher<impl Fn(&Value) -> bool, &'static str, &'static str> {
+pub fn is_null()
+-> JsonPredicateMatcher<impl Fn(&Value) -> bool + Clone + Send + Sync, &'static str, &'static str> {
     JsonPredicateMatcher::new(|v| v.is_null(), "JSON null", "which is not JSON null")
         .with_explain_fn(__internal_unstable_do_not_depend_on_these::describe_json_type)
 }
@@ -67,7 +68,8 @@ pub fn is_null() -> JsonPredicateMatcher<impl Fn(&Value) -> bool, &'static str,
 /// assert_that!(json!("text"), j::is_not_null());
 /// assert_that!(json!(null), not(j::is_not_null()));
 /// ```
-pub fn is_not_null() -> JsonPredicateMatcher<impl Fn(&Value) -> bool, &'static str, &'static str> {
+pub fn is_not_null()
+-> JsonPredicateMatcher<impl Fn(&Value) -> bool + Clone + Send + Sync, &'static str, &'static str> {
     JsonPredicateMatcher::new(|v| !v.is_null(), "not JSON null", "which is JSON null")
         .with_explain_fn(__internal_unstable_do_not_depend_on_these::describe_json_type)
 }
@@ -84,7 +86,8 @@ pub fn is_not_null() -> JsonPredicateMatcher<impl Fn(&Value) -> bool, &'static s
 /// assert_that!(json!(null), not(j::any_value()));
 /// ```
 #[deprecated(since = "0.2.2", note = "Use `is_not_null` instead")]
-pub fn any_value() -> JsonPredicateMatcher<impl Fn(&Value) -> bool, &'static str, &'static str> {
+pub fn any_value()
+-> JsonPredicateMatcher<impl Fn(&Value) -> bool + Clone + Send + Sync, &'static str, &'static str> {
     JsonPredicateMatcher::new(|v| !v.is_null(), "any JSON value", "is not any JSON value")
         .with_explain_fn(__internal_unstable_do_not_depend_on_these::describe_json_type)
 }
@@ -100,7 +103,8 @@ pub fn any_value() -> JsonPredicateMatcher<impl Fn(&Value) -> bool, &'static str
 /// assert_that!(json!("hi"), j::is_string());
 /// assert_that!(json!(true), not(j::is_string()));
 /// ```
-pub fn is_string() -> JsonPredicateMatcher<impl Fn(&Value) -> bool, &'static str, &'static str> {
+pub fn is_string()
+-> JsonPredicateMatcher<impl Fn(&Value) -> bool + Clone + Send + Sync, &'static str, &'static str> {
     JsonPredicateMatcher::new(
         |v| v.is_string(),
         "a JSON string",
@@ -120,8 +124,8 @@ pub fn is_string() -> JsonPredicateMatcher<impl Fn(&Value) -> bool, &'static str
 /// assert_that!(json!(""), j::is_empty_string());
 /// assert_that!(json!("hi"), not(j::is_empty_string()));
 /// ```
-pub fn is_empty_string() -> JsonPredicateMatcher<impl Fn(&Value) -> bool, &'static str, &'static str>
-{
+pub fn is_empty_string()
+-> JsonPredicateMatcher<impl Fn(&Value) -> bool + Clone + Send + Sync, &'static str, &'static str> {
     JsonPredicateMatcher::new(
         |v| v.as_str().is_some_and(|s| s.is_empty()),
         "an empty JSON string",
@@ -148,7 +152,7 @@ pub fn is_empty_string() -> JsonPredicateMatcher<impl Fn(&Value) -> bool, &'stat
 /// assert_that!(json!(""), not(j::is_non_empty_string()));
 /// ```
 pub fn is_non_empty_string()
--> JsonPredicateMatcher<impl Fn(&Value) -> bool, &'static str, &'static str> {
+-> JsonPredicateMatcher<impl Fn(&Value) -> bool + Clone + Send + Sync, &'static str, &'static str> {
     JsonPredicateMatcher::new(
         |v| v.as_str().is_some_and(|s| !s.is_empty()),
         "a non-empty JSON string",
@@ -174,7 +178,8 @@ pub fn is_non_empty_string()
 /// assert_that!(json!(3.14), j::is_number());
 /// assert_that!(json!("three"), not(j::is_number()));
 /// ```
-pub fn is_number() -> JsonPredicateMatcher<impl Fn(&Value) -> bool, &'static str, &'static str> {
+pub fn is_number()
+-> JsonPredicateMatcher<impl Fn(&Value) -> bool + Clone + Send + Sync, &'static str, &'static str> {
     JsonPredicateMatcher::new(
         |v| v.is_number(),
         "a JSON number",
@@ -194,7 +199,8 @@ pub fn is_number() -> JsonPredicateMatcher<impl Fn(&Value) -> bool, &'static str
 /// assert_that!(json!(42), j::is_integer());
 /// assert_that!(json!(3.14), not(j::is_integer()));
 /// ```
-pub fn is_integer() -> JsonPredicateMatcher<impl Fn(&Value) -> bool, &'static str, &'static str> {
+pub fn is_integer()
+-> JsonPredicateMatcher<impl Fn(&Value) -> bool + Clone + Send + Sync, &'static str, &'static str> {
     JsonPredicateMatcher::new(
         |v| matches!(v, Value::Number(n) if n.is_i64() || n.is_u64()),
         "an integer JSON number",
@@ -220,8 +226,8 @@ pub fn is_integer() -> JsonPredicateMatcher<impl Fn(&Value) -> bool, &'static st
 /// assert_that!(json!(2.0), j::is_whole_number());
 /// assert_that!(json!(2.5), not(j::is_whole_number()));
 /// ```
-pub fn is_whole_number() -> JsonPredicateMatcher<impl Fn(&Value) -> bool, &'static str, &'static str>
-{
+pub fn is_whole_number()
+-> JsonPredicateMatcher<impl Fn(&Value) -> bool + Clone + Send + Sync, &'static str, &'static str> {
     JsonPredicateMatcher::new(
         |v| match v {
             Value::Number(n) => {
@@ -259,7 +265,7 @@ pub fn is_whole_number() -> JsonPredicateMatcher<impl Fn(&Value) -> bool, &'stat
 /// assert_that!(json!(3.0), not(j::is_fractional_number()));
 /// ```
 pub fn is_fractional_number()
--> JsonPredicateMatcher<impl Fn(&Value) -> bool, &'static str, &'static str> {
+-> JsonPredicateMatcher<impl Fn(&Value) -> bool + Clone + Send + Sync, &'static str, &'static str> {
     JsonPredicateMatcher::new(
         |v| match v {
             Value::Number(n) => {
@@ -294,7 +300,8 @@ pub fn is_fractional_number()
 /// assert_that!(json!(true), j::is_boolean());
 /// assert_that!(json!(0), not(j::is_boolean()));
 /// ```
-pub fn is_boolean() -> JsonPredicateMatcher<impl Fn(&Value) -> bool, &'static str, &'static str> {
+pub fn is_boolean()
+-> JsonPredicateMatcher<impl Fn(&Value) -> bool + Clone + Send + Sync, &'static str, &'static str> {
     JsonPredicateMatcher::new(
         |v| v.is_boolean(),
         "a JSON boolean",
@@ -314,7 +321,8 @@ pub fn is_boolean() -> JsonPredicateMatcher<impl Fn(&Value) -> bool, &'static st
 /// assert_that!(json!(true), j::is_true());
 /// assert_that!(json!(false), not(j::is_true()));
 /// ```
-pub fn is_true() -> JsonPredicateMatcher<impl Fn(&Value) -> bool, &'static str, &'static str> {
+pub fn is_true()
+-> JsonPredicateMatcher<impl Fn(&Value) -> bool + Clone + Send + Sync, &'static str, &'static str> {
     JsonPredicateMatcher::new(
         |v| matches!(v, Value::Bool(true)),
         "JSON true",
@@ -337,7 +345,8 @@ pub fn is_true() -> JsonPredicateMatcher<impl Fn(&Value) -> bool, &'static str,
 /// assert_that!(json!(false), j::is_false());
 /// assert_that!(json!(true), not(j::is_false()));
 /// ```
-pub fn is_false() -> JsonPredicateMatcher<impl Fn(&Value) -> bool, &'static str, &'static str> {
+pub fn is_false()
+-> JsonPredicateMatcher<impl Fn(&Value) -> bool + Clone + Send + Sync, &'static str, &'static str> {
     JsonPredicateMatcher::new(
         |v| matches!(v, Value::Bool(false)),
         "JSON false",
@@ -360,7 +369,8 @@ pub fn is_false() -> JsonPredicateMatcher<impl Fn(&Value) -> bool, &'static str,
 /// assert_that!(json!([1, 2]), j::is_array());
 /// assert_that!(json!({"a":1}), not(j::is_array()));
 /// ```
-pub fn is_array() -> JsonPredicateMatcher<impl Fn(&Value) -> bool, &'static str, &'static str> {
+pub fn is_array()
+-> JsonPredicateMatcher<impl Fn(&Value) -> bool + Clone + Send + Sync, &'static str, &'static str> {
     JsonPredicateMatcher::new(
         |v| v.is_array(),
         "a JSON array",
@@ -380,8 +390,8 @@ pub fn is_array() -> JsonPredicateMatcher<impl Fn(&Value) -> bool, &'static str,
 /// assert_that!(json!([]), j::is_empty_array());
 /// assert_that!(json!([1]), not(j::is_empty_array()));
 /// ```
-pub fn is_empty_array() -> JsonPredicateMatcher<impl Fn(&Value) -> bool, &'static str, &'static str>
-{
+pub fn is_empty_array()
+-> JsonPredicateMatcher<impl Fn(&Value) -> bool + Clone + Send + Sync, &'static str, &'static str> {
     JsonPredicateMatcher::new(
         |v| v.as_array().is_some_and(|a| a.is_empty()),
         "an empty JSON array",
@@ -408,7 +418,7 @@ pub fn is_empty_array() -> JsonPredicateMatcher<impl Fn(&Value) -> bool, &'stati
 /// assert_that!(json!([]), not(j::is_non_empty_array()));
 /// ```
 pub fn is_non_empty_array()
--> JsonPredicateMatcher<impl Fn(&Value) -> bool, &'static str, &'static str> {
+-> JsonPredicateMatcher<impl Fn(&Value) -> bool + Clone + Send + Sync, &'static str, &'static str> {
     JsonPredicateMatcher::new(
         |v| v.as_array().is_some_and(|a| !a.is_empty()),
         "a non-empty JSON array",
@@ -434,7 +444,8 @@ pub fn is_non_empty_array()
 /// assert_that!(json!({"a": 1}), j::is_object());
 /// assert_that!(json!(null), not(j::is_object()));
 /// ```
-pub fn is_object() -> JsonPredicateMatcher<impl Fn(&Value) -> bool, &'static str, &'static str> {
+pub fn is_object()
+-> JsonPredicateMatcher<impl Fn(&Value) -> bool + Clone + Send + Sync, &'static str, &'static str> {
     JsonPredicateMatcher::new(
         |v| v.is_object(),
         "a JSON object",
@@ -454,8 +465,8 @@ pub fn is_object() -> JsonPredicateMatcher<impl Fn(&Value) -> bool, &'static str
 /// assert_that!(json!({}), j::is_empty_object());
 /// assert_that!(json!({"a":1}), not(j::is_empty_object()));
 /// ```
-pub fn is_empty_object() -> JsonPredicateMatcher<impl Fn(&Value) -> bool, &'static str, &'static str>
-{
+pub fn is_empty_object()
+-> JsonPredicateMatcher<impl Fn(&Value) -> bool + Clone + Send + Sync, &'static str, &'static str> {
     JsonPredicateMatcher::new(
         |v| v.as_object().is_some_and(|o| o.is_empty()),
         "an empty JSON object",
@@ -482,7 +493,7 @@ pub fn is_empty_object() -> JsonPredicateMatcher<impl Fn(&Value) -> bool, &'stat
 /// assert_that!(json!({}), not(j::is_non_empty_object()));
 /// ```
 pub fn is_non_empty_object()
--> JsonPredicateMatcher<impl Fn(&Value) -> bool, &'static str, &'static str> {
+-> JsonPredicateMatcher<impl Fn(&Value) -> bool + Clone + Send + Sync, &'static str, &'static str> {
     JsonPredicateMatcher::new(
         |v| v.as_object().is_some_and(|o| !o.is_empty()),
         "a non-empty JSON object",
@@ -499,12 +510,33 @@ pub fn is_non_empty_object()
 
 // Path-based matchers live in `path_matcher.rs`.
 
+/// Converts a macro argument into a child of a composite JSON matcher: a `SharedMatcher` when
+/// it is `Send + Sync`, a `LocalMatcher` otherwise.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __json_into_matcher {
+    ($matcher:expr) => {{
+        #[allow(unused_imports)]
+        use $crate::matchers::__internal_unstable_do_not_depend_on_these::{
+            ViaLocal as _, ViaShared as _,
+        };
+        (&&$crate::matchers::__internal_unstable_do_not_depend_on_these::ChildArg::new($matcher))
+            .child()
+    }};
+}
+
 #[doc(hidden)]
 pub mod internal {
+    use crate::matcher_support::path::PathSegment;
+    use crate::matchers::options_matcher::internal::JsonOptions;
+    use crate::report::{JsonMismatch, report_mismatch};
     use googletest::description::Description;
     use googletest::matcher::MatcherResult::{Match, NoMatch};
     use googletest::matcher::{Matcher, MatcherBase, MatcherResult};
     use serde_json::Value;
+    use std::cell::Cell;
+    use std::rc::Rc;
+    use std::sync::Arc;
 
     /// Trait for types that can provide a description string.
     pub trait PredicateDescription {
@@ -541,9 +573,10 @@ pub mod internal {
     }
 
     /// Type alias for the explain function to reduce type complexity.
-    type ExplainFn = Box<dyn Fn(&Value) -> Description>;
+    type ExplainFn = Arc<dyn Fn(&Value) -> Description + Send + Sync>;
 
-    #[derive(MatcherBase)]
+    /// A JSON matcher built from a predicate closure and optional descriptions.
+    #[derive(Clone, MatcherBase)]
     pub struct JsonPredicateMatcher<P, D1 = NoDescription, D2 = NoDescription>
     where
         P: Fn(&Value) -> bool,
@@ -562,6 +595,7 @@ pub mod internal {
         D1: PredicateDescription,
         D2: PredicateDescription,
     {
+        /// Creates a matcher that matches when `predicate` returns true.
         pub fn new(predicate: P, positive_description: D1, negative_description: D2) -> Self {
             Self {
                 predicate,
@@ -571,6 +605,7 @@ pub mod internal {
             }
         }
 
+        /// Replaces the descriptions shown for a match and for a mismatch.
         pub fn with_description<D1b, D2b>(
             self,
             positive_description: D1b,
@@ -588,11 +623,12 @@ pub mod internal {
             }
         }
 
+        /// Sets how a non-matching value is explained, e.g. [`describe_json_type`].
         pub fn with_explain_fn<F>(mut self, f: F) -> Self
         where
-            F: Fn(&Value) -> Description + 'static,
+            F: Fn(&Value) -> Description + Send + Sync + 'static,
         {
-            self.explain_fn = Some(Box::new(f));
+            self.explain_fn = Some(Arc::new(f));
             self
         }
     }
@@ -630,39 +666,305 @@ pub mod internal {
             Description::new().text("which does not match the predicate")
         }
     }
-    /// Marker trait for JSON-aware matchers.
+    /// A matcher over `serde_json::Value` that the JSON macros can compose.
+    ///
+    /// Implement it alongside `Matcher<&Value>` to use a custom matcher inside `pat!`,
+    /// `elements_are!`, `optional!` and friends. See [`crate::extension`].
     pub trait JsonMatcher: for<'a> Matcher<&'a Value> {
         /// Returns true if this matcher allows the field to be missing in an object.
+        ///
+        /// When an object pattern finds the field absent, it accepts the object only if this
+        /// returns true; the matcher itself is not invoked.
         fn allows_missing(&self) -> bool {
             false
         }
+
+        /// Appends every failed expectation under `actual` to `out`, with paths relative to
+        /// the document root. `path` is the location of `actual` and is restored on return.
+        ///
+        /// The default records this matcher as failing at `path`. Composite matchers override
+        /// it to attribute failures to the children that caused them.
+        fn collect_mismatches(
+            &self,
+            actual: &Value,
+            path: &mut Vec<PathSegment>,
+            out: &mut Vec<JsonMismatch>,
+        ) {
+            report_mismatch(self, actual, path, out);
+        }
     }
 
-    /// Trait for converting into a boxed JSON matcher.
+    /// Lets a borrowed matcher, e.g. one stored in a `static LazyLock`, be reused inside
+    /// other patterns without cloning it.
+    impl<J: JsonMatcher> JsonMatcher for &J {
+        fn allows_missing(&self) -> bool {
+            (**self).allows_missing()
+        }
+
+        fn collect_mismatches(
+            &self,
+            actual: &Value,
+            path: &mut Vec<PathSegment>,
+            out: &mut Vec<JsonMismatch>,
+        ) {
+            (**self).collect_mismatches(actual, path, out)
+        }
+    }
+
+    /// Trait for converting a macro argument into a JSON matcher.
+    ///
+    /// Implemented for every [`JsonMatcher`], for JSON values and literals (compared for
+    /// equality), and for native googletest matchers over primitives. The type parameter only
+    /// disambiguates these blanket implementations.
     pub trait IntoJsonMatcher<T> {
-        fn into_json_matcher(self) -> Box<dyn JsonMatcher>;
+        type Matcher: JsonMatcher + 'static;
+
+        fn into_json_matcher(self) -> Self::Matcher;
     }
 
     impl<J> IntoJsonMatcher<()> for J
     where
         J: JsonMatcher + 'static,
     {
-        fn into_json_matcher(self) -> Box<dyn JsonMatcher> {
-            Box::new(self)
+        type Matcher = J;
+
+        fn into_json_matcher(self) -> J {
+            self
+        }
+    }
+
+    /// A child of a composite JSON matcher, shared between clones of the composite.
+    ///
+    /// Holds a `Send + Sync` matcher, so composites built only from these are `Send + Sync`
+    /// too.
+    #[derive(Clone, MatcherBase)]
+    pub struct SharedMatcher(Arc<dyn JsonMatcher + Send + Sync>);
+
+    impl SharedMatcher {
+        pub fn new(matcher: impl JsonMatcher + Send + Sync + 'static) -> Self {
+            Self(Arc::new(matcher))
+        }
+    }
+
+    impl Matcher<&Value> for SharedMatcher {
+        fn matches(&self, actual: &Value) -> MatcherResult {
+            self.0.matches(actual)
+        }
+
+        fn describe(&self, result: MatcherResult) -> Description {
+            self.0.describe(result)
+        }
+
+        fn explain_match(&self, actual: &Value) -> Description {
+            self.0.explain_match(actual)
+        }
+    }
+
+    impl JsonMatcher for SharedMatcher {
+        fn allows_missing(&self) -> bool {
+            self.0.allows_missing()
+        }
+
+        fn collect_mismatches(
+            &self,
+            actual: &Value,
+            path: &mut Vec<PathSegment>,
+            out: &mut Vec<JsonMismatch>,
+        ) {
+            self.0.collect_mismatches(actual, path, out)
+        }
+    }
+
+    /// A child of a composite JSON matcher that is not thread-safe, such as one built from
+    /// googletest's `starts_with` or `contains`.
+    ///
+    /// Composites holding one are neither `Send` nor `Sync`, but otherwise work the same.
+    #[derive(Clone, MatcherBase)]
+    pub struct LocalMatcher(Rc<dyn JsonMatcher>);
+
+    impl LocalMatcher {
+        pub fn new(matcher: impl JsonMatcher + 'static) -> Self {
+            Self(Rc::new(matcher))
+        }
+    }
+
+    impl From<SharedMatcher> for LocalMatcher {
+        fn from(matcher: SharedMatcher) -> Self {
+            Self::new(matcher)
+        }
+    }
+
+    impl Matcher<&Value> for LocalMatcher {
+        fn matches(&self, actual: &Value) -> MatcherResult {
+            self.0.matches(actual)
+        }
+
+        fn describe(&self, result: MatcherResult) -> Description {
+            self.0.describe(result)
+        }
+
+        fn explain_match(&self, actual: &Value) -> Description {
+            self.0.explain_match(actual)
+        }
+    }
+
+    impl JsonMatcher for LocalMatcher {
+        fn allows_missing(&self) -> bool {
+            self.0.allows_missing()
+        }
+
+        fn collect_mismatches(
+            &self,
+            actual: &Value,
+            path: &mut Vec<PathSegment>,
+            out: &mut Vec<JsonMismatch>,
+        ) {
+            self.0.collect_mismatches(actual, path, out)
+        }
+    }
+
+    /// The child type able to hold both `Self` and `Rhs`: shared when both are shared, local
+    /// otherwise.
+    pub trait Join<Rhs> {
+        type Out;
+
+        fn left(self) -> Self::Out;
+
+        fn right(rhs: Rhs) -> Self::Out;
+    }
+
+    impl Join<SharedMatcher> for SharedMatcher {
+        type Out = SharedMatcher;
+
+        fn left(self) -> SharedMatcher {
+            self
+        }
+
+        fn right(rhs: SharedMatcher) -> SharedMatcher {
+            rhs
+        }
+    }
+
+    impl Join<LocalMatcher> for SharedMatcher {
+        type Out = LocalMatcher;
+
+        fn left(self) -> LocalMatcher {
+            self.into()
+        }
+
+        fn right(rhs: LocalMatcher) -> LocalMatcher {
+            rhs
+        }
+    }
+
+    impl Join<SharedMatcher> for LocalMatcher {
+        type Out = LocalMatcher;
+
+        fn left(self) -> LocalMatcher {
+            self
+        }
+
+        fn right(rhs: SharedMatcher) -> LocalMatcher {
+            rhs.into()
+        }
+    }
+
+    impl Join<LocalMatcher> for LocalMatcher {
+        type Out = LocalMatcher;
+
+        fn left(self) -> LocalMatcher {
+            self
+        }
+
+        fn right(rhs: LocalMatcher) -> LocalMatcher {
+            rhs
+        }
+    }
+
+    /// Collects the children of a composite JSON matcher, widening them all to the type that
+    /// can hold every one of them.
+    #[derive(Default)]
+    pub struct Children<C>(Vec<C>);
+
+    impl Children<SharedMatcher> {
+        pub fn new() -> Self {
+            Self(Vec::new())
+        }
+    }
+
+    impl<C> Children<C> {
+        pub fn push<D>(self, child: D) -> Children<C::Out>
+        where
+            C: Join<D>,
+        {
+            let mut children: Vec<_> = self.0.into_iter().map(C::left).collect();
+            children.push(C::right(child));
+            Children(children)
+        }
+
+        pub fn into_vec(self) -> Vec<C> {
+            self.0
+        }
+    }
+
+    /// Converts a macro argument into a [`SharedMatcher`] when its matcher is `Send + Sync`,
+    /// and into a [`LocalMatcher`] otherwise.
+    ///
+    /// This relies on autoref specialization: `ViaShared` is implemented for `&ChildArg<M>` and
+    /// `ViaLocal` for `ChildArg<M>`, so method resolution on `(&&arg).child()` tries them
+    /// in that order.
+    pub struct ChildArg<M>(Cell<Option<M>>);
+
+    impl<M> ChildArg<M> {
+        pub fn new(matcher: M) -> Self {
+            Self(Cell::new(Some(matcher)))
+        }
+
+        fn take(&self) -> M {
+            self.0
+                .take()
+                .expect("a matcher argument is converted only once")
+        }
+    }
+
+    pub trait ViaShared<T> {
+        fn child(&self) -> SharedMatcher;
+    }
+
+    impl<T, M> ViaShared<T> for &ChildArg<M>
+    where
+        M: IntoJsonMatcher<T>,
+        M::Matcher: Send + Sync,
+    {
+        fn child(&self) -> SharedMatcher {
+            SharedMatcher::new(self.take().into_json_matcher())
+        }
+    }
+
+    pub trait ViaLocal<T> {
+        fn child(&self) -> LocalMatcher;
+    }
+
+    impl<T, M> ViaLocal<T> for ChildArg<M>
+    where
+        M: IntoJsonMatcher<T>,
+    {
+        fn child(&self) -> LocalMatcher {
+            LocalMatcher::new(self.take().into_json_matcher())
         }
     }
 
     // A concrete matcher that checks equality with an owned serde_json::Value.
     // This avoids lifetime issues of using googletest::eq on &Value and gives
     // us control over descriptions.
-    #[derive(googletest::matcher::MatcherBase)]
+    #[derive(Clone, googletest::matcher::MatcherBase)]
     struct JsonEqMatcher {
         expected: Value,
     }
 
     impl Matcher<&Value> for JsonEqMatcher {
         fn matches(&self, actual: &Value) -> MatcherResult {
-            if *actual == self.expected {
+            if JsonOptions::current().values_equal(actual, &self.expected) {
                 Match
             } else {
                 NoMatch
@@ -686,16 +988,20 @@ pub mod internal {
 
     // Allow &serde_json::Value to be used seamlessly with JSON macros
     impl IntoJsonMatcher<Value> for &Value {
-        fn into_json_matcher(self) -> Box<dyn JsonMatcher> {
-            Box::new(JsonEqMatcher {
+        type Matcher = SharedMatcher;
+
+        fn into_json_matcher(self) -> SharedMatcher {
+            SharedMatcher::new(JsonEqMatcher {
                 expected: self.clone(),
             })
         }
     }
 
     impl IntoJsonMatcher<Value> for Value {
-        fn into_json_matcher(self) -> Box<dyn JsonMatcher> {
-            Box::new(JsonEqMatcher { expected: self })
+        type Matcher = SharedMatcher;
+
+        fn into_json_matcher(self) -> SharedMatcher {
+            SharedMatcher::new(JsonEqMatcher { expected: self })
         }
     }
 
@@ -703,55 +1009,69 @@ pub mod internal {
     pub struct Literal;
 
     impl IntoJsonMatcher<Literal> for &str {
-        fn into_json_matcher(self) -> Box<dyn JsonMatcher> {
-            Box::new(JsonEqMatcher {
+        type Matcher = SharedMatcher;
+
+        fn into_json_matcher(self) -> SharedMatcher {
+            SharedMatcher::new(JsonEqMatcher {
                 expected: Value::from(self),
             })
         }
     }
 
     impl IntoJsonMatcher<Literal> for String {
-        fn into_json_matcher(self) -> Box<dyn JsonMatcher> {
-            Box::new(JsonEqMatcher {
+        type Matcher = SharedMatcher;
+
+        fn into_json_matcher(self) -> SharedMatcher {
+            SharedMatcher::new(JsonEqMatcher {
                 expected: Value::from(self),
             })
         }
     }
 
     impl IntoJsonMatcher<Literal> for bool {
-        fn into_json_matcher(self) -> Box<dyn JsonMatcher> {
-            Box::new(JsonEqMatcher {
+        type Matcher = SharedMatcher;
+
+        fn into_json_matcher(self) -> SharedMatcher {
+            SharedMatcher::new(JsonEqMatcher {
                 expected: Value::from(self),
             })
         }
     }
 
     impl IntoJsonMatcher<Literal> for i64 {
-        fn into_json_matcher(self) -> Box<dyn JsonMatcher> {
-            Box::new(JsonEqMatcher {
+        type Matcher = SharedMatcher;
+
+        fn into_json_matcher(self) -> SharedMatcher {
+            SharedMatcher::new(JsonEqMatcher {
                 expected: Value::from(self),
             })
         }
     }
     impl IntoJsonMatcher<Literal> for i32 {
-        fn into_json_matcher(self) -> Box<dyn JsonMatcher> {
-            Box::new(JsonEqMatcher {
+        type Matcher = SharedMatcher;
+
+        fn into_json_matcher(self) -> SharedMatcher {
+            SharedMatcher::new(JsonEqMatcher {
                 expected: Value::from(self),
             })
         }
     }
 
     impl IntoJsonMatcher<Literal> for u64 {
-        fn into_json_matcher(self) -> Box<dyn JsonMatcher> {
-            Box::new(JsonEqMatcher {
+        type Matcher = SharedMatcher;
+
+        fn into_json_matcher(self) -> SharedMatcher {
+            SharedMatcher::new(JsonEqMatcher {
                 expected: Value::from(self),
             })
         }
     }
 
     impl IntoJsonMatcher<Literal> for f64 {
-        fn into_json_matcher(self) -> Box<dyn JsonMatcher> {
-            Box::new(JsonEqMatcher {
+        type Matcher = SharedMatcher;
+
+        fn into_json_matcher(self) -> SharedMatcher {
+            SharedMatcher::new(JsonEqMatcher {
                 expected: Value::from(self),
             })
         }
@@ -765,6 +1085,7 @@ pub mod internal {
     {
     }
 
+    /// Explains a value by its JSON type, e.g. "which is a JSON string".
     pub fn describe_json_type(v: &Value) -> Description {
         match v {
             Value::Null => "which is a JSON null",
@@ -25,38 +25,36 @@
 macro_rules! __json_len {
     ($lit:literal) => {{
         $crate::matchers::__internal_unstable_do_not_depend_on_these::JsonLenMatcher::new(
-            $crate::matchers::__internal_unstable_do_not_depend_on_these::IntoJsonMatcher::into_json_matcher(
-                googletest::matchers::eq($lit)
-            ),
+            $crate::__json_into_matcher!(googletest::matchers::eq($lit)),
         )
     }};
     ($inner:expr) => {{
         $crate::matchers::__internal_unstable_do_not_depend_on_these::JsonLenMatcher::new(
-            $crate::matchers::__internal_unstable_do_not_depend_on_these::IntoJsonMatcher::into_json_matcher($inner),
+            $crate::__json_into_matcher!($inner),
         )
     }};
 }
 
 pub mod internal {
-    use crate::matchers::__internal_unstable_do_not_depend_on_these::JsonMatcher;
+    use crate::matchers::__internal_unstable_do_not_depend_on_these::{JsonMatcher, SharedMatcher};
     use googletest::description::Description;
     use googletest::matcher::{Matcher, MatcherBase, MatcherResult};
     use serde_json::Value;
 
     /// A JSON-aware length matcher that works for arrays and strings,
     /// without requiring the type to implement IntoIterator.
-    #[derive(MatcherBase)]
-    pub struct JsonLenMatcher {
-        inner: Box<dyn JsonMatcher>,
+    #[derive(Clone, MatcherBase)]
+    pub struct JsonLenMatcher<C = SharedMatcher> {
+        inner: C,
     }
 
-    impl JsonLenMatcher {
-        pub fn new(inner: Box<dyn JsonMatcher>) -> Self {
+    impl<C: JsonMatcher> JsonLenMatcher<C> {
+        pub fn new(inner: C) -> Self {
             Self { inner }
         }
     }
 
-    impl Matcher<&Value> for JsonLenMatcher {
+    impl<C: JsonMatcher> Matcher<&Value> for JsonLenMatcher<C> {
         fn matches(&self, value: &Value) -> MatcherResult {
             let len = match value {
                 Value::Array(arr) => arr.len(),
@@ -87,5 +85,5 @@ pub mod internal {
         }
     }
 
-    impl JsonMatcher for JsonLenMatcher {}
+    impl<C: JsonMatcher> JsonMatcher for JsonLenMatcher<C> {}
 }
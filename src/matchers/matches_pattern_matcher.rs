@@ -2,6 +2,24 @@
 ///
 /// Fields listed in the pattern must match; a trailing `..` allows extra fields.
 ///
+/// Keys are string literals, or any expression yielding a `&str` or `String` when wrapped in
+/// brackets: `[KEY]: ...`, `[format!("x_{id}")]: ...`. A key listed twice makes the pattern
+/// fail with an explanation naming the duplicate.
+///
+/// A bracketed key can also be a string matcher, e.g. `[matches_regex("usr_.*")]: ...`. At least
+/// one field not named elsewhere in the pattern must have a matching key, and every such field's
+/// value must match. Wrap the key matcher in [`j::all_keys`](crate::json::all_keys) to require
+/// every field not named in the pattern to match instead.
+///
+/// The trailing `..` may carry a matcher, `.. : matcher`, which every field not otherwise listed
+/// must then satisfy. To constrain the keys of those fields instead, use a key matcher entry such
+/// as `[j::all_keys(starts_with("x-"))]: j::is_string()`.
+///
+/// Array values written as `[ ... ]`, in a field or at the top level, are matched element by
+/// element. A leading and/or trailing `..` relaxes them: `[a, ..]` matches arrays starting with
+/// `a`, `[.., a]` arrays ending with it, and `[.., a, ..]` arrays containing it; several
+/// elements must then appear consecutively.
+///
 /// # Examples
 ///
 /// ```rust
@@ -26,9 +44,25 @@
 /// );
 /// ```
 ///
+/// ```rust
+/// # use googletest::prelude::*;
+/// # use serde_json::json;
+/// # use googletest_json_serde::json as j;
+/// let value = json!({"tags": ["alpha", "beta", "gamma"], "matrix": [[1, 2], [3, 4]]});
+/// assert_that!(
+///     value,
+///     j::pat!({
+///         "tags": [.., starts_with("b"), ..],
+///         "matrix": [[1, ..], [.., 4]],
+///     })
+/// );
+/// assert_that!(json!(["a", "b"]), j::pat!(["a", starts_with("b")]));
+/// ```
+///
 /// # Errors
 ///
 /// Fails when the value is not a JSON object, when a required field is missing, when a field value mismatches, or when extra fields appear without `..`.
+/// Array patterns fail when the value is not an array or when the listed elements are not found in the required position.
 ///
 /// This macro is reexported as [`j::pat!`](crate::json::pat).
 ///
@@ -47,83 +81,774 @@ macro_rules! __json_matches_pattern {
     };
     // Nested object arm: handles `key: { ... }` by delegating to the pattern macro.
     (@wrap_matcher { $($inner:tt)* }) => {
-        $crate::matchers::__internal_unstable_do_not_depend_on_these::IntoJsonMatcher::into_json_matcher(
+        $crate::__json_into_matcher!(
             $crate::__json_matches_pattern!({ $($inner)* })
         )
     };
+    // Nested array arm: handles `key: [ ... ]` by delegating to the pattern macro.
+    (@wrap_matcher [ $($inner:tt)* ]) => {
+        $crate::__json_into_matcher!(
+            $crate::__json_matches_pattern!([ $($inner)* ])
+        )
+    };
     // Expression arm: handles matchers and values like `eq(1)` or `json!(...)`.
     (@wrap_matcher $expr:expr) => {
-        $crate::matchers::__internal_unstable_do_not_depend_on_these::IntoJsonMatcher::into_json_matcher($expr)
+        $crate::__json_into_matcher!($expr)
     };
     // Parse completion: no more tokens to consume.
-    (@parse $fields:ident $strict:ident; ) => {};
+    (@parse ($pattern:expr); ) => {
+        $pattern.build()
+    };
     // Spread operator arm: `..` makes the object relaxed (must be last).
-    (@parse $fields:ident $strict:ident; ..) => {
-        $strict = false;
+    (@parse ($pattern:expr); ..) => {
+        $pattern.allow_rest().build()
+    };
+    // Constrained spread: `.. : matcher` requires every unlisted field to match (must be last).
+    (@parse ($pattern:expr); .. : { $($inner:tt)* } $(,)?) => {
+        $pattern
+            .rest($crate::__json_matches_pattern!(@wrap_matcher { $($inner)* }))
+            .build()
+    };
+    (@parse ($pattern:expr); .. : [ $($inner:tt)* ] $(,)?) => {
+        $pattern
+            .rest($crate::__json_matches_pattern!(@wrap_matcher [ $($inner)* ]))
+            .build()
+    };
+    (@parse ($pattern:expr); .. : $val:expr $(,)?) => {
+        $pattern
+            .rest($crate::__json_matches_pattern!(@wrap_matcher $val))
+            .build()
     };
     // Error case: `..` is only valid at the end of the object pattern.
-    (@parse $fields:ident $strict:ident; .. , $($rest:tt)+) => {
-        compile_error!("`..` must be the last token in a j::pat! object pattern");
+    (@parse ($pattern:expr); .. , $($rest:tt)+) => {
+        compile_error!("`..` must be the last token in a j::pat! object pattern")
+    };
+    // Literal key: `"name": value`.
+    (@parse ($pattern:expr); $key:literal : $($rest:tt)*) => {
+        $crate::__json_matches_pattern!(@value ($pattern) (
+            ::std::string::String::from($key)
+        ); $($rest)*)
+    };
+    // Bracketed key: a computed name like `[KEY]` or `[format!("x_{id}")]`, or a key matcher
+    // like `[matches_regex("usr_.*")]` or `[j::all_keys(matches_regex("usr_.*"))]`.
+    (@parse ($pattern:expr); [ $key:expr ] : $($rest:tt)*) => {
+        $crate::__json_matches_pattern!(@value ($pattern) ({
+            #[allow(unused_imports)]
+            use $crate::matchers::__internal_unstable_do_not_depend_on_these::{
+                ViaKeyName as _, ViaLocalKeyMatcher as _, ViaSharedKeyMatcher as _,
+            };
+            (&&&$crate::matchers::__internal_unstable_do_not_depend_on_these::FieldKeyArg::new(
+                $key,
+            ))
+                .field_key()
+        }); $($rest)*)
     };
     // Nested object value: recurse into the inner pattern (e.g., `"user": { "id": eq(1) }`).
-    (@parse $fields:ident $strict:ident;
-        $key:literal : { $($inner:tt)* } $(, $($rest:tt)*)?
-    ) => {{
-        $fields.push((
+    (@value ($pattern:expr) ($key:expr);
+        { $($inner:tt)* } $(, $($rest:tt)*)?
+    ) => {
+        $crate::__json_matches_pattern!(@parse ($pattern.field(
             $key,
             $crate::__json_matches_pattern!(@wrap_matcher { $($inner)* }),
-        ));
-        $crate::__json_matches_pattern!(@parse $fields $strict; $($($rest)*)?);
-    }};
-    // Leaf value: handles `key: expr` when the value is not an object.
-    (@parse $fields:ident $strict:ident;
-        $key:literal : $val:expr $(, $($rest:tt)*)?
-    ) => {{
-        $fields.push((
+        )); $($($rest)*)?)
+    };
+    // Nested array value: recurse into the array pattern (e.g., `"tags": ["a", ..]`).
+    (@value ($pattern:expr) ($key:expr);
+        [ $($inner:tt)* ] $(, $($rest:tt)*)?
+    ) => {
+        $crate::__json_matches_pattern!(@parse ($pattern.field(
+            $key,
+            $crate::__json_matches_pattern!(@wrap_matcher [ $($inner)* ]),
+        )); $($($rest)*)?)
+    };
+    // Leaf value: handles `key: expr` when the value is not an object or array.
+    (@value ($pattern:expr) ($key:expr);
+        $val:expr $(, $($rest:tt)*)?
+    ) => {
+        $crate::__json_matches_pattern!(@parse ($pattern.field(
             $key,
             $crate::__json_matches_pattern!(@wrap_matcher $val),
-        ));
-        $crate::__json_matches_pattern!(@parse $fields $strict; $($($rest)*)?);
-    }};
-    // Entry point: build the field list and parse the pattern tokens.
-    ({ $($tokens:tt)* }) => {{
-        let mut fields = Vec::new();
-        let mut strict = true;
-        $crate::__json_matches_pattern!(@parse fields strict; $($tokens)*);
-        $crate::matchers::__internal_unstable_do_not_depend_on_these::JsonObjectMatcher::new(
-            fields,
-            strict,
+        )); $($($rest)*)?)
+    };
+    // Array parse completion: no more elements to consume.
+    (@array ($elements:expr) $open_start:literal; ) => {
+        $crate::matchers::__internal_unstable_do_not_depend_on_these::JsonArrayPatternMatcher::new(
+            $elements.into_vec(),
+            $open_start,
+            false,
+        )
+    };
+    // Trailing `..`: the array may continue after the listed elements.
+    (@array ($elements:expr) $open_start:literal; .. $(,)?) => {
+        $crate::matchers::__internal_unstable_do_not_depend_on_these::JsonArrayPatternMatcher::new(
+            $elements.into_vec(),
+            $open_start,
+            true,
         )
-    }};
+    };
+    // Error case: `..` is only valid at the start or end of the array pattern.
+    (@array ($elements:expr) $open_start:literal; .. , $($rest:tt)+) => {
+        compile_error!("`..` must be the first or last element of a j::pat! array pattern")
+    };
+    // Nested object or array element.
+    (@array ($elements:expr) $open_start:literal;
+        { $($inner:tt)* } $(, $($rest:tt)*)?
+    ) => {
+        $crate::__json_matches_pattern!(@array ($elements.push(
+            $crate::__json_matches_pattern!(@wrap_matcher { $($inner)* })
+        )) $open_start; $($($rest)*)?)
+    };
+    (@array ($elements:expr) $open_start:literal;
+        [ $($inner:tt)* ] $(, $($rest:tt)*)?
+    ) => {
+        $crate::__json_matches_pattern!(@array ($elements.push(
+            $crate::__json_matches_pattern!(@wrap_matcher [ $($inner)* ])
+        )) $open_start; $($($rest)*)?)
+    };
+    // Leaf element.
+    (@array ($elements:expr) $open_start:literal; $val:expr $(, $($rest:tt)*)?) => {
+        $crate::__json_matches_pattern!(@array ($elements.push(
+            $crate::__json_matches_pattern!(@wrap_matcher $val)
+        )) $open_start; $($($rest)*)?)
+    };
+    // Array entry point with a leading `..`: the listed elements may be preceded by others.
+    ([ .. $(, $($tokens:tt)*)? ]) => {
+        $crate::__json_matches_pattern!(@array (
+            $crate::matchers::__internal_unstable_do_not_depend_on_these::Children::new()
+        ) true; $($($tokens)*)?)
+    };
+    // Array entry point: collect the elements while parsing the pattern tokens.
+    ([ $($tokens:tt)* ]) => {
+        $crate::__json_matches_pattern!(@array (
+            $crate::matchers::__internal_unstable_do_not_depend_on_these::Children::new()
+        ) false; $($tokens)*)
+    };
+    // Entry point: collect the entries while parsing the pattern tokens.
+    ({ $($tokens:tt)* }) => {
+        $crate::__json_matches_pattern!(@parse (
+            $crate::matchers::__internal_unstable_do_not_depend_on_these::ObjectPattern::new()
+        ); $($tokens)*)
+    };
+}
+
+/// Matches a JSON object whose every field value matches the given matcher.
+///
+/// Shorthand for `j::pat!({ .. : matcher })`; inside a larger pattern, write `.. : matcher` as
+/// the last entry to constrain only the fields the pattern does not list.
+///
+/// # Examples
+///
+/// ```rust
+/// # use googletest::prelude::*;
+/// # use googletest_json_serde::json as j;
+/// # use serde_json::json;
+/// assert_that!(json!({"a": "x", "b": "y"}), j::rest!(j::is_string()));
+/// assert_that!(json!({"a": "x", "b": null}), not(j::rest!(j::is_string())));
+/// assert_that!(
+///     json!({"id": 1, "x-trace": "abc", "x-span": "def"}),
+///     j::pat!({"id": 1, .. : j::is_non_empty_string()})
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Fails when the value is not a JSON object or when any field value does not match.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __json_rest {
+    ($matcher:expr) => {
+        $crate::__json_matches_pattern!({ .. : $matcher })
+    };
+}
+
+use crate::matchers::__internal_unstable_do_not_depend_on_these;
+use googletest::matcher::Matcher;
+
+/// Makes a key matcher in a [`j::pat!`](crate::json::pat) entry cover every key the pattern does
+/// not name, instead of at least one.
+///
+/// # Examples
+///
+/// ```rust
+/// # use googletest::prelude::*;
+/// # use googletest_json_serde::json as j;
+/// # use serde_json::json;
+/// let users = json!({"version": 2, "usr_1": {"name": "Ann"}, "usr_2": {"name": "Bo"}});
+/// assert_that!(
+///     users,
+///     j::pat!({"version": 2, [j::all_keys(matches_regex("usr_.*"))]: {"name": j::is_string()}})
+/// );
+/// assert_that!(
+///     json!({"version": 2, "usr_1": {"name": "Ann"}, "admin": {}}),
+///     not(j::pat!({"version": 2, [j::all_keys(matches_regex("usr_.*"))]: {"name": j::is_string()}}))
+/// );
+/// ```
+pub fn all_keys<M>(key_matcher: M) -> __internal_unstable_do_not_depend_on_these::AllKeys<M>
+where
+    M: for<'a> Matcher<&'a str>,
+{
+    __internal_unstable_do_not_depend_on_these::AllKeys(key_matcher)
 }
 
 #[doc(hidden)]
 pub mod internal {
-    use crate::matchers::json_matcher::internal::JsonMatcher;
+    use crate::matcher_support::path::PathSegment;
+    use crate::matchers::json_matcher::internal::{Join, JsonMatcher, SharedMatcher};
+    use crate::matchers::options_matcher::internal::JsonOptions;
+    use crate::report::{JsonMismatch, report_mismatch};
     use googletest::{
         description::Description,
         matcher::{Matcher, MatcherBase, MatcherResult},
     };
     use serde_json::{Map, Value};
+    use std::cell::Cell;
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    /// A key matcher of an object pattern entry that is `Send + Sync`.
+    #[derive(Clone, MatcherBase)]
+    pub struct SharedKeyMatcher(Arc<dyn for<'a> Matcher<&'a str> + Send + Sync>);
 
-    type FieldMatcherPair = (&'static str, Box<dyn JsonMatcher>);
-    #[derive(MatcherBase)]
-    pub struct JsonObjectMatcher {
-        fields: Vec<FieldMatcherPair>,
-        strict: bool,
+    impl<'a> Matcher<&'a str> for SharedKeyMatcher {
+        fn matches(&self, actual: &'a str) -> MatcherResult {
+            self.0.matches(actual)
+        }
+
+        fn describe(&self, result: MatcherResult) -> Description {
+            self.0.describe(result)
+        }
+
+        fn explain_match(&self, actual: &'a str) -> Description {
+            self.0.explain_match(actual)
+        }
     }
 
-    impl JsonMatcher for JsonObjectMatcher {}
+    /// A key matcher of an object pattern entry that is not thread-safe, such as googletest's
+    /// `starts_with`.
+    #[derive(Clone, MatcherBase)]
+    pub struct LocalKeyMatcher(Rc<dyn for<'a> Matcher<&'a str>>);
 
-    impl JsonObjectMatcher {
-        pub fn new(fields: Vec<FieldMatcherPair>, strict: bool) -> Self {
-            Self { fields, strict }
+    impl<'a> Matcher<&'a str> for LocalKeyMatcher {
+        fn matches(&self, actual: &'a str) -> MatcherResult {
+            self.0.matches(actual)
         }
 
-        fn collect_field_mismatches(&self, obj: &Map<String, Value>) -> Vec<String> {
+        fn describe(&self, result: MatcherResult) -> Description {
+            self.0.describe(result)
+        }
+
+        fn explain_match(&self, actual: &'a str) -> Description {
+            self.0.explain_match(actual)
+        }
+    }
+
+    impl From<SharedKeyMatcher> for LocalKeyMatcher {
+        fn from(matcher: SharedKeyMatcher) -> Self {
+            Self(Rc::new(matcher))
+        }
+    }
+
+    impl Join<SharedKeyMatcher> for SharedKeyMatcher {
+        type Out = SharedKeyMatcher;
+
+        fn left(self) -> SharedKeyMatcher {
+            self
+        }
+
+        fn right(rhs: SharedKeyMatcher) -> SharedKeyMatcher {
+            rhs
+        }
+    }
+
+    impl Join<LocalKeyMatcher> for SharedKeyMatcher {
+        type Out = LocalKeyMatcher;
+
+        fn left(self) -> LocalKeyMatcher {
+            self.into()
+        }
+
+        fn right(rhs: LocalKeyMatcher) -> LocalKeyMatcher {
+            rhs
+        }
+    }
+
+    impl Join<SharedKeyMatcher> for LocalKeyMatcher {
+        type Out = LocalKeyMatcher;
+
+        fn left(self) -> LocalKeyMatcher {
+            self
+        }
+
+        fn right(rhs: SharedKeyMatcher) -> LocalKeyMatcher {
+            rhs.into()
+        }
+    }
+
+    impl Join<LocalKeyMatcher> for LocalKeyMatcher {
+        type Out = LocalKeyMatcher;
+
+        fn left(self) -> LocalKeyMatcher {
+            self
+        }
+
+        fn right(rhs: LocalKeyMatcher) -> LocalKeyMatcher {
+            rhs
+        }
+    }
+
+    /// A key matcher for an object pattern entry, with how many keys it must cover.
+    #[derive(Clone)]
+    pub struct KeyPattern<K> {
+        matcher: K,
+        every_key: bool,
+    }
+
+    /// The key of an object pattern entry: an exact name or a key matcher.
+    #[derive(Clone)]
+    pub enum FieldKey<K> {
+        Name(String),
+        Pattern(KeyPattern<K>),
+    }
+
+    /// A key written in a `j::pat!` entry, widening the key matchers of the pattern to a type
+    /// that can hold it.
+    pub trait IntoFieldKey<K> {
+        type Out;
+
+        fn into_field_key(self) -> FieldKey<Self::Out>;
+
+        fn widen(key: K) -> Self::Out;
+    }
+
+    impl<K> IntoFieldKey<K> for String {
+        type Out = K;
+
+        fn into_field_key(self) -> FieldKey<K> {
+            FieldKey::Name(self)
+        }
+
+        fn widen(key: K) -> K {
+            key
+        }
+    }
+
+    impl<K: Join<L>, L> IntoFieldKey<K> for KeyPattern<L> {
+        type Out = K::Out;
+
+        fn into_field_key(self) -> FieldKey<K::Out> {
+            FieldKey::Pattern(KeyPattern {
+                matcher: K::right(self.matcher),
+                every_key: self.every_key,
+            })
+        }
+
+        fn widen(key: K) -> K::Out {
+            key.left()
+        }
+    }
+
+    /// Wraps a key matcher so that every key not named in the pattern must match it.
+    pub struct AllKeys<M>(pub(crate) M);
+
+    /// Picks, in order: an exact key name, a thread-safe key matcher, or any other key
+    /// matcher. See `ChildArg` for the technique.
+    pub struct FieldKeyArg<K>(Cell<Option<K>>);
+
+    impl<K> FieldKeyArg<K> {
+        pub fn new(key: K) -> Self {
+            Self(Cell::new(Some(key)))
+        }
+
+        fn take(&self) -> K {
+            self.0
+                .take()
+                .expect("an object pattern key is converted only once")
+        }
+    }
+
+    pub trait ViaKeyName {
+        fn field_key(&self) -> String;
+    }
+
+    impl<K: Into<String>> ViaKeyName for &&FieldKeyArg<K> {
+        fn field_key(&self) -> String {
+            self.take().into()
+        }
+    }
+
+    pub trait ViaSharedKeyMatcher {
+        fn field_key(&self) -> KeyPattern<SharedKeyMatcher>;
+    }
+
+    impl<M> ViaSharedKeyMatcher for &FieldKeyArg<M>
+    where
+        M: for<'a> Matcher<&'a str> + Send + Sync + 'static,
+    {
+        fn field_key(&self) -> KeyPattern<SharedKeyMatcher> {
+            KeyPattern {
+                matcher: SharedKeyMatcher(Arc::new(self.take())),
+                every_key: false,
+            }
+        }
+    }
+
+    impl<M> ViaSharedKeyMatcher for &FieldKeyArg<AllKeys<M>>
+    where
+        M: for<'a> Matcher<&'a str> + Send + Sync + 'static,
+    {
+        fn field_key(&self) -> KeyPattern<SharedKeyMatcher> {
+            KeyPattern {
+                matcher: SharedKeyMatcher(Arc::new(self.take().0)),
+                every_key: true,
+            }
+        }
+    }
+
+    pub trait ViaLocalKeyMatcher {
+        fn field_key(&self) -> KeyPattern<LocalKeyMatcher>;
+    }
+
+    impl<M> ViaLocalKeyMatcher for FieldKeyArg<M>
+    where
+        M: for<'a> Matcher<&'a str> + 'static,
+    {
+        fn field_key(&self) -> KeyPattern<LocalKeyMatcher> {
+            KeyPattern {
+                matcher: LocalKeyMatcher(Rc::new(self.take())),
+                every_key: false,
+            }
+        }
+    }
+
+    impl<M> ViaLocalKeyMatcher for FieldKeyArg<AllKeys<M>>
+    where
+        M: for<'a> Matcher<&'a str> + 'static,
+    {
+        fn field_key(&self) -> KeyPattern<LocalKeyMatcher> {
+            KeyPattern {
+                matcher: LocalKeyMatcher(Rc::new(self.take().0)),
+                every_key: true,
+            }
+        }
+    }
+
+    /// What an object pattern requires of fields it neither names nor covers by a key matcher.
+    #[derive(Clone)]
+    pub enum RestPolicy<C> {
+        /// No other fields may be present (no trailing `..`).
+        Forbid,
+        /// Other fields are accepted unconditionally (`..`).
+        Allow,
+        /// Every other field's value must match (`.. : matcher`).
+        Match(C),
+    }
+
+    /// Collects the entries of a `j::pat!` object pattern, widening the matchers already added
+    /// whenever a new one needs a more general type.
+    pub struct ObjectPattern<C, K> {
+        entries: Vec<(FieldKey<K>, C)>,
+        rest: RestPolicy<C>,
+    }
+
+    impl ObjectPattern<SharedMatcher, SharedKeyMatcher> {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    impl Default for ObjectPattern<SharedMatcher, SharedKeyMatcher> {
+        fn default() -> Self {
+            Self {
+                entries: Vec::new(),
+                rest: RestPolicy::Forbid,
+            }
+        }
+    }
+
+    impl<C: JsonMatcher, K: for<'a> Matcher<&'a str>> ObjectPattern<C, K> {
+        pub fn field<N, D>(self, key: N, matcher: D) -> ObjectPattern<C::Out, N::Out>
+        where
+            C: Join<D>,
+            N: IntoFieldKey<K>,
+        {
+            let mut entries: Vec<_> = self
+                .entries
+                .into_iter()
+                .map(|(key, matcher)| (key.map(N::widen), C::left(matcher)))
+                .collect();
+            entries.push((key.into_field_key(), C::right(matcher)));
+            ObjectPattern {
+                entries,
+                rest: self.rest.map(C::left),
+            }
+        }
+
+        /// Accepts fields the pattern does not list (`..`).
+        pub fn allow_rest(mut self) -> Self {
+            self.rest = RestPolicy::Allow;
+            self
+        }
+
+        /// Requires every field the pattern does not list to match (`.. : matcher`).
+        pub fn rest<D>(self, matcher: D) -> ObjectPattern<C::Out, K>
+        where
+            C: Join<D>,
+        {
+            ObjectPattern {
+                entries: self
+                    .entries
+                    .into_iter()
+                    .map(|(key, matcher)| (key, C::left(matcher)))
+                    .collect(),
+                rest: RestPolicy::Match(C::right(matcher)),
+            }
+        }
+
+        pub fn build(self) -> JsonObjectMatcher<C, K> {
+            JsonObjectMatcher::new(self.entries, self.rest)
+        }
+    }
+
+    impl<K> FieldKey<K> {
+        fn map<L>(self, f: impl FnOnce(K) -> L) -> FieldKey<L> {
+            match self {
+                FieldKey::Name(name) => FieldKey::Name(name),
+                FieldKey::Pattern(pattern) => FieldKey::Pattern(KeyPattern {
+                    matcher: f(pattern.matcher),
+                    every_key: pattern.every_key,
+                }),
+            }
+        }
+    }
+
+    impl<C> RestPolicy<C> {
+        fn map<D>(self, f: impl FnOnce(C) -> D) -> RestPolicy<D> {
+            match self {
+                RestPolicy::Forbid => RestPolicy::Forbid,
+                RestPolicy::Allow => RestPolicy::Allow,
+                RestPolicy::Match(matcher) => RestPolicy::Match(f(matcher)),
+            }
+        }
+    }
+
+    type FieldMatcherPair<C, K> = (FieldKey<K>, C);
+    type KeyPatternPair<C, K> = (KeyPattern<K>, C);
+    #[derive(Clone, MatcherBase)]
+    pub struct JsonObjectMatcher<C = SharedMatcher, K = SharedKeyMatcher> {
+        fields: Vec<(String, C)>,
+        key_patterns: Vec<KeyPatternPair<C, K>>,
+        rest: RestPolicy<C>,
+        // Keys listed more than once; such a pattern is ambiguous and never matches.
+        duplicate_keys: Vec<String>,
+    }
+
+    impl<K: for<'a> Matcher<&'a str>> KeyPattern<K> {
+        fn describe(&self) -> Description {
+            self.matcher.describe(MatcherResult::Match)
+        }
+    }
+
+    impl<C: JsonMatcher, K: for<'a> Matcher<&'a str>> JsonMatcher for JsonObjectMatcher<C, K> {
+        fn collect_mismatches(
+            &self,
+            actual: &Value,
+            path: &mut Vec<PathSegment>,
+            out: &mut Vec<JsonMismatch>,
+        ) {
+            let Value::Object(obj) = actual else {
+                return report_mismatch(self, actual, path, out);
+            };
+            if self.matches(actual).is_match() {
+                return;
+            }
+            for key in &self.duplicate_keys {
+                path.push(PathSegment::Field(key.clone()));
+                out.push(JsonMismatch::new(
+                    path.clone(),
+                    "a single entry for this key in the pattern",
+                    obj.get(key),
+                    format!("duplicate key '{key}' in pattern"),
+                ));
+                path.pop();
+            }
+            for (key, matcher) in &self.fields {
+                path.push(PathSegment::Field(key.clone()));
+                match JsonOptions::current().field(obj, key) {
+                    Some(value) => matcher.collect_mismatches(value, path, out),
+                    None if !matcher.allows_missing() => out.push(JsonMismatch::new(
+                        path.clone(),
+                        matcher.describe(MatcherResult::Match),
+                        None,
+                        "was missing",
+                    )),
+                    None => {}
+                }
+                path.pop();
+            }
+            for (pattern, matcher) in &self.key_patterns {
+                let mut found = false;
+                for (key, value) in Self::present_fields(obj).filter(|(key, _)| !self.is_named(key))
+                {
+                    path.push(PathSegment::Field(key.clone()));
+                    if pattern.matcher.matches(key).is_match() {
+                        found = true;
+                        matcher.collect_mismatches(value, path, out);
+                    } else if pattern.every_key {
+                        out.push(JsonMismatch::new(
+                            path.clone(),
+                            format!("a key that {}", pattern.describe()),
+                            Some(value),
+                            format!("key {}", pattern.matcher.explain_match(key)),
+                        ));
+                    }
+                    path.pop();
+                }
+                if !found && !pattern.every_key {
+                    out.push(JsonMismatch::new(
+                        path.clone(),
+                        format!("a field whose key {}", pattern.describe()),
+                        Some(actual),
+                        format!("no field whose key {}", pattern.describe()),
+                    ));
+                }
+            }
+            let rest = self.rest_policy();
+            for (key, value) in Self::present_fields(obj).filter(|(key, _)| !self.is_known(key)) {
+                path.push(PathSegment::Field(key.clone()));
+                match rest {
+                    RestPolicy::Forbid => out.push(JsonMismatch::new(
+                        path.clone(),
+                        "no such field",
+                        Some(value),
+                        format!("unexpected field '{key}' present"),
+                    )),
+                    RestPolicy::Allow => {}
+                    RestPolicy::Match(matcher) => matcher.collect_mismatches(value, path, out),
+                }
+                path.pop();
+            }
+        }
+    }
+
+    impl<C: JsonMatcher, K: for<'a> Matcher<&'a str>> JsonObjectMatcher<C, K> {
+        pub fn new(entries: Vec<FieldMatcherPair<C, K>>, rest: RestPolicy<C>) -> Self {
+            let mut fields = Vec::new();
+            let mut key_patterns = Vec::new();
+            for (key, matcher) in entries {
+                match key {
+                    FieldKey::Name(name) => fields.push((name, matcher)),
+                    FieldKey::Pattern(pattern) => key_patterns.push((pattern, matcher)),
+                }
+            }
+            let mut duplicate_keys: Vec<String> = Vec::new();
+            for (index, (key, _)) in fields.iter().enumerate() {
+                if fields[..index].iter().any(|(earlier, _)| earlier == key)
+                    && !duplicate_keys.contains(key)
+                {
+                    duplicate_keys.push(key.clone());
+                }
+            }
+            Self {
+                fields,
+                key_patterns,
+                rest,
+                duplicate_keys,
+            }
+        }
+
+        /// The fields of `obj` that count as present under the current options.
+        fn present_fields(obj: &Map<String, Value>) -> impl Iterator<Item = (&String, &Value)> {
+            let options = JsonOptions::current();
+            obj.iter()
+                .filter(move |(_, value)| !options.is_missing(value))
+        }
+
+        /// The rest policy under the current options, which may allow unlisted fields.
+        fn rest_policy(&self) -> &RestPolicy<C> {
+            match self.rest {
+                RestPolicy::Forbid if JsonOptions::current().allow_extra_fields() => {
+                    &RestPolicy::Allow
+                }
+                _ => &self.rest,
+            }
+        }
+
+        /// Whether `key` is listed by name in the pattern.
+        fn is_named(&self, key: &str) -> bool {
+            self.fields
+                .iter()
+                .any(|(expected_key, _)| expected_key == key)
+        }
+
+        /// Whether `key` is listed by name or covered by a key matcher. A key matcher wrapped in
+        /// `all_keys` covers every key and reports the ones it rejects itself.
+        fn is_known(&self, key: &str) -> bool {
+            self.is_named(key)
+                || self.key_patterns.iter().any(|(pattern, _)| {
+                    pattern.every_key || pattern.matcher.matches(key).is_match()
+                })
+        }
+
+        fn describe_entries(&self) -> String {
+            self.fields
+                .iter()
+                .map(|(k, m)| format!("  '{}': {}", k, m.describe(MatcherResult::Match)))
+                .chain(self.key_patterns.iter().map(|(pattern, m)| {
+                    let scope = if pattern.every_key {
+                        "every other key"
+                    } else {
+                        "key"
+                    };
+                    format!(
+                        "  [{scope} that {}]: {}",
+                        pattern.describe(),
+                        m.describe(MatcherResult::Match)
+                    )
+                }))
+                .chain(match &self.rest {
+                    RestPolicy::Match(m) => {
+                        Some(format!("  ..: {}", m.describe(MatcherResult::Match)))
+                    }
+                    RestPolicy::Forbid | RestPolicy::Allow => None,
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        }
+
+        fn collect_key_pattern_mismatches(&self, obj: &Map<String, Value>) -> Vec<String> {
             let mut mismatches = Vec::new();
+            for (pattern, matcher) in &self.key_patterns {
+                let mut found = false;
+                for (key, value) in Self::present_fields(obj).filter(|(key, _)| !self.is_named(key))
+                {
+                    if pattern.matcher.matches(key).is_match() {
+                        found = true;
+                        if matcher.matches(value).is_no_match() {
+                            mismatches
+                                .push(format!("  field '{key}': {}", matcher.explain_match(value)));
+                        }
+                    } else if pattern.every_key {
+                        mismatches.push(format!(
+                            "  field '{key}': key {}",
+                            pattern.matcher.explain_match(key)
+                        ));
+                    }
+                }
+                if !found && !pattern.every_key {
+                    mismatches.push(format!("  no field whose key {}", pattern.describe()));
+                }
+            }
+            mismatches
+        }
+
+        fn collect_field_mismatches(&self, obj: &Map<String, Value>) -> Vec<String> {
+            let mut mismatches = self
+                .duplicate_keys
+                .iter()
+                .map(|key| format!("  duplicate key '{key}' in pattern"))
+                .collect::<Vec<_>>();
             for (key, matcher) in &self.fields {
-                match obj.get(*key) {
+                match JsonOptions::current().field(obj, key) {
                     Some(value) => {
                         if matcher.matches(value).is_no_match() {
                             mismatches.push(format!(
@@ -145,28 +870,37 @@ pub mod internal {
 
         fn collect_unknown_fields(&self, obj: &Map<String, Value>) -> Vec<String> {
             let mut unknown_fields = Vec::new();
-            for key in obj.keys() {
-                if !self
-                    .fields
-                    .iter()
-                    .any(|(expected_key, _)| expected_key == key)
-                {
-                    unknown_fields.push(format!("  unexpected field '{key}' present"));
+            let rest = self.rest_policy();
+            for (key, value) in Self::present_fields(obj).filter(|(key, _)| !self.is_known(key)) {
+                match rest {
+                    RestPolicy::Forbid => {
+                        unknown_fields.push(format!("  unexpected field '{key}' present"));
+                    }
+                    RestPolicy::Allow => {}
+                    RestPolicy::Match(matcher) => {
+                        if matcher.matches(value).is_no_match() {
+                            unknown_fields
+                                .push(format!("  field '{key}': {}", matcher.explain_match(value)));
+                        }
+                    }
                 }
             }
             unknown_fields
         }
     }
 
-    impl Matcher<&Value> for JsonObjectMatcher {
+    impl<C: JsonMatcher, K: for<'a> Matcher<&'a str>> Matcher<&Value> for JsonObjectMatcher<C, K> {
         fn matches(&self, actual: &Value) -> MatcherResult {
             let Value::Object(obj) = actual else {
                 return MatcherResult::NoMatch;
             };
+            if !self.duplicate_keys.is_empty() {
+                return MatcherResult::NoMatch;
+            }
 
             // 1. Check all expected fields
             for (key, matcher) in &self.fields {
-                match obj.get(*key) {
+                match JsonOptions::current().field(obj, key) {
                     Some(v) => {
                         if matcher.matches(v).is_no_match() {
                             return MatcherResult::NoMatch;
@@ -181,17 +915,36 @@ pub mod internal {
                 }
             }
 
-            // 2. In strict mode, reject unknown fields
-            if self.strict {
-                for actual_key in obj.keys() {
-                    if !self
-                        .fields
-                        .iter()
-                        .any(|(expected_key, _)| expected_key == actual_key)
-                    {
+            // 2. Check entries keyed by a matcher against the keys not listed by name
+            for (pattern, matcher) in &self.key_patterns {
+                let mut found = false;
+                for (key, value) in Self::present_fields(obj).filter(|(key, _)| !self.is_named(key))
+                {
+                    if pattern.matcher.matches(key).is_match() {
+                        found = true;
+                        if matcher.matches(value).is_no_match() {
+                            return MatcherResult::NoMatch;
+                        }
+                    } else if pattern.every_key {
                         return MatcherResult::NoMatch;
                     }
                 }
+                if !found && !pattern.every_key {
+                    return MatcherResult::NoMatch;
+                }
+            }
+
+            // 3. Apply the rest policy to the remaining fields
+            let mut remaining = Self::present_fields(obj).filter(|(key, _)| !self.is_known(key));
+            let rest_ok = match self.rest_policy() {
+                RestPolicy::Forbid => remaining.next().is_none(),
+                RestPolicy::Allow => true,
+                RestPolicy::Match(matcher) => {
+                    remaining.all(|(_, value)| matcher.matches(value).is_match())
+                }
+            };
+            if !rest_ok {
+                return MatcherResult::NoMatch;
             }
 
             MatcherResult::Match
@@ -201,27 +954,24 @@ pub mod internal {
             if result.is_match() {
                 "has JSON object with expected fields".into()
             } else {
-                let expected_fields = self
-                    .fields
-                    .iter()
-                    .map(|(k, m)| format!("  '{}': {}", k, m.describe(MatcherResult::Match)))
-                    .collect::<Vec<_>>()
-                    .join("\n");
+                let expected_fields = self.describe_entries();
                 format!("expected JSON object with fields:\n{expected_fields}").into()
             }
         }
         fn explain_match(&self, actual: &Value) -> Description {
             match actual {
                 Value::Object(obj) => {
-                    if obj.is_empty() && self.fields.iter().all(|(_, m)| m.allows_missing()) {
+                    if obj.is_empty()
+                        && self.duplicate_keys.is_empty()
+                        && self.key_patterns.is_empty()
+                        && self.fields.iter().all(|(_, m)| m.allows_missing())
+                    {
                         Description::new()
                     } else {
                         let mut mismatches = self.collect_field_mismatches(obj);
+                        mismatches.extend(self.collect_key_pattern_mismatches(obj));
 
-                        if self.strict {
-                            let unknown_fields = self.collect_unknown_fields(obj);
-                            mismatches.extend(unknown_fields);
-                        }
+                        mismatches.extend(self.collect_unknown_fields(obj));
 
                         if mismatches.is_empty() {
                             Description::new().text("all fields matched as expected")
@@ -250,7 +1000,9 @@ pub mod internal {
 
     /// Support matching on `Option<Value>` to handle cases where JSON objects may be optional,
     /// such as API responses that might be null.
-    impl Matcher<&Option<Value>> for JsonObjectMatcher {
+    impl<C: JsonMatcher, K: for<'a> Matcher<&'a str>> Matcher<&Option<Value>>
+        for JsonObjectMatcher<C, K>
+    {
         fn matches(&self, actual: &Option<Value>) -> MatcherResult {
             match actual {
                 Some(v) => self.matches(v),
@@ -262,12 +1014,7 @@ pub mod internal {
             if result.is_match() {
                 "has Some(JSON object) with expected fields".into()
             } else {
-                let expected_fields = self
-                    .fields
-                    .iter()
-                    .map(|(k, m)| format!("  '{}': {}", k, m.describe(MatcherResult::Match)))
-                    .collect::<Vec<_>>()
-                    .join("\n");
+                let expected_fields = self.describe_entries();
                 format!("expected Some(JSON object) with fields:\n{expected_fields}").into()
             }
         }
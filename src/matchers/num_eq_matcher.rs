@@ -0,0 +1,125 @@
+use crate::matchers::__internal_unstable_do_not_depend_on_these::{JsonNumEqMatcher, JsonNumber};
+
+/// Matches a JSON number with the same value as `expected`, however it is written.
+///
+/// `1`, `1.0` and `1e0` are all numerically equal, so the matcher accepts numbers serialised by
+/// services that disagree on integer vs. float representations. Integers are compared exactly,
+/// not through `f64`. A float tolerance set with [`with_options!`](crate::json::with_options)
+/// also applies.
+///
+/// To compare every literal in a pattern this way, wrap it in
+/// `j::with_options!(j::options().coercing_numbers(), ...)` instead.
+///
+/// # Examples
+///
+/// ```rust
+/// # use googletest::prelude::*;
+/// # use googletest_json_serde::json as j;
+/// # use serde_json::json;
+/// assert_that!(json!(3.0), j::num_eq(3));
+/// assert_that!(json!(3), j::num_eq(3.0));
+/// assert_that!(json!({"qty": 2e0}), j::pat!({"qty": j::num_eq(2u8)}));
+/// assert_that!(json!(3.5), not(j::num_eq(3)));
+/// assert_that!(json!("3"), not(j::num_eq(3)));
+/// ```
+///
+/// # Panics
+///
+/// Panics if `expected` is a NaN or infinite float, which JSON cannot represent.
+pub fn num_eq<N: JsonNumber>(expected: N) -> JsonNumEqMatcher {
+    JsonNumEqMatcher::new(expected.into_number())
+}
+
+#[doc(hidden)]
+pub mod internal {
+    use crate::matchers::json_matcher::internal::{JsonMatcher, describe_json_type};
+    use crate::matchers::options_matcher::internal::JsonOptions;
+    use googletest::description::Description;
+    use googletest::matcher::{Matcher, MatcherBase, MatcherResult};
+    use serde_json::{Number, Value};
+
+    /// Rust numbers accepted by `j::num_eq`.
+    #[doc(hidden)]
+    pub trait JsonNumber {
+        fn into_number(self) -> Number;
+    }
+
+    macro_rules! impl_json_number_for_int {
+        ($($t:ty),*) => {
+            $(impl JsonNumber for $t {
+                fn into_number(self) -> Number {
+                    Number::from(self)
+                }
+            })*
+        };
+    }
+
+    macro_rules! impl_json_number_for_float {
+        ($($t:ty),*) => {
+            $(impl JsonNumber for $t {
+                fn into_number(self) -> Number {
+                    Number::from_f64(self.into()).unwrap_or_else(|| {
+                        panic!("j::num_eq expects a finite number, got {self}")
+                    })
+                }
+            })*
+        };
+    }
+
+    impl_json_number_for_int!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+    impl_json_number_for_float!(f32, f64);
+
+    impl JsonNumber for Number {
+        fn into_number(self) -> Number {
+            self
+        }
+    }
+
+    /// Compares JSON numbers by value rather than representation; see `j::num_eq`.
+    #[doc(hidden)]
+    #[derive(Clone, MatcherBase)]
+    pub struct JsonNumEqMatcher {
+        expected: Value,
+    }
+
+    impl JsonNumEqMatcher {
+        pub fn new(expected: Number) -> Self {
+            Self {
+                expected: Value::Number(expected),
+            }
+        }
+    }
+
+    impl Matcher<&Value> for JsonNumEqMatcher {
+        fn matches(&self, actual: &Value) -> MatcherResult {
+            (actual.is_number()
+                && JsonOptions::current()
+                    .coercing_numbers()
+                    .values_equal(actual, &self.expected))
+            .into()
+        }
+
+        fn describe(&self, result: MatcherResult) -> Description {
+            match result {
+                MatcherResult::Match => format!("is numerically equal to {}", self.expected),
+                MatcherResult::NoMatch => format!("isn't numerically equal to {}", self.expected),
+            }
+            .into()
+        }
+
+        fn explain_match(&self, actual: &Value) -> Description {
+            if !actual.is_number() {
+                return describe_json_type(actual);
+            }
+            match self.matches(actual) {
+                MatcherResult::Match => format!("which is numerically equal to {}", self.expected),
+                MatcherResult::NoMatch => {
+                    format!("which isn't numerically equal to {}", self.expected)
+                }
+            }
+            .into()
+        }
+    }
+
+    impl JsonMatcher for JsonNumEqMatcher {}
+}
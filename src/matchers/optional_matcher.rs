@@ -27,35 +27,48 @@
 macro_rules! __json_optional {
     ($inner:expr) => {{
         $crate::matchers::__internal_unstable_do_not_depend_on_these::JsonOptionalMatcher::new(
-            $crate::matchers::__internal_unstable_do_not_depend_on_these::IntoJsonMatcher::into_json_matcher($inner),
+            $crate::__json_into_matcher!($inner),
         )
     }};
 }
 
 pub mod internal {
-    use crate::matchers::json_matcher::internal::JsonMatcher;
+    use crate::matcher_support::path::PathSegment;
+    use crate::matchers::json_matcher::internal::{JsonMatcher, SharedMatcher};
+    use crate::report::JsonMismatch;
     use googletest::description::Description;
     use googletest::matcher::{Matcher, MatcherBase, MatcherResult};
     use serde_json::Value;
 
-    #[derive(MatcherBase)]
-    pub struct JsonOptionalMatcher {
-        inner: Box<dyn JsonMatcher>,
+    #[derive(Clone, MatcherBase)]
+    pub struct JsonOptionalMatcher<C = SharedMatcher> {
+        inner: C,
     }
 
-    impl JsonOptionalMatcher {
-        pub fn new(inner: Box<dyn JsonMatcher>) -> Self {
+    impl<C: JsonMatcher> JsonOptionalMatcher<C> {
+        pub fn new(inner: C) -> Self {
             Self { inner }
         }
     }
 
-    impl JsonMatcher for JsonOptionalMatcher {
+    impl<C: JsonMatcher> JsonMatcher for JsonOptionalMatcher<C> {
         fn allows_missing(&self) -> bool {
             true
         }
+
+        fn collect_mismatches(
+            &self,
+            actual: &Value,
+            path: &mut Vec<PathSegment>,
+            out: &mut Vec<JsonMismatch>,
+        ) {
+            if !actual.is_null() {
+                self.inner.collect_mismatches(actual, path, out);
+            }
+        }
     }
 
-    impl Matcher<&Value> for JsonOptionalMatcher {
+    impl<C: JsonMatcher> Matcher<&Value> for JsonOptionalMatcher<C> {
         fn matches(&self, actual: &Value) -> MatcherResult {
             if actual.is_null() {
                 MatcherResult::Match
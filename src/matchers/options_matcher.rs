@@ -0,0 +1,325 @@
+use std::fmt;
+
+/// Starts a set of matching options for [`with_options!`](crate::json::with_options), with every
+/// policy off.
+///
+/// # Examples
+///
+/// ```rust
+/// # use googletest_json_serde::json as j;
+/// let options = j::options()
+///     .float_tolerance(1e-9)
+///     .coercing_numbers()
+///     .allowing_extra_fields()
+///     .treating_null_as_missing();
+/// ```
+pub fn options() -> JsonOptions {
+    JsonOptions::default()
+}
+
+/// Matching policies for [`with_options!`](crate::json::with_options), built with
+/// [`options()`](crate::json::options).
+///
+/// Every policy starts off; each builder method turns one on. Options of a nested
+/// `with_options!` are merged over the outer ones: the policies either turns on apply, and an
+/// inner float tolerance takes the place of the outer one.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct JsonOptions {
+    float_tolerance: f64,
+    coerce_numbers: bool,
+    allow_extra_fields: bool,
+    null_as_missing: bool,
+}
+
+impl JsonOptions {
+    /// Lets literal numbers equal actual floats that differ by at most `tolerance`.
+    pub fn float_tolerance(mut self, tolerance: f64) -> Self {
+        self.float_tolerance = tolerance.abs();
+        self
+    }
+
+    /// Treats an integer and a float with the same value as equal.
+    pub fn coercing_numbers(mut self) -> Self {
+        self.coerce_numbers = true;
+        self
+    }
+
+    /// Lets object patterns without a trailing `..` accept fields they do not list.
+    pub fn allowing_extra_fields(mut self) -> Self {
+        self.allow_extra_fields = true;
+        self
+    }
+
+    /// Treats fields whose value is `null` as absent.
+    pub fn treating_null_as_missing(mut self) -> Self {
+        self.null_as_missing = true;
+        self
+    }
+}
+
+impl fmt::Display for JsonOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut policies = Vec::new();
+        if self.float_tolerance > 0.0 {
+            policies.push(format!("float tolerance {}", self.float_tolerance));
+        }
+        if self.coerce_numbers {
+            policies.push("integers equal to floats".to_string());
+        }
+        if self.allow_extra_fields {
+            policies.push("extra fields allowed".to_string());
+        }
+        if self.null_as_missing {
+            policies.push("null treated as missing".to_string());
+        }
+        if policies.is_empty() {
+            write!(f, "default options")
+        } else {
+            write!(f, "{}", policies.join(", "))
+        }
+    }
+}
+
+/// Applies crate-wide matching policies to a matcher and everything nested inside it.
+///
+/// The options are in effect while the matcher runs, so they reach patterns at any depth
+/// without repeating `near(...)` or `..` on every field:
+/// - `.float_tolerance(eps)`: numbers are equal within `eps` when either side is a float, so
+///   the literal `1` matches `1.0000001`.
+/// - `.coercing_numbers()`: integers and floats with the same value are equal, so `1` matches
+///   `1.0` and `j::primitive!(eq(3))` accepts `3.0`.
+/// - `.allowing_extra_fields()`: object patterns without a trailing `..` accept unlisted
+///   fields.
+/// - `.treating_null_as_missing()`: `null` fields count as absent, both when object patterns
+///   look up fields and when literal objects are compared.
+///
+/// Literal values, `j::pat!` objects and the array matchers built from them honour the options;
+/// native googletest matchers such as `eq(...)` and the `j::eq_json!` diff do not. A nested
+/// `with_options!` adds its policies to the outer ones for its subtree, and its float tolerance,
+/// if set, replaces the outer one.
+///
+/// The matcher may be anything a `j::pat!` field accepts: a JSON matcher, a literal, a
+/// `serde_json::Value` or a native googletest matcher.
+///
+/// # Examples
+///
+/// ```rust
+/// # use googletest::prelude::*;
+/// # use googletest_json_serde::json as j;
+/// # use serde_json::json;
+/// let response = json!({
+///     "total": 0.30000000000000004,
+///     "count": 2.0,
+///     "items": [{"id": 1, "note": null, "etag": "x"}]
+/// });
+/// assert_that!(
+///     response,
+///     j::with_options!(
+///         j::options()
+///             .float_tolerance(1e-9)
+///             .coercing_numbers()
+///             .allowing_extra_fields()
+///             .treating_null_as_missing(),
+///         j::pat!({
+///             "total": 0.3,
+///             "count": 2,
+///             "items": j::elements_are![j::pat!({"id": 1, "note": j::absent()})],
+///         })
+///     )
+/// );
+/// assert_that!(
+///     json!([1.0000001]),
+///     j::with_options!(j::options().float_tolerance(1e-6), json!([1]))
+/// );
+/// ```
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __json_with_options {
+    ($options:expr, $inner:expr $(,)?) => {
+        $crate::matchers::__internal_unstable_do_not_depend_on_these::JsonWithOptionsMatcher::new(
+            $options,
+            $crate::__json_into_matcher!($inner),
+        )
+    };
+}
+
+#[doc(hidden)]
+pub mod internal {
+    use crate::matcher_support::number;
+    use crate::matcher_support::path::PathSegment;
+    use crate::matchers::json_matcher::internal::{JsonMatcher, SharedMatcher};
+    use crate::report::JsonMismatch;
+    use googletest::description::Description;
+    use googletest::matcher::{Matcher, MatcherBase, MatcherResult};
+    use serde_json::{Map, Number, Value};
+    use std::cell::Cell;
+
+    pub use super::JsonOptions;
+
+    thread_local! {
+        /// The options of the innermost `with_options` being evaluated on this thread.
+        static CURRENT: Cell<JsonOptions> = Cell::new(JsonOptions::default());
+    }
+
+    impl JsonOptions {
+        /// The options in effect on this thread.
+        pub(crate) fn current() -> Self {
+            CURRENT.with(Cell::get)
+        }
+
+        pub(crate) fn coerce_numbers(&self) -> bool {
+            self.coerce_numbers
+        }
+
+        pub(crate) fn allow_extra_fields(&self) -> bool {
+            self.allow_extra_fields
+        }
+
+        /// The value of `key` in `obj`, unless it is `null` and nulls count as missing.
+        pub(crate) fn field<'a>(
+            &self,
+            obj: &'a Map<String, Value>,
+            key: &str,
+        ) -> Option<&'a Value> {
+            obj.get(key).filter(|value| !self.is_missing(value))
+        }
+
+        /// Whether a field holding `value` counts as absent.
+        pub(crate) fn is_missing(&self, value: &Value) -> bool {
+            self.null_as_missing && value.is_null()
+        }
+
+        /// Compares two values under these options.
+        pub(crate) fn values_equal(&self, actual: &Value, expected: &Value) -> bool {
+            if *self == Self::default() {
+                return actual == expected;
+            }
+            match (actual, expected) {
+                (Value::Number(actual), Value::Number(expected)) => {
+                    self.numbers_equal(actual, expected)
+                }
+                (Value::Array(actual), Value::Array(expected)) => {
+                    actual.len() == expected.len()
+                        && actual
+                            .iter()
+                            .zip(expected)
+                            .all(|(actual, expected)| self.values_equal(actual, expected))
+                }
+                (Value::Object(actual), Value::Object(expected)) => {
+                    let present = |obj: &Map<String, Value>| {
+                        obj.values().filter(|value| !self.is_missing(value)).count()
+                    };
+                    present(actual) == present(expected)
+                        && expected
+                            .iter()
+                            .filter(|(_, value)| !self.is_missing(value))
+                            .all(|(key, expected)| {
+                                self.field(actual, key)
+                                    .is_some_and(|actual| self.values_equal(actual, expected))
+                            })
+                }
+                _ => actual == expected,
+            }
+        }
+
+        fn numbers_equal(&self, actual: &Number, expected: &Number) -> bool {
+            let same_kind = actual.is_f64() == expected.is_f64() || self.coerce_numbers;
+            (same_kind && number::same_value(actual, expected))
+                || (self.float_tolerance > 0.0
+                    && (actual.is_f64() || expected.is_f64())
+                    && self.floats_equal(actual, expected))
+        }
+
+        fn floats_equal(&self, actual: &Number, expected: &Number) -> bool {
+            match (actual.as_f64(), expected.as_f64()) {
+                (Some(actual), Some(expected)) => (actual - expected).abs() <= self.float_tolerance,
+                _ => false,
+            }
+        }
+
+        /// These options with any policy they leave off taken from `outer`.
+        fn merged_over(self, outer: Self) -> Self {
+            Self {
+                float_tolerance: if self.float_tolerance > 0.0 {
+                    self.float_tolerance
+                } else {
+                    outer.float_tolerance
+                },
+                coerce_numbers: self.coerce_numbers || outer.coerce_numbers,
+                allow_extra_fields: self.allow_extra_fields || outer.allow_extra_fields,
+                null_as_missing: self.null_as_missing || outer.null_as_missing,
+            }
+        }
+
+        /// Merges these over the current options until the guard is dropped.
+        fn enter(self) -> OptionsGuard {
+            OptionsGuard {
+                previous: CURRENT.with(|current| current.replace(self.merged_over(current.get()))),
+            }
+        }
+    }
+
+    /// Restores the previous options on drop.
+    struct OptionsGuard {
+        previous: JsonOptions,
+    }
+
+    impl Drop for OptionsGuard {
+        fn drop(&mut self) {
+            CURRENT.with(|current| current.set(self.previous));
+        }
+    }
+
+    /// Evaluates a matcher with matching options in effect; see `j::with_options!`.
+    #[doc(hidden)]
+    #[derive(Clone, MatcherBase)]
+    pub struct JsonWithOptionsMatcher<C = SharedMatcher> {
+        options: JsonOptions,
+        inner: C,
+    }
+
+    impl<C: JsonMatcher> JsonWithOptionsMatcher<C> {
+        pub fn new(options: JsonOptions, inner: C) -> Self {
+            Self { options, inner }
+        }
+    }
+
+    impl<C: JsonMatcher> Matcher<&Value> for JsonWithOptionsMatcher<C> {
+        fn matches(&self, actual: &Value) -> MatcherResult {
+            let _guard = self.options.enter();
+            self.inner.matches(actual)
+        }
+
+        fn describe(&self, result: MatcherResult) -> Description {
+            let _guard = self.options.enter();
+            format!(
+                "{}\n(with {})",
+                self.inner.describe(result),
+                JsonOptions::current()
+            )
+            .into()
+        }
+
+        fn explain_match(&self, actual: &Value) -> Description {
+            let _guard = self.options.enter();
+            self.inner.explain_match(actual)
+        }
+    }
+
+    impl<C: JsonMatcher> JsonMatcher for JsonWithOptionsMatcher<C> {
+        fn allows_missing(&self) -> bool {
+            let _guard = self.options.enter();
+            self.inner.allows_missing()
+        }
+
+        fn collect_mismatches(
+            &self,
+            actual: &Value,
+            path: &mut Vec<PathSegment>,
+            out: &mut Vec<JsonMismatch>,
+        ) {
+            let _guard = self.options.enter();
+            self.inner.collect_mismatches(actual, path, out);
+        }
+    }
+}
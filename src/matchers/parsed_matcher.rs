@@ -0,0 +1,85 @@
+use crate::matchers::__internal_unstable_do_not_depend_on_these::{JsonMatcher, JsonParsedMatcher};
+
+/// Matches raw JSON text or bytes, or a container of a `serde_json::Value`, by the JSON value
+/// it holds.
+///
+/// Text and bytes (`&str`, `String`, `&[u8]`, `Vec<u8>`, and `bytes::Bytes` / `BytesMut` with
+/// the `bytes` feature) are parsed before the JSON matcher runs. Invalid JSON never matches,
+/// and the explanation points at the line and column of the parse error.
+///
+/// `Option<Value>`, `Option<&Value>` and `Result<Value, E>` lend the value they hold; `None`
+/// and `Err(...)` never match and are explained as "was None" / "was Err(...)". A
+/// `Map<String, Value>` or `Vec<Value>` is copied into a new `Value` on every match.
+///
+/// # Examples
+///
+/// ```rust
+/// # use googletest::prelude::*;
+/// # use googletest_json_serde::json as j;
+/// # use serde_json::json;
+/// let body = r#"{"id": 7, "roles": ["admin"]}"#;
+/// assert_that!(body, j::parsed(j::pat!({"id": 7, "roles": j::len!(1)})));
+/// assert_that!(br#"[1, 2]"#, j::parsed(j::elements_are![1, 2]));
+///
+/// let user = json!({"user": {"id": 7}});
+/// assert_that!(user.get("user"), j::parsed(j::has_path_with!("id", 7)));
+/// assert_that!(user.get("team"), not(j::parsed(j::is_object())));
+/// ```
+///
+/// ```rust,should_panic
+/// # use googletest::prelude::*;
+/// # use googletest_json_serde::json as j;
+/// // Fails with:
+/// //   which is not valid JSON: expected `:` at line 1, column 7
+/// //     {"id" 7}
+/// //           ^
+/// assert_that!(r#"{"id" 7}"#, j::parsed(j::pat!({"id": 7})));
+/// ```
+pub fn parsed<M: JsonMatcher>(inner: M) -> JsonParsedMatcher<M> {
+    JsonParsedMatcher::new(inner)
+}
+
+#[doc(hidden)]
+pub mod internal {
+    use crate::matcher_support::json_input::JsonInput;
+    use crate::matchers::json_matcher::internal::JsonMatcher;
+    use googletest::description::Description;
+    use googletest::matcher::{Matcher, MatcherBase, MatcherResult};
+
+    /// Converts raw JSON or a container into a `Value` before applying a JSON matcher; see
+    /// `j::parsed`.
+    #[doc(hidden)]
+    #[derive(Clone, MatcherBase)]
+    pub struct JsonParsedMatcher<M> {
+        inner: M,
+    }
+
+    impl<M> JsonParsedMatcher<M> {
+        pub fn new(inner: M) -> Self {
+            Self { inner }
+        }
+    }
+
+    impl<Input, M> Matcher<Input> for JsonParsedMatcher<M>
+    where
+        Input: JsonInput,
+        M: JsonMatcher,
+    {
+        fn matches(&self, actual: Input) -> MatcherResult {
+            actual
+                .to_json()
+                .map_or(MatcherResult::NoMatch, |value| self.inner.matches(&value))
+        }
+
+        fn describe(&self, result: MatcherResult) -> Description {
+            self.inner.describe(result)
+        }
+
+        fn explain_match(&self, actual: Input) -> Description {
+            match actual.to_json() {
+                Ok(value) => self.inner.explain_match(&value),
+                Err(explanation) => explanation.into(),
+            }
+        }
+    }
+}
@@ -1,15 +1,25 @@
-use crate::matcher_support::path::{ParsedPaths, collect_paths, format_path, parse_expected_paths};
+use crate::matcher_support::path::{
+    ParsedPath, ParsedPaths, PathSegment, PathSyntax, collect_paths, parse_path_specs,
+};
 use crate::matchers::__internal_unstable_do_not_depend_on_these;
-use crate::matchers::__internal_unstable_do_not_depend_on_these::JsonPredicateMatcher;
+use crate::matchers::__internal_unstable_do_not_depend_on_these::{
+    JsonPathArg, JsonPredicateMatcher,
+};
 use googletest::description::Description;
 use serde_json::Value;
 use std::collections::BTreeSet;
 
 /// Matches a JSON leaf at the given path against the provided matcher.
 ///
-/// The path uses the same dot-and-escape rules as [`has_paths`]. The matcher can be a literal,
+/// The path uses the same dot-and-escape rules as [`has_paths`], or RFC 6901 JSON Pointer
+/// syntax when it starts with `/` or is built with [`pointer()`]. The matcher can be a literal,
 /// a `serde_json::Value`, or any native googletest matcher.
 ///
+/// Paths may contain `*` (any key or index) and `**` (any depth) segments. By default every
+/// selected path must satisfy the matcher; call `.for_any()` to require at least one. A wildcard
+/// path that selects nothing never matches, and the explanation lists each concrete path that
+/// failed.
+///
 /// # Examples
 ///
 /// ```rust
@@ -23,6 +33,13 @@ use std::collections::BTreeSet;
 ///         .and(j::has_path_with!("user.id", json!(7)))
 ///         .and(j::has_path_with!("user.name", starts_with("A")))
 /// );
+///
+/// let order = json!({"items": [{"price": 3}, {"price": 0}]});
+/// assert_that!(order, j::has_path_with!("items.*.price", ge(0)));
+/// assert_that!(order, j::has_path_with!("**.price", gt(0)).for_any());
+/// assert_that!(order, not(j::has_path_with!("items.*.price", gt(0))));
+///
+/// assert_that!(value, j::has_path_with!("/user/name", "Ada"));
 /// ```
 ///
 /// # Supported Inputs
@@ -31,22 +48,32 @@ use std::collections::BTreeSet;
 /// - Native googletest matchers
 ///
 /// # Errors
-/// Fails when the path is invalid, missing, the value is not an object, or the leaf does not
-/// satisfy the matcher.
+/// Fails when the path is invalid, missing (or a wildcard selects nothing), the value is not an
+/// object, or the selected leaves do not satisfy the matcher under the chosen quantifier.
 #[macro_export]
 #[doc(hidden)]
 macro_rules! __json_has_path_with {
     ($path:expr, $matcher:expr) => {{
         $crate::matchers::__internal_unstable_do_not_depend_on_these::JsonPathWithMatcher::new(
             $path,
-            $crate::matchers::__internal_unstable_do_not_depend_on_these::IntoJsonMatcher::into_json_matcher($matcher),
+            $crate::__json_into_matcher!($matcher),
         )
     }};
 }
 
 /// Matches a JSON object that contains all specified paths (order-agnostic, extras allowed).
 ///
-/// Paths use dot notation; escape dots inside field names with `\`.
+/// Paths use dot notation; escape dots inside field names with `\`. A `*` segment matches any
+/// single key or index and `**` matches any depth; a wildcard path is satisfied when it selects at
+/// least one actual path. Escape a literal `*` field name as `\*`.
+///
+/// Paths starting with `/` (or built with [`pointer()`]) are parsed as RFC 6901 JSON Pointers,
+/// with `~0`/`~1` escapes and no wildcards. A numeric segment is an array index or, inside an
+/// object, a key, so `/items/42` finds `{"items": {"42": 1}}`. Explanations echo each path in
+/// the syntax it was written in.
+///
+/// An empty list has no element to infer the path type from, so spell it out:
+/// `j::has_paths::<&str>(&[])`.
 ///
 /// # Examples
 ///
@@ -56,14 +83,17 @@ macro_rules! __json_has_path_with {
 /// # use serde_json::json;
 /// let value = json!({"user": {"id": 7, "name": "Ada"}});
 /// assert_that!(value, j::has_paths(&["user.id", "user.name"]));
+/// assert_that!(value, j::has_paths(&["user.*", "**.name"]));
+/// assert_that!(value, j::has_paths(&["/user/id", "/user/name"]));
 /// ```
 ///
 /// # Errors
 ///
 /// Fails when any path is invalid, when the value is not a JSON object, or when required paths are missing.
-pub fn has_paths(paths: &[&str]) -> JsonPredicateMatcher<impl Fn(&Value) -> bool, String, String> {
-    let ParsedPaths { parsed, errors } = parse_expected_paths(paths);
-    let expected_set: BTreeSet<_> = parsed.iter().map(|p| p.segments.clone()).collect();
+pub fn has_paths<P: JsonPathArg>(
+    paths: &[P],
+) -> JsonPredicateMatcher<impl Fn(&Value) -> bool + Clone + Send + Sync, String, String> {
+    let ParsedPaths { parsed, errors } = parse_path_specs(paths.iter().map(P::path_spec));
     let errors_for_explain = errors.clone();
     let expected_desc = format!(
         "a JSON object containing paths {:?}",
@@ -76,14 +106,14 @@ pub fn has_paths(paths: &[&str]) -> JsonPredicateMatcher<impl Fn(&Value) -> bool
 
     JsonPredicateMatcher::new(
         {
-            let expected_set = expected_set.clone();
+            let parsed = parsed.clone();
             let errors = errors.clone();
             move |v| {
                 if !errors.is_empty() || !v.is_object() {
                     return false;
                 }
                 let actual = collect_paths(v);
-                expected_set.iter().all(|p| actual.contains(p))
+                missing_patterns(&parsed, &actual).is_empty()
             }
         },
         expected_desc,
@@ -103,13 +133,16 @@ pub fn has_paths(paths: &[&str]) -> JsonPredicateMatcher<impl Fn(&Value) -> bool
             return __internal_unstable_do_not_depend_on_these::describe_json_type(v);
         }
         let actual = collect_paths(v);
-        let missing: BTreeSet<_> = expected_set.difference(&actual).cloned().collect();
+        let missing = missing_patterns(&parsed, &actual);
         if missing.is_empty() {
             Description::new()
         } else {
             Description::new().text(format!(
                 "missing paths {:?}",
-                missing.iter().map(|p| format_path(p)).collect::<Vec<_>>()
+                missing
+                    .iter()
+                    .map(|p| p.display_pattern())
+                    .collect::<Vec<_>>()
             ))
         }
     })
@@ -117,6 +150,12 @@ pub fn has_paths(paths: &[&str]) -> JsonPredicateMatcher<impl Fn(&Value) -> bool
 
 /// Matches a JSON object whose paths are exactly the provided set (no extras or missing).
 ///
+/// Wildcard segments (`*`, `**`) are allowed: every actual path must be covered by some pattern,
+/// and every pattern must select at least one actual path.
+///
+/// Extra paths are explained as JSON Pointers when every expected path is one, and in dot
+/// notation otherwise, including when the expected paths mix both syntaxes.
+///
 /// # Examples
 ///
 /// ```rust
@@ -125,16 +164,16 @@ pub fn has_paths(paths: &[&str]) -> JsonPredicateMatcher<impl Fn(&Value) -> bool
 /// # use serde_json::json;
 /// let value = json!({"ids": [1, 2], "ok": true});
 /// assert_that!(value, j::has_only_paths(&["ids", "ids.0", "ids.1", "ok"]));
+/// assert_that!(value, j::has_only_paths(&["ids", "ids.*", "ok"]));
 /// ```
 ///
 /// # Errors
 ///
 /// Fails when any path is invalid, when the value is not a JSON object, or when the set of paths differs.
-pub fn has_only_paths(
-    paths: &[&str],
-) -> JsonPredicateMatcher<impl Fn(&Value) -> bool, String, String> {
-    let ParsedPaths { parsed, errors } = parse_expected_paths(paths);
-    let expected_set: BTreeSet<_> = parsed.iter().map(|p| p.segments.clone()).collect();
+pub fn has_only_paths<P: JsonPathArg>(
+    paths: &[P],
+) -> JsonPredicateMatcher<impl Fn(&Value) -> bool + Clone + Send + Sync, String, String> {
+    let ParsedPaths { parsed, errors } = parse_path_specs(paths.iter().map(P::path_spec));
     let errors_for_explain = errors.clone();
     let expected_desc = format!(
         "a JSON object with exactly paths {:?}",
@@ -147,14 +186,15 @@ pub fn has_only_paths(
 
     JsonPredicateMatcher::new(
         {
-            let expected_set = expected_set.clone();
+            let parsed = parsed.clone();
             let errors = errors.clone();
             move |v| {
                 if !errors.is_empty() || !v.is_object() {
                     return false;
                 }
                 let actual = collect_paths(v);
-                actual == expected_set
+                missing_patterns(&parsed, &actual).is_empty()
+                    && extra_paths(&parsed, &actual).is_empty()
             }
         },
         expected_desc,
@@ -174,102 +214,306 @@ pub fn has_only_paths(
             return __internal_unstable_do_not_depend_on_these::describe_json_type(v);
         }
         let actual = collect_paths(v);
-        let missing: BTreeSet<_> = expected_set.difference(&actual).cloned().collect();
-        let extra: BTreeSet<_> = actual.difference(&expected_set).cloned().collect();
+        let missing = missing_patterns(&parsed, &actual);
+        let extra = extra_paths(&parsed, &actual);
+        let syntax = common_syntax(&parsed);
         match (!missing.is_empty(), !extra.is_empty()) {
             (true, true) => Description::new()
                 .text(format!(
                     "missing paths {:?}",
-                    missing.iter().map(|p| format_path(p)).collect::<Vec<_>>()
+                    missing
+                        .iter()
+                        .map(|p| p.display_pattern())
+                        .collect::<Vec<_>>()
                 ))
                 .text(format!(
                     ", extra paths {:?}",
-                    extra.iter().map(|p| format_path(p)).collect::<Vec<_>>()
+                    extra.iter().map(|p| syntax.format(p)).collect::<Vec<_>>()
                 )),
             (true, false) => Description::new().text(format!(
                 "missing paths {:?}",
-                missing.iter().map(|p| format_path(p)).collect::<Vec<_>>()
+                missing
+                    .iter()
+                    .map(|p| p.display_pattern())
+                    .collect::<Vec<_>>()
             )),
             (false, true) => Description::new().text(format!(
                 "extra paths {:?}",
-                extra.iter().map(|p| format_path(p)).collect::<Vec<_>>()
+                extra.iter().map(|p| syntax.format(p)).collect::<Vec<_>>()
             )),
             (false, false) => Description::new(),
         }
     })
 }
 
+/// The syntax every expected path is written in, or dot notation when they mix syntaxes.
+fn common_syntax(parsed: &[ParsedPath]) -> PathSyntax {
+    match parsed.first() {
+        Some(first) if parsed.iter().all(|p| p.syntax == first.syntax) => first.syntax,
+        _ => PathSyntax::Dot,
+    }
+}
+
+/// Expected patterns that select none of the actual paths, sorted and deduplicated.
+fn missing_patterns<'a>(
+    parsed: &'a [ParsedPath],
+    actual: &BTreeSet<Vec<PathSegment>>,
+) -> Vec<&'a ParsedPath> {
+    let mut missing: Vec<_> = parsed
+        .iter()
+        .filter(|pattern| !actual.iter().any(|path| pattern.matches(path)))
+        .collect();
+    missing.sort_by(|a, b| a.segments.cmp(&b.segments));
+    missing.dedup_by(|a, b| a.segments == b.segments);
+    missing
+}
+
+/// Actual paths that no expected pattern selects.
+fn extra_paths(
+    parsed: &[ParsedPath],
+    actual: &BTreeSet<Vec<PathSegment>>,
+) -> BTreeSet<Vec<PathSegment>> {
+    actual
+        .iter()
+        .filter(|path| !parsed.iter().any(|pattern| pattern.matches(path)))
+        .cloned()
+        .collect()
+}
+
+/// Builds a path that is always parsed as an RFC 6901 JSON Pointer.
+///
+/// Paths starting with `/` are detected as pointers automatically; use this to make the intent
+/// explicit or to get a clear error for pointers missing their leading `/`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use googletest::prelude::*;
+/// # use googletest_json_serde::json as j;
+/// # use serde_json::json;
+/// let value = json!({"user": {"addresses": [{"zip": "1234"}], "a/b": true}});
+/// assert_that!(value, j::has_path_with!(j::pointer("/user/addresses/0/zip"), "1234"));
+/// assert_that!(value, j::has_paths(&[j::pointer("/user/a~1b")]));
+/// ```
+pub fn pointer(pointer: impl Into<String>) -> JsonPointer {
+    JsonPointer(pointer.into())
+}
+
+/// A path in RFC 6901 JSON Pointer syntax; see [`pointer()`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct JsonPointer(String);
+
 #[doc(hidden)]
 pub mod internal {
-    use crate::matcher_support::path::{PathSegment, format_path, parse_expected_paths};
+    use super::JsonPointer;
+    use crate::matcher_support::path::{
+        ParsedPath, PathSegment, PathSpec, parse_path_spec, resolve_pattern,
+    };
     use crate::matchers::__internal_unstable_do_not_depend_on_these::describe_json_type;
-    use crate::matchers::json_matcher::internal::JsonMatcher;
+    use crate::matchers::json_matcher::internal::{JsonMatcher, SharedMatcher};
+    use crate::report::{JsonMismatch, report_mismatch};
     use googletest::description::Description;
     use googletest::matcher::{Matcher, MatcherBase, MatcherResult};
     use serde_json::Value;
 
-    #[derive(MatcherBase)]
-    pub struct JsonPathWithMatcher {
-        raw: String,
-        segments: Vec<PathSegment>,
-        matcher: Box<dyn JsonMatcher>,
-        parse_error: Option<String>,
+    /// Types accepted as paths by the path matchers.
+    ///
+    /// Strings use dot notation, or JSON Pointer syntax when they start with `/`.
+    pub trait JsonPathArg {
+        fn path_spec(&self) -> PathSpec<'_>;
+    }
+
+    impl<S: AsRef<str>> JsonPathArg for S {
+        fn path_spec(&self) -> PathSpec<'_> {
+            PathSpec::Auto(self.as_ref())
+        }
     }
 
-    fn parse_single_path(path: &str) -> (Vec<PathSegment>, Option<String>) {
-        let parsed_paths = parse_expected_paths(&[path]);
-        match (parsed_paths.parsed.first(), parsed_paths.errors.first()) {
-            (_, Some(err)) => (Vec::new(), Some(err.clone())),
-            (Some(p), None) => (p.segments.clone(), None),
-            _ => (Vec::new(), Some("empty path".to_string())),
+    impl JsonPathArg for JsonPointer {
+        fn path_spec(&self) -> PathSpec<'_> {
+            PathSpec::Pointer(&self.0)
         }
     }
 
-    impl JsonPathWithMatcher {
-        pub fn new(path: &str, matcher: Box<dyn JsonMatcher>) -> Self {
-            let (segments, parse_error) = parse_single_path(path);
+    /// How many of the paths selected by a wildcard pattern must satisfy the matcher.
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub enum PathQuantifier {
+        /// Every selected path must match (the default).
+        All,
+        /// At least one selected path must match.
+        Any,
+    }
+
+    #[derive(Clone, MatcherBase)]
+    pub struct JsonPathWithMatcher<C = SharedMatcher> {
+        raw: String,
+        pattern: Option<ParsedPath>,
+        matcher: C,
+        parse_error: Option<String>,
+        quantifier: PathQuantifier,
+    }
+
+    impl<C: JsonMatcher> JsonPathWithMatcher<C> {
+        pub fn new(path: impl JsonPathArg, matcher: C) -> Self {
+            let spec = path.path_spec();
+            let (pattern, parse_error) = match parse_path_spec(spec) {
+                Ok(pattern) => (Some(pattern), None),
+                Err(err) => (None, Some(err)),
+            };
+            let raw = match spec {
+                PathSpec::Auto(raw) | PathSpec::Pointer(raw) => raw.to_string(),
+            };
             Self {
-                raw: path.to_string(),
-                segments,
+                raw,
+                pattern,
                 matcher,
                 parse_error,
+                quantifier: PathQuantifier::All,
             }
         }
 
-        fn find_leaf<'a>(&self, value: &'a Value) -> Option<&'a Value> {
+        /// Requires every path selected by a wildcard pattern to match (the default).
+        pub fn for_all(mut self) -> Self {
+            self.quantifier = PathQuantifier::All;
+            self
+        }
+
+        /// Requires at least one path selected by a wildcard pattern to match.
+        pub fn for_any(mut self) -> Self {
+            self.quantifier = PathQuantifier::Any;
+            self
+        }
+
+        /// Follows a concrete path, reading numeric segments as keys inside objects.
+        ///
+        /// Returns the leaf together with the path actually taken to it.
+        fn find_leaf<'a>(
+            segments: &[PathSegment],
+            value: &'a Value,
+        ) -> Option<(Vec<PathSegment>, &'a Value)> {
             let mut current = value;
-            for seg in &self.segments {
+            let mut resolved = Vec::with_capacity(segments.len());
+            for seg in segments {
                 match (seg, current) {
                     (PathSegment::Field(name), Value::Object(map)) => {
                         current = map.get(name)?;
+                        resolved.push(seg.clone());
+                    }
+                    (PathSegment::Index(idx), Value::Object(map)) => {
+                        let key = idx.to_string();
+                        current = map.get(&key)?;
+                        resolved.push(PathSegment::Field(key));
                     }
                     (PathSegment::Index(idx), Value::Array(arr)) => {
                         current = arr.get(*idx)?;
+                        resolved.push(seg.clone());
                     }
                     _ => return None,
                 }
             }
-            Some(current)
+            Some((resolved, current))
+        }
+
+        /// Resolves the pattern into the concrete nodes it selects.
+        fn resolve<'a>(&self, value: &'a Value) -> Vec<(Vec<PathSegment>, &'a Value)> {
+            let Some(pattern) = &self.pattern else {
+                return Vec::new();
+            };
+            match pattern.concrete() {
+                Some(segments) => Self::find_leaf(&segments, value).into_iter().collect(),
+                None => resolve_pattern(value, pattern),
+            }
+        }
+
+        fn display(&self, path: &[PathSegment]) -> String {
+            self.pattern
+                .as_ref()
+                .map_or_else(String::new, |p| p.display(path))
+        }
+
+        fn is_wildcard(&self) -> bool {
+            self.pattern
+                .as_ref()
+                .is_some_and(|p| p.concrete().is_none())
         }
     }
 
-    impl Matcher<&Value> for JsonPathWithMatcher {
+    impl<C: JsonMatcher> JsonMatcher for JsonPathWithMatcher<C> {
+        fn collect_mismatches(
+            &self,
+            actual: &Value,
+            path: &mut Vec<PathSegment>,
+            out: &mut Vec<JsonMismatch>,
+        ) {
+            if self.matches(actual).is_match() {
+                return;
+            }
+            let nodes = self.resolve(actual);
+            let concrete = self.pattern.as_ref().and_then(ParsedPath::concrete);
+            match (concrete, nodes.is_empty()) {
+                (Some(segments), true) if actual.is_object() => {
+                    let base = path.len();
+                    path.extend(segments);
+                    out.push(JsonMismatch::new(
+                        path.clone(),
+                        self.matcher.describe(MatcherResult::Match),
+                        None,
+                        "was missing",
+                    ));
+                    path.truncate(base);
+                }
+                // A quantifier of `Any` has no single culprit, so it is reported as a whole.
+                (_, false) if self.quantifier == PathQuantifier::All => {
+                    for (segments, leaf) in nodes {
+                        let base = path.len();
+                        path.extend(segments);
+                        self.matcher.collect_mismatches(leaf, path, out);
+                        path.truncate(base);
+                    }
+                }
+                _ => report_mismatch(self, actual, path, out),
+            }
+        }
+    }
+
+    impl<C: JsonMatcher> Matcher<&Value> for JsonPathWithMatcher<C> {
         fn matches(&self, value: &Value) -> MatcherResult {
             if self.parse_error.is_some() {
                 return MatcherResult::NoMatch;
             }
-            let Some(leaf) = self.find_leaf(value) else {
+            let nodes = self.resolve(value);
+            if nodes.is_empty() {
                 return MatcherResult::NoMatch;
-            };
-            self.matcher.matches(leaf)
+            }
+            let mut results = nodes.iter().map(|(_, leaf)| self.matcher.matches(leaf));
+            match self.quantifier {
+                PathQuantifier::All => results.all(|r| r.is_match()).into(),
+                PathQuantifier::Any => results.any(|r| r.is_match()).into(),
+            }
         }
 
         fn describe(&self, result: MatcherResult) -> Description {
             let path = &self.raw;
-            match result {
-                MatcherResult::Match => format!("has path `{path}` whose value matches").into(),
-                MatcherResult::NoMatch => {
-                    format!("has path `{path}` whose value does not match").into()
+            if !self.is_wildcard() {
+                return match result {
+                    MatcherResult::Match => format!("has path `{path}` whose value matches").into(),
+                    MatcherResult::NoMatch => {
+                        format!("has path `{path}` whose value does not match").into()
+                    }
+                };
+            }
+            match (self.quantifier, result) {
+                (PathQuantifier::All, MatcherResult::Match) => {
+                    format!("has paths matching `{path}` whose values all match").into()
+                }
+                (PathQuantifier::All, MatcherResult::NoMatch) => {
+                    format!("has paths matching `{path}` whose values do not all match").into()
+                }
+                (PathQuantifier::Any, MatcherResult::Match) => {
+                    format!("has a path matching `{path}` whose value matches").into()
+                }
+                (PathQuantifier::Any, MatcherResult::NoMatch) => {
+                    format!("has no path matching `{path}` whose value matches").into()
                 }
             }
         }
@@ -278,14 +522,63 @@ pub mod internal {
             if let Some(err) = &self.parse_error {
                 return Description::new().text(format!("invalid path {err}"));
             }
-            let Some(leaf) = self.find_leaf(value) else {
-                return match value {
-                    Value::Object(_) => Description::new()
-                        .text(format!("missing path `{}`", format_path(&self.segments))),
+            let nodes = self.resolve(value);
+            if nodes.is_empty() {
+                return match (value, self.is_wildcard()) {
+                    (Value::Object(_), false) => Description::new().text(format!(
+                        "missing path `{}`",
+                        self.pattern
+                            .as_ref()
+                            .and_then(|p| p.concrete().map(|c| p.display(&c)))
+                            .unwrap_or_default()
+                    )),
+                    (Value::Object(_) | Value::Array(_), true) => {
+                        Description::new().text(format!("no paths match `{}`", self.raw))
+                    }
                     _ => describe_json_type(value),
                 };
-            };
-            self.matcher.explain_match(leaf)
+            }
+            if !self.is_wildcard() {
+                return self.matcher.explain_match(nodes[0].1);
+            }
+            let failures = nodes
+                .iter()
+                .filter(|(_, leaf)| self.matcher.matches(leaf).is_no_match())
+                .map(|(path, leaf)| {
+                    format!(
+                        "  `{}`: {}",
+                        self.display(path),
+                        self.matcher.explain_match(leaf)
+                    )
+                })
+                .collect::<Vec<_>>();
+            match self.quantifier {
+                PathQuantifier::All if failures.is_empty() => {
+                    format!("all {} paths matching `{}` matched", nodes.len(), self.raw).into()
+                }
+                PathQuantifier::Any if failures.len() < nodes.len() => format!(
+                    "{} of {} paths matching `{}` matched",
+                    nodes.len() - failures.len(),
+                    nodes.len(),
+                    self.raw
+                )
+                .into(),
+                PathQuantifier::All => format!(
+                    "{} of {} paths matching `{}` did not match:\n{}",
+                    failures.len(),
+                    nodes.len(),
+                    self.raw,
+                    failures.join("\n")
+                )
+                .into(),
+                PathQuantifier::Any => format!(
+                    "none of the {} paths matching `{}` matched:\n{}",
+                    nodes.len(),
+                    self.raw,
+                    failures.join("\n")
+                )
+                .into(),
+            }
         }
     }
 }
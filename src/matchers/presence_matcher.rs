@@ -0,0 +1,232 @@
+//! Matchers that tell absent, null and present object fields apart.
+//!
+//! [`j::optional!`](crate::json::optional) accepts a field that is absent, null or matching.
+//! These matchers pick exactly the states an API allows, e.g. for PATCH payloads.
+//!
+//! | Matcher | absent | null | other value |
+//! | --- | --- | --- | --- |
+//! | `j::absent()` | yes | no | no |
+//! | `j::present_null()` | no | yes | no |
+//! | `j::nullable!(m)` | no | yes | if `m` matches |
+//! | `j::missing_or!(m)` | yes | no | if `m` matches |
+//! | `j::optional!(m)` | yes | yes | if `m` matches |
+
+use crate::matchers::__internal_unstable_do_not_depend_on_these;
+use crate::matchers::__internal_unstable_do_not_depend_on_these::{
+    JsonAbsentMatcher, JsonPredicateMatcher,
+};
+use serde_json::Value;
+
+/// Matches an object field that must not exist.
+///
+/// Only meaningful as a field of [`j::pat!`](crate::json::pat): any value, including null,
+/// fails it.
+///
+/// # Examples
+///
+/// ```rust
+/// # use googletest::prelude::*;
+/// # use googletest_json_serde::json as j;
+/// # use serde_json::json;
+/// assert_that!(json!({"id": 1}), j::pat!({"id": 1, "deleted_at": j::absent()}));
+/// assert_that!(
+///     json!({"id": 1, "deleted_at": null}),
+///     not(j::pat!({"id": 1, "deleted_at": j::absent()}))
+/// );
+/// ```
+pub fn absent() -> JsonAbsentMatcher {
+    JsonAbsentMatcher
+}
+
+/// Matches an object field that exists and is JSON null.
+///
+/// Unlike [`j::is_null`](crate::json::is_null) inside [`j::optional!`](crate::json::optional),
+/// a missing field fails it.
+///
+/// # Examples
+///
+/// ```rust
+/// # use googletest::prelude::*;
+/// # use googletest_json_serde::json as j;
+/// # use serde_json::json;
+/// assert_that!(json!({"nickname": null}), j::pat!({"nickname": j::present_null()}));
+/// assert_that!(json!({}), not(j::pat!({"nickname": j::present_null()})));
+/// ```
+pub fn present_null()
+-> JsonPredicateMatcher<impl Fn(&Value) -> bool + Clone + Send + Sync, &'static str, &'static str> {
+    JsonPredicateMatcher::new(
+        |v| v.is_null(),
+        "a present JSON null",
+        "which is not JSON null",
+    )
+    .with_explain_fn(__internal_unstable_do_not_depend_on_these::describe_json_type)
+}
+
+/// Matches an object field that exists and is either null or satisfies the inner matcher.
+///
+/// # Examples
+///
+/// ```rust
+/// # use googletest::prelude::*;
+/// # use googletest_json_serde::json as j;
+/// # use serde_json::json;
+/// let pattern = j::pat!({"nickname": j::nullable!(starts_with("B"))});
+/// assert_that!(json!({"nickname": null}), pattern);
+/// assert_that!(json!({"nickname": "Bob"}), j::pat!({"nickname": j::nullable!(starts_with("B"))}));
+/// assert_that!(json!({}), not(j::pat!({"nickname": j::nullable!(starts_with("B"))})));
+/// ```
+///
+/// # Supported Inputs
+/// - Literal JSON-compatible values
+/// - Direct `serde_json::Value`
+/// - Native googletest matchers
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __json_nullable {
+    ($inner:expr) => {{
+        $crate::matchers::__internal_unstable_do_not_depend_on_these::JsonPresenceMatcher::nullable(
+            $crate::__json_into_matcher!($inner),
+        )
+    }};
+}
+
+/// Matches an object field that is either absent or satisfies the inner matcher; null fails.
+///
+/// # Examples
+///
+/// ```rust
+/// # use googletest::prelude::*;
+/// # use googletest_json_serde::json as j;
+/// # use serde_json::json;
+/// assert_that!(json!({}), j::pat!({"email": j::missing_or!(j::is_string())}));
+/// assert_that!(json!({"email": "a@b.c"}), j::pat!({"email": j::missing_or!(j::is_string())}));
+/// assert_that!(
+///     json!({"email": null}),
+///     not(j::pat!({"email": j::missing_or!(j::is_string())}))
+/// );
+/// ```
+///
+/// # Supported Inputs
+/// - Literal JSON-compatible values
+/// - Direct `serde_json::Value`
+/// - Native googletest matchers
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __json_missing_or {
+    ($inner:expr) => {{
+        $crate::matchers::__internal_unstable_do_not_depend_on_these::JsonPresenceMatcher::missing_or(
+            $crate::__json_into_matcher!($inner),
+        )
+    }};
+}
+
+#[doc(hidden)]
+pub mod internal {
+    use crate::matcher_support::path::PathSegment;
+    use crate::matchers::json_matcher::internal::{JsonMatcher, SharedMatcher};
+    use crate::report::{JsonMismatch, report_mismatch};
+    use googletest::description::Description;
+    use googletest::matcher::{Matcher, MatcherBase, MatcherResult};
+    use serde_json::Value;
+
+    /// Matches only when the field is missing; see `j::absent()`.
+    #[derive(Clone, Copy, MatcherBase)]
+    pub struct JsonAbsentMatcher;
+
+    impl Matcher<&Value> for JsonAbsentMatcher {
+        fn matches(&self, _actual: &Value) -> MatcherResult {
+            MatcherResult::NoMatch
+        }
+
+        fn describe(&self, result: MatcherResult) -> Description {
+            match result {
+                MatcherResult::Match => "is absent".into(),
+                MatcherResult::NoMatch => "is present".into(),
+            }
+        }
+
+        fn explain_match(&self, _actual: &Value) -> Description {
+            "which is present".into()
+        }
+    }
+
+    impl JsonMatcher for JsonAbsentMatcher {
+        fn allows_missing(&self) -> bool {
+            true
+        }
+    }
+
+    /// Accepts null or a missing field, but not both, in addition to the inner matcher; see
+    /// `j::nullable!` and `j::missing_or!`.
+    #[derive(Clone, MatcherBase)]
+    pub struct JsonPresenceMatcher<C = SharedMatcher> {
+        inner: C,
+        allow_missing: bool,
+        allow_null: bool,
+    }
+
+    impl<C: JsonMatcher> JsonPresenceMatcher<C> {
+        pub fn nullable(inner: C) -> Self {
+            Self {
+                inner,
+                allow_missing: false,
+                allow_null: true,
+            }
+        }
+
+        pub fn missing_or(inner: C) -> Self {
+            Self {
+                inner,
+                allow_missing: true,
+                allow_null: false,
+            }
+        }
+    }
+
+    impl<C: JsonMatcher> Matcher<&Value> for JsonPresenceMatcher<C> {
+        fn matches(&self, actual: &Value) -> MatcherResult {
+            if actual.is_null() {
+                self.allow_null.into()
+            } else {
+                self.inner.matches(actual)
+            }
+        }
+
+        fn describe(&self, result: MatcherResult) -> Description {
+            let inner = self.inner.describe(result);
+            match (result, self.allow_null) {
+                (MatcherResult::Match, true) => format!("is null or {inner}").into(),
+                (MatcherResult::NoMatch, true) => format!("is not null and {inner}").into(),
+                (MatcherResult::Match, false) => format!("is absent or {inner}").into(),
+                (MatcherResult::NoMatch, false) => format!("is present and {inner}").into(),
+            }
+        }
+
+        fn explain_match(&self, actual: &Value) -> Description {
+            if actual.is_null() {
+                Description::new().text("which is null")
+            } else {
+                self.inner.explain_match(actual)
+            }
+        }
+    }
+
+    impl<C: JsonMatcher> JsonMatcher for JsonPresenceMatcher<C> {
+        fn allows_missing(&self) -> bool {
+            self.allow_missing
+        }
+
+        fn collect_mismatches(
+            &self,
+            actual: &Value,
+            path: &mut Vec<PathSegment>,
+            out: &mut Vec<JsonMismatch>,
+        ) {
+            if actual.is_null() {
+                report_mismatch(self, actual, path, out);
+            } else {
+                self.inner.collect_mismatches(actual, path, out);
+            }
+        }
+    }
+}
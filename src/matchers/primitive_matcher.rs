@@ -65,16 +65,17 @@ macro_rules! __json_primitive {
 
 #[doc(hidden)]
 pub mod internal {
+    use crate::matcher_support::number;
     use crate::matchers::json_matcher::internal::{IntoJsonMatcher, JsonMatcher};
     use googletest::description::Description;
     use googletest::matcher::{Matcher, MatcherBase, MatcherResult};
     use serde_json::Value;
 
     #[doc(hidden)]
-    #[derive(MatcherBase)]
+    #[derive(Clone, MatcherBase)]
     pub struct JsonPrimitiveMatcher<M, T> {
         inner: M,
-        phantom: std::marker::PhantomData<T>,
+        phantom: std::marker::PhantomData<fn() -> T>,
     }
 
     impl<M, T> JsonPrimitiveMatcher<M, T> {
@@ -113,9 +114,9 @@ pub mod internal {
     {
         fn matches(&self, actual: &Value) -> MatcherResult {
             match actual {
-                Value::Number(n) => n
-                    .as_i64()
-                    .map_or(MatcherResult::NoMatch, |i| self.inner.matches(i)),
+                Value::Number(n) => {
+                    number::as_i64(n).map_or(MatcherResult::NoMatch, |i| self.inner.matches(i))
+                }
                 _ => MatcherResult::NoMatch,
             }
         }
@@ -124,7 +125,7 @@ pub mod internal {
         }
         fn explain_match(&self, actual: &Value) -> Description {
             match actual {
-                Value::Number(n) => match n.as_i64() {
+                Value::Number(n) => match number::as_i64(n) {
                     Some(i) => self.inner.explain_match(i),
                     None => Description::new().text(format!("number out of i64 range: {n}")),
                 },
@@ -190,8 +191,10 @@ pub mod internal {
     where
         M: Matcher<i64> + 'static,
     {
-        fn into_json_matcher(self) -> Box<dyn JsonMatcher> {
-            Box::new(JsonPrimitiveMatcher::<M, i64>::new(self))
+        type Matcher = JsonPrimitiveMatcher<M, i64>;
+
+        fn into_json_matcher(self) -> Self::Matcher {
+            JsonPrimitiveMatcher::<M, i64>::new(self)
         }
     }
 
@@ -201,9 +204,9 @@ pub mod internal {
     {
         fn matches(&self, actual: &Value) -> MatcherResult {
             match actual {
-                Value::Number(n) => n
-                    .as_u64()
-                    .map_or(MatcherResult::NoMatch, |u| self.inner.matches(u)),
+                Value::Number(n) => {
+                    number::as_u64(n).map_or(MatcherResult::NoMatch, |u| self.inner.matches(u))
+                }
                 _ => MatcherResult::NoMatch,
             }
         }
@@ -212,7 +215,7 @@ pub mod internal {
         }
         fn explain_match(&self, actual: &Value) -> Description {
             match actual {
-                Value::Number(n) => match n.as_u64() {
+                Value::Number(n) => match number::as_u64(n) {
                     Some(u) => self.inner.explain_match(u),
                     None => Description::new().text(format!("number out of u64 range: {n}")),
                 },
@@ -225,8 +228,10 @@ pub mod internal {
     where
         M: Matcher<u64> + 'static,
     {
-        fn into_json_matcher(self) -> Box<dyn JsonMatcher> {
-            Box::new(JsonPrimitiveMatcher::<M, u64>::new(self))
+        type Matcher = JsonPrimitiveMatcher<M, u64>;
+
+        fn into_json_matcher(self) -> Self::Matcher {
+            JsonPrimitiveMatcher::<M, u64>::new(self)
         }
     }
 
@@ -234,8 +239,10 @@ pub mod internal {
     where
         M: Matcher<f64> + 'static,
     {
-        fn into_json_matcher(self) -> Box<dyn JsonMatcher> {
-            Box::new(JsonPrimitiveMatcher::<M, f64>::new(self))
+        type Matcher = JsonPrimitiveMatcher<M, f64>;
+
+        fn into_json_matcher(self) -> Self::Matcher {
+            JsonPrimitiveMatcher::<M, f64>::new(self)
         }
     }
 
@@ -243,8 +250,10 @@ pub mod internal {
     where
         M: for<'a> Matcher<&'a str> + 'static,
     {
-        fn into_json_matcher(self) -> Box<dyn JsonMatcher> {
-            Box::new(JsonPrimitiveMatcher::<M, String>::new(self))
+        type Matcher = JsonPrimitiveMatcher<M, String>;
+
+        fn into_json_matcher(self) -> Self::Matcher {
+            JsonPrimitiveMatcher::<M, String>::new(self)
         }
     }
 
@@ -252,8 +261,10 @@ pub mod internal {
     where
         M: Matcher<bool> + 'static,
     {
-        fn into_json_matcher(self) -> Box<dyn JsonMatcher> {
-            Box::new(JsonPrimitiveMatcher::<M, bool>::new(self))
+        type Matcher = JsonPrimitiveMatcher<M, bool>;
+
+        fn into_json_matcher(self) -> Self::Matcher {
+            JsonPrimitiveMatcher::<M, bool>::new(self)
         }
     }
 
@@ -263,7 +274,7 @@ pub mod internal {
     {
         fn matches(&self, actual: &Value) -> MatcherResult {
             match actual {
-                Value::Number(n) => match n.as_i64() {
+                Value::Number(n) => match number::as_i64(n) {
                     Some(i) => match i32::try_from(i) {
                         Ok(i32_val) => self.inner.matches(i32_val),
                         Err(_) => MatcherResult::NoMatch,
@@ -278,7 +289,7 @@ pub mod internal {
         }
         fn explain_match(&self, actual: &Value) -> Description {
             match actual {
-                Value::Number(n) => match n.as_i64() {
+                Value::Number(n) => match number::as_i64(n) {
                     Some(i) => match i32::try_from(i) {
                         Ok(i32_val) => self.inner.explain_match(i32_val),
                         Err(_) => Description::new().text(format!("number out of i32 range: {n}")),
@@ -294,8 +305,10 @@ pub mod internal {
     where
         M: Matcher<i32> + 'static,
     {
-        fn into_json_matcher(self) -> Box<dyn JsonMatcher> {
-            Box::new(JsonPrimitiveMatcher::<M, i32>::new(self))
+        type Matcher = JsonPrimitiveMatcher<M, i32>;
+
+        fn into_json_matcher(self) -> Self::Matcher {
+            JsonPrimitiveMatcher::<M, i32>::new(self)
         }
     }
 
@@ -305,7 +318,7 @@ pub mod internal {
     {
         fn matches(&self, actual: &Value) -> MatcherResult {
             match actual {
-                Value::Number(n) => match n.as_i64() {
+                Value::Number(n) => match number::as_i64(n) {
                     Some(i) => match i8::try_from(i) {
                         Ok(i8_val) => self.inner.matches(i8_val),
                         Err(_) => MatcherResult::NoMatch,
@@ -320,7 +333,7 @@ pub mod internal {
         }
         fn explain_match(&self, actual: &Value) -> Description {
             match actual {
-                Value::Number(n) => match n.as_i64() {
+                Value::Number(n) => match number::as_i64(n) {
                     Some(i) => match i8::try_from(i) {
                         Ok(i8_val) => self.inner.explain_match(i8_val),
                         Err(_) => Description::new().text(format!("number out of i8 range: {n}")),
@@ -336,8 +349,10 @@ pub mod internal {
     where
         M: Matcher<i8> + 'static,
     {
-        fn into_json_matcher(self) -> Box<dyn JsonMatcher> {
-            Box::new(JsonPrimitiveMatcher::<M, i8>::new(self))
+        type Matcher = JsonPrimitiveMatcher<M, i8>;
+
+        fn into_json_matcher(self) -> Self::Matcher {
+            JsonPrimitiveMatcher::<M, i8>::new(self)
         }
     }
 
@@ -347,7 +362,7 @@ pub mod internal {
     {
         fn matches(&self, actual: &Value) -> MatcherResult {
             match actual {
-                Value::Number(n) => match n.as_i64() {
+                Value::Number(n) => match number::as_i64(n) {
                     Some(i) => match i16::try_from(i) {
                         Ok(i16_val) => self.inner.matches(i16_val),
                         Err(_) => MatcherResult::NoMatch,
@@ -362,7 +377,7 @@ pub mod internal {
         }
         fn explain_match(&self, actual: &Value) -> Description {
             match actual {
-                Value::Number(n) => match n.as_i64() {
+                Value::Number(n) => match number::as_i64(n) {
                     Some(i) => match i16::try_from(i) {
                         Ok(i16_val) => self.inner.explain_match(i16_val),
                         Err(_) => Description::new().text(format!("number out of i16 range: {n}")),
@@ -378,8 +393,10 @@ pub mod internal {
     where
         M: Matcher<i16> + 'static,
     {
-        fn into_json_matcher(self) -> Box<dyn JsonMatcher> {
-            Box::new(JsonPrimitiveMatcher::<M, i16>::new(self))
+        type Matcher = JsonPrimitiveMatcher<M, i16>;
+
+        fn into_json_matcher(self) -> Self::Matcher {
+            JsonPrimitiveMatcher::<M, i16>::new(self)
         }
     }
 
@@ -389,7 +406,7 @@ pub mod internal {
     {
         fn matches(&self, actual: &Value) -> MatcherResult {
             match actual {
-                Value::Number(n) => match n.as_u64() {
+                Value::Number(n) => match number::as_u64(n) {
                     Some(u) => match u8::try_from(u) {
                         Ok(u8_val) => self.inner.matches(u8_val),
                         Err(_) => MatcherResult::NoMatch,
@@ -404,7 +421,7 @@ pub mod internal {
         }
         fn explain_match(&self, actual: &Value) -> Description {
             match actual {
-                Value::Number(n) => match n.as_u64() {
+                Value::Number(n) => match number::as_u64(n) {
                     Some(u) => match u8::try_from(u) {
                         Ok(u8_val) => self.inner.explain_match(u8_val),
                         Err(_) => Description::new().text(format!("number out of u8 range: {n}")),
@@ -420,8 +437,10 @@ pub mod internal {
     where
         M: Matcher<u8> + 'static,
     {
-        fn into_json_matcher(self) -> Box<dyn JsonMatcher> {
-            Box::new(JsonPrimitiveMatcher::<M, u8>::new(self))
+        type Matcher = JsonPrimitiveMatcher<M, u8>;
+
+        fn into_json_matcher(self) -> Self::Matcher {
+            JsonPrimitiveMatcher::<M, u8>::new(self)
         }
     }
 
@@ -431,7 +450,7 @@ pub mod internal {
     {
         fn matches(&self, actual: &Value) -> MatcherResult {
             match actual {
-                Value::Number(n) => match n.as_u64() {
+                Value::Number(n) => match number::as_u64(n) {
                     Some(u) => match u16::try_from(u) {
                         Ok(u16_val) => self.inner.matches(u16_val),
                         Err(_) => MatcherResult::NoMatch,
@@ -446,7 +465,7 @@ pub mod internal {
         }
         fn explain_match(&self, actual: &Value) -> Description {
             match actual {
-                Value::Number(n) => match n.as_u64() {
+                Value::Number(n) => match number::as_u64(n) {
                     Some(u) => match u16::try_from(u) {
                         Ok(u16_val) => self.inner.explain_match(u16_val),
                         Err(_) => Description::new().text(format!("number out of u16 range: {n}")),
@@ -462,8 +481,10 @@ pub mod internal {
     where
         M: Matcher<u16> + 'static,
     {
-        fn into_json_matcher(self) -> Box<dyn JsonMatcher> {
-            Box::new(JsonPrimitiveMatcher::<M, u16>::new(self))
+        type Matcher = JsonPrimitiveMatcher<M, u16>;
+
+        fn into_json_matcher(self) -> Self::Matcher {
+            JsonPrimitiveMatcher::<M, u16>::new(self)
         }
     }
 
@@ -473,7 +494,7 @@ pub mod internal {
     {
         fn matches(&self, actual: &Value) -> MatcherResult {
             match actual {
-                Value::Number(n) => match n.as_u64() {
+                Value::Number(n) => match number::as_u64(n) {
                     Some(u) => match u32::try_from(u) {
                         Ok(u32_val) => self.inner.matches(u32_val),
                         Err(_) => MatcherResult::NoMatch,
@@ -488,7 +509,7 @@ pub mod internal {
         }
         fn explain_match(&self, actual: &Value) -> Description {
             match actual {
-                Value::Number(n) => match n.as_u64() {
+                Value::Number(n) => match number::as_u64(n) {
                     Some(u) => match u32::try_from(u) {
                         Ok(u32_val) => self.inner.explain_match(u32_val),
                         Err(_) => Description::new().text(format!("number out of u32 range: {n}")),
@@ -504,8 +525,10 @@ pub mod internal {
     where
         M: Matcher<u32> + 'static,
     {
-        fn into_json_matcher(self) -> Box<dyn JsonMatcher> {
-            Box::new(JsonPrimitiveMatcher::<M, u32>::new(self))
+        type Matcher = JsonPrimitiveMatcher<M, u32>;
+
+        fn into_json_matcher(self) -> Self::Matcher {
+            JsonPrimitiveMatcher::<M, u32>::new(self)
         }
     }
 
@@ -516,7 +539,7 @@ pub mod internal {
     {
         fn matches(&self, actual: &Value) -> MatcherResult {
             match actual {
-                Value::Number(n) => match n.as_u64() {
+                Value::Number(n) => match number::as_u64(n) {
                     Some(u) => match usize::try_from(u) {
                         Ok(usize_val) => self.inner.matches(usize_val),
                         Err(_) => MatcherResult::NoMatch,
@@ -533,7 +556,7 @@ pub mod internal {
 
         fn explain_match(&self, actual: &Value) -> Description {
             match actual {
-                Value::Number(n) => match n.as_u64() {
+                Value::Number(n) => match number::as_u64(n) {
                     Some(u) => match usize::try_from(u) {
                         Ok(usize_val) => self.inner.explain_match(usize_val),
                         Err(_) => {
@@ -551,8 +574,10 @@ pub mod internal {
     where
         M: Matcher<usize> + 'static,
     {
-        fn into_json_matcher(self) -> Box<dyn JsonMatcher> {
-            Box::new(JsonPrimitiveMatcher::<M, usize>::new(self))
+        type Matcher = JsonPrimitiveMatcher<M, usize>;
+
+        fn into_json_matcher(self) -> Self::Matcher {
+            JsonPrimitiveMatcher::<M, usize>::new(self)
         }
     }
 }
@@ -0,0 +1,274 @@
+use crate::matchers::__internal_unstable_do_not_depend_on_these::{
+    JsonMatcher, JsonRecursiveMatcher, JsonRecursiveRef,
+};
+
+/// Builds a self-referential matcher for tree-shaped JSON.
+///
+/// The closure receives a handle to the matcher being defined and returns its pattern; use the
+/// handle wherever a nested node of the same shape is expected. Nesting is limited to 128
+/// levels by default; call `.max_depth(n)` to change it. Deeper values fail instead of
+/// overflowing the stack.
+///
+/// Explanations and [`j::check`](crate::json::check) reports point at the failing nested path.
+///
+/// # Examples
+///
+/// ```rust
+/// # use googletest::prelude::*;
+/// # use googletest_json_serde::json as j;
+/// # use serde_json::json;
+/// let category = j::recursive(|node| {
+///     j::pat!({"id": j::is_integer(), "children": j::each!(node), ..})
+/// });
+/// let tree = json!({
+///     "id": 1,
+///     "children": [{"id": 2, "children": []}, {"id": 3, "children": [{"id": 4, "children": []}]}]
+/// });
+/// assert_that!(tree, &category);
+/// assert_that!(tree, not(category.clone().max_depth(1)));
+/// ```
+///
+/// ```rust,should_panic
+/// # use googletest::prelude::*;
+/// # use googletest_json_serde::json as j;
+/// # use serde_json::json;
+/// // Fails with:
+/// //   field 'children': element #0 (...) did not match: field 'id': which is a JSON string
+/// assert_that!(
+///     json!({"id": 1, "children": [{"id": "2", "children": []}]}),
+///     j::recursive(|node| j::pat!({"id": j::is_integer(), "children": j::each!(node)}))
+/// );
+/// ```
+pub fn recursive<F, M>(build: F) -> JsonRecursiveMatcher
+where
+    F: FnOnce(JsonRecursiveRef) -> M,
+    M: JsonMatcher + Send + Sync + 'static,
+{
+    JsonRecursiveMatcher::new(build)
+}
+
+#[doc(hidden)]
+pub mod internal {
+    use crate::matcher_support::path::PathSegment;
+    use crate::matchers::json_matcher::internal::JsonMatcher;
+    use crate::report::{JsonMismatch, report_mismatch};
+    use googletest::description::Description;
+    use googletest::matcher::{Matcher, MatcherBase, MatcherResult};
+    use serde_json::Value;
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+    use std::sync::{Arc, OnceLock, Weak};
+
+    struct RecursiveState {
+        pattern: OnceLock<Arc<dyn JsonMatcher + Send + Sync>>,
+    }
+
+    /// How deep an evaluation of one recursive matcher currently is on this thread.
+    #[derive(Clone, Copy)]
+    struct Frame {
+        depth: usize,
+        max_depth: usize,
+    }
+
+    thread_local! {
+        /// The innermost frame per recursive matcher being evaluated on this thread, keyed by
+        /// the address of its shared state.
+        static FRAMES: RefCell<HashMap<usize, Frame>> = RefCell::new(HashMap::new());
+    }
+
+    /// Installs a frame for as long as it is alive and restores the previous one on drop.
+    struct FrameGuard {
+        key: usize,
+        previous: Option<Frame>,
+    }
+
+    impl FrameGuard {
+        fn enter(key: usize, next: impl FnOnce(Option<Frame>) -> Frame) -> (Self, Frame) {
+            FRAMES.with(|frames| {
+                let mut frames = frames.borrow_mut();
+                let frame = next(frames.get(&key).copied());
+                let previous = frames.insert(key, frame);
+                (Self { key, previous }, frame)
+            })
+        }
+    }
+
+    impl Drop for FrameGuard {
+        fn drop(&mut self) {
+            FRAMES.with(|frames| {
+                let mut frames = frames.borrow_mut();
+                match self.previous {
+                    Some(previous) => frames.insert(self.key, previous),
+                    None => frames.remove(&self.key),
+                };
+            });
+        }
+    }
+
+    /// A self-referential matcher built by `j::recursive`.
+    #[doc(hidden)]
+    #[derive(Clone, MatcherBase)]
+    pub struct JsonRecursiveMatcher {
+        state: Arc<RecursiveState>,
+        max_depth: usize,
+    }
+
+    impl JsonRecursiveMatcher {
+        /// The nesting limit used unless `max_depth` is called.
+        pub const DEFAULT_MAX_DEPTH: usize = 128;
+
+        pub fn new<F, M>(build: F) -> Self
+        where
+            F: FnOnce(JsonRecursiveRef) -> M,
+            M: JsonMatcher + Send + Sync + 'static,
+        {
+            let state = Arc::new(RecursiveState {
+                pattern: OnceLock::new(),
+            });
+            let pattern = build(JsonRecursiveRef {
+                state: Arc::downgrade(&state),
+            });
+            let _ = state.pattern.set(Arc::new(pattern));
+            Self {
+                state,
+                max_depth: Self::DEFAULT_MAX_DEPTH,
+            }
+        }
+
+        /// Limits how many levels the pattern may refer to itself; deeper values fail.
+        pub fn max_depth(mut self, max_depth: usize) -> Self {
+            self.max_depth = max_depth;
+            self
+        }
+
+        /// Returns the pattern along with a guard that starts counting depth from the root.
+        fn enter(&self) -> (&Arc<dyn JsonMatcher + Send + Sync>, FrameGuard) {
+            let pattern = self
+                .state
+                .pattern
+                .get()
+                .expect("recursive pattern is set on construction");
+            let (guard, _) = FrameGuard::enter(Arc::as_ptr(&self.state) as usize, |_| Frame {
+                depth: 0,
+                max_depth: self.max_depth,
+            });
+            (pattern, guard)
+        }
+    }
+
+    impl Matcher<&Value> for JsonRecursiveMatcher {
+        fn matches(&self, actual: &Value) -> MatcherResult {
+            let (pattern, _guard) = self.enter();
+            pattern.matches(actual)
+        }
+
+        fn describe(&self, result: MatcherResult) -> Description {
+            let (pattern, _guard) = self.enter();
+            pattern.describe(result)
+        }
+
+        fn explain_match(&self, actual: &Value) -> Description {
+            let (pattern, _guard) = self.enter();
+            pattern.explain_match(actual)
+        }
+    }
+
+    impl JsonMatcher for JsonRecursiveMatcher {
+        fn allows_missing(&self) -> bool {
+            let (pattern, _guard) = self.enter();
+            pattern.allows_missing()
+        }
+
+        fn collect_mismatches(
+            &self,
+            actual: &Value,
+            path: &mut Vec<PathSegment>,
+            out: &mut Vec<JsonMismatch>,
+        ) {
+            let (pattern, _guard) = self.enter();
+            pattern.collect_mismatches(actual, path, out);
+        }
+    }
+
+    /// The handle passed to the `j::recursive` closure; matches like the enclosing pattern.
+    #[doc(hidden)]
+    #[derive(Clone, MatcherBase)]
+    pub struct JsonRecursiveRef {
+        state: Weak<RecursiveState>,
+    }
+
+    /// The outcome of entering one more level of a recursive pattern.
+    enum Level {
+        Pattern(Arc<dyn JsonMatcher + Send + Sync>, FrameGuard),
+        TooDeep(usize),
+        Dropped,
+    }
+
+    impl JsonRecursiveRef {
+        fn enter(&self) -> Level {
+            let Some(state) = self.state.upgrade() else {
+                return Level::Dropped;
+            };
+            let Some(pattern) = state.pattern.get() else {
+                return Level::Dropped;
+            };
+            let (guard, frame) = FrameGuard::enter(Weak::as_ptr(&self.state) as usize, |outer| {
+                let outer = outer.unwrap_or(Frame {
+                    depth: 0,
+                    max_depth: JsonRecursiveMatcher::DEFAULT_MAX_DEPTH,
+                });
+                Frame {
+                    depth: outer.depth + 1,
+                    ..outer
+                }
+            });
+            if frame.depth > frame.max_depth {
+                Level::TooDeep(frame.max_depth)
+            } else {
+                Level::Pattern(pattern.clone(), guard)
+            }
+        }
+    }
+
+    impl Matcher<&Value> for JsonRecursiveRef {
+        fn matches(&self, actual: &Value) -> MatcherResult {
+            match self.enter() {
+                Level::Pattern(pattern, _guard) => pattern.matches(actual),
+                Level::TooDeep(_) | Level::Dropped => MatcherResult::NoMatch,
+            }
+        }
+
+        // Describing the pattern here would recurse forever, so refer back to it instead.
+        fn describe(&self, result: MatcherResult) -> Description {
+            match result {
+                MatcherResult::Match => "matches the recursive pattern".into(),
+                MatcherResult::NoMatch => "doesn't match the recursive pattern".into(),
+            }
+        }
+
+        fn explain_match(&self, actual: &Value) -> Description {
+            match self.enter() {
+                Level::Pattern(pattern, _guard) => pattern.explain_match(actual),
+                Level::TooDeep(max_depth) => {
+                    format!("which is nested more than {max_depth} levels deep, the maximum depth")
+                        .into()
+                }
+                Level::Dropped => "whose recursive pattern no longer exists".into(),
+            }
+        }
+    }
+
+    impl JsonMatcher for JsonRecursiveRef {
+        fn collect_mismatches(
+            &self,
+            actual: &Value,
+            path: &mut Vec<PathSegment>,
+            out: &mut Vec<JsonMismatch>,
+        ) {
+            match self.enter() {
+                Level::Pattern(pattern, _guard) => pattern.collect_mismatches(actual, path, out),
+                Level::TooDeep(_) | Level::Dropped => report_mismatch(self, actual, path, out),
+            }
+        }
+    }
+}
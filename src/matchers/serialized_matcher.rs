@@ -0,0 +1,78 @@
+use crate::matchers::__internal_unstable_do_not_depend_on_these::{
+    JsonMatcher, JsonSerializedMatcher,
+};
+
+/// Matches any `Serialize` value by its JSON representation.
+///
+/// The actual value is serialized with `serde_json::to_value` and the result is passed to the
+/// JSON matcher, so serde renames, `skip_serializing_if` and custom serializers are checked as
+/// clients see them. When serialization fails, the explanation includes serde's error.
+///
+/// # Examples
+///
+/// ```rust
+/// # use googletest::prelude::*;
+/// # use googletest_json_serde::json as j;
+/// # use serde::Serialize;
+/// #[derive(Debug, Serialize)]
+/// #[serde(rename_all = "camelCase")]
+/// struct User {
+///     user_id: u64,
+///     #[serde(skip_serializing_if = "Option::is_none")]
+///     nickname: Option<String>,
+/// }
+///
+/// let user = User { user_id: 7, nickname: None };
+/// assert_that!(user, j::serialized(j::pat!({"userId": 7, "nickname": j::absent()})));
+/// ```
+pub fn serialized<M: JsonMatcher>(inner: M) -> JsonSerializedMatcher<M> {
+    JsonSerializedMatcher::new(inner)
+}
+
+#[doc(hidden)]
+pub mod internal {
+    use crate::matchers::json_matcher::internal::JsonMatcher;
+    use googletest::description::Description;
+    use googletest::matcher::{Matcher, MatcherBase, MatcherResult};
+    use serde::Serialize;
+    use std::fmt::Debug;
+
+    /// Serializes the actual value before applying a JSON matcher; see `j::serialized`.
+    #[doc(hidden)]
+    #[derive(Clone, MatcherBase)]
+    pub struct JsonSerializedMatcher<M> {
+        inner: M,
+    }
+
+    impl<M> JsonSerializedMatcher<M> {
+        pub fn new(inner: M) -> Self {
+            Self { inner }
+        }
+    }
+
+    impl<T, M> Matcher<&T> for JsonSerializedMatcher<M>
+    where
+        T: Serialize + Debug + ?Sized,
+        M: JsonMatcher,
+    {
+        fn matches(&self, actual: &T) -> MatcherResult {
+            serde_json::to_value(actual)
+                .map_or(MatcherResult::NoMatch, |value| self.inner.matches(&value))
+        }
+
+        fn describe(&self, result: MatcherResult) -> Description {
+            format!("serializes to JSON which {}", self.inner.describe(result)).into()
+        }
+
+        fn explain_match(&self, actual: &T) -> Description {
+            match serde_json::to_value(actual) {
+                Ok(value) => format!(
+                    "which serializes to {value}, {}",
+                    self.inner.explain_match(&value)
+                )
+                .into(),
+                Err(err) => format!("which fails to serialize to JSON: {err}").into(),
+            }
+        }
+    }
+}
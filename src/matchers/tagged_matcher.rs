@@ -0,0 +1,286 @@
+/// Matches a tagged union: a JSON object whose discriminator field selects the matcher for the
+/// rest of its shape.
+///
+/// The first argument names the discriminator field, which must hold a string. Each
+/// `"tag" => matcher` branch is applied to the whole object when the discriminator equals
+/// `"tag"`, so object patterns for the branches see the discriminator too; list it or allow
+/// extra fields with `..`. An object whose tag has no branch fails and the explanation names
+/// the tags that are handled. A tag listed twice makes the matcher fail with an explanation
+/// naming the duplicate.
+///
+/// Call `.known_variants(&[...])` to also require that every variant the API can send has a
+/// branch, so adding a variant without a test fails loudly; tags outside the known list are
+/// then reported as unknown variants.
+///
+/// # Examples
+///
+/// ```rust
+/// # use googletest::prelude::*;
+/// # use googletest_json_serde::json as j;
+/// # use serde_json::json;
+/// let event = j::tagged!("type", {
+///     "created" => j::pat!({"type": "created", "id": j::is_integer(), "name": j::is_string()}),
+///     "deleted" => j::pat!({"id": j::is_integer(), ..}),
+/// });
+/// assert_that!(json!({"type": "created", "id": 1, "name": "Ada"}), &event);
+/// assert_that!(json!({"type": "deleted", "id": 1}), &event);
+/// assert_that!(json!({"type": "renamed", "id": 1}), not(&event));
+/// ```
+///
+/// ```rust,should_panic
+/// # use googletest::prelude::*;
+/// # use googletest_json_serde::json as j;
+/// # use serde_json::json;
+/// // Fails with:
+/// //   which has no branch for known variant 'archived'
+/// assert_that!(
+///     json!({"type": "deleted", "id": 1}),
+///     j::tagged!("type", {
+///         "created" => j::pat!({"id": j::is_integer(), ..}),
+///         "deleted" => j::pat!({"id": j::is_integer(), ..}),
+///     })
+///     .known_variants(&["created", "deleted", "archived"])
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Fails when the value is not a JSON object, when the discriminator is missing or not a
+/// string, when the tag has no branch, or when the selected branch does not match.
+///
+/// # Supported Inputs
+/// - Literal JSON-compatible values
+/// - Direct `serde_json::Value`
+/// - Native googletest matchers
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __json_tagged {
+    ($tag_field:expr, { $($tag:expr => $matcher:expr),* $(,)? } $(,)?) => {{
+        $crate::matchers::__internal_unstable_do_not_depend_on_these::JsonTaggedMatcher::new(
+            $tag_field,
+            vec![$(::std::string::String::from($tag)),*],
+            $crate::matchers::__internal_unstable_do_not_depend_on_these::Children::new()
+                $(.push($crate::__json_into_matcher!($matcher)))*
+                .into_vec(),
+        )
+    }};
+}
+
+#[doc(hidden)]
+pub mod internal {
+    use crate::matcher_support::path::PathSegment;
+    use crate::matchers::json_matcher::internal::{JsonMatcher, SharedMatcher};
+    use crate::report::{JsonMismatch, report_mismatch};
+    use googletest::description::Description;
+    use googletest::matcher::{Matcher, MatcherBase, MatcherResult};
+    use serde_json::Value;
+
+    /// What the discriminator of an actual value selects.
+    enum Selected<'a, C> {
+        Branch(&'a C),
+        NotObject,
+        MissingTag,
+        TagNotString(&'a Value),
+        Unhandled(&'a str),
+    }
+
+    /// Dispatches on a discriminator field; see `j::tagged!`.
+    #[doc(hidden)]
+    #[derive(Clone, MatcherBase)]
+    pub struct JsonTaggedMatcher<C = SharedMatcher> {
+        tag_field: String,
+        branches: Vec<(String, C)>,
+        known_variants: Option<Vec<String>>,
+        duplicate_tags: Vec<String>,
+    }
+
+    impl<C: JsonMatcher> JsonTaggedMatcher<C> {
+        pub fn new(tag_field: impl Into<String>, tags: Vec<String>, branches: Vec<C>) -> Self {
+            let branches: Vec<(String, C)> = tags.into_iter().zip(branches).collect();
+            let mut duplicate_tags: Vec<String> = Vec::new();
+            for (index, (tag, _)) in branches.iter().enumerate() {
+                if branches[..index].iter().any(|(earlier, _)| earlier == tag)
+                    && !duplicate_tags.contains(tag)
+                {
+                    duplicate_tags.push(tag.clone());
+                }
+            }
+            Self {
+                tag_field: tag_field.into(),
+                branches,
+                known_variants: None,
+                duplicate_tags,
+            }
+        }
+
+        /// Requires a branch for every listed variant; other tags are reported as unknown.
+        pub fn known_variants(mut self, variants: &[&str]) -> Self {
+            self.known_variants = Some(variants.iter().map(|v| v.to_string()).collect());
+            self
+        }
+
+        /// Known variants without a branch, in the order they were listed.
+        fn unhandled_variants(&self) -> Vec<&str> {
+            self.known_variants
+                .iter()
+                .flatten()
+                .filter(|variant| !self.branches.iter().any(|(tag, _)| tag == *variant))
+                .map(String::as_str)
+                .collect()
+        }
+
+        fn select<'a>(&'a self, actual: &'a Value) -> Selected<'a, C> {
+            let Value::Object(obj) = actual else {
+                return Selected::NotObject;
+            };
+            match obj.get(&self.tag_field) {
+                None => Selected::MissingTag,
+                Some(Value::String(tag)) => self
+                    .branches
+                    .iter()
+                    .find(|(branch, _)| branch == tag)
+                    .map_or(Selected::Unhandled(tag), |(_, matcher)| {
+                        Selected::Branch(matcher)
+                    }),
+                Some(other) => Selected::TagNotString(other),
+            }
+        }
+
+        fn quoted(tags: impl IntoIterator<Item = impl AsRef<str>>) -> String {
+            tags.into_iter()
+                .map(|tag| format!("'{}'", tag.as_ref()))
+                .collect::<Vec<_>>()
+                .join(", ")
+        }
+
+        fn explain_unhandled(&self, tag: &str) -> String {
+            let handled = Self::quoted(self.branches.iter().map(|(tag, _)| tag));
+            match &self.known_variants {
+                Some(known) if !known.iter().any(|variant| variant == tag) => format!(
+                    "'{tag}' is an unknown variant, expected one of {}",
+                    Self::quoted(known)
+                ),
+                _ => format!("'{tag}' has no branch, expected one of {handled}"),
+            }
+        }
+    }
+
+    impl<C: JsonMatcher> Matcher<&Value> for JsonTaggedMatcher<C> {
+        fn matches(&self, actual: &Value) -> MatcherResult {
+            if !self.duplicate_tags.is_empty() || !self.unhandled_variants().is_empty() {
+                return MatcherResult::NoMatch;
+            }
+            match self.select(actual) {
+                Selected::Branch(matcher) => matcher.matches(actual),
+                _ => MatcherResult::NoMatch,
+            }
+        }
+
+        fn describe(&self, result: MatcherResult) -> Description {
+            let branches = self
+                .branches
+                .iter()
+                .map(|(tag, matcher)| {
+                    format!("'{tag}': {}", matcher.describe(MatcherResult::Match))
+                })
+                .collect::<Description>()
+                .bullet_list()
+                .indent();
+            let verb = if result.is_match() { "is" } else { "isn't" };
+            format!(
+                "{verb} a JSON object tagged by field '{}', where:\n{branches}",
+                self.tag_field
+            )
+            .into()
+        }
+
+        fn explain_match(&self, actual: &Value) -> Description {
+            if !self.duplicate_tags.is_empty() {
+                return self
+                    .duplicate_tags
+                    .iter()
+                    .map(|tag| format!("duplicate branch for tag '{tag}'"))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+                    .into();
+            }
+            let unhandled = self.unhandled_variants();
+            match unhandled.len() {
+                0 => {}
+                1 => {
+                    return format!("which has no branch for known variant '{}'", unhandled[0])
+                        .into();
+                }
+                _ => {
+                    return format!(
+                        "which has no branches for known variants {}",
+                        Self::quoted(unhandled)
+                    )
+                    .into();
+                }
+            }
+            match self.select(actual) {
+                Selected::Branch(matcher) => matcher.explain_match(actual),
+                Selected::NotObject => format!("was {actual} (expected object)").into(),
+                Selected::MissingTag => format!("field '{}': was missing", self.tag_field).into(),
+                Selected::TagNotString(tag) => {
+                    format!("field '{}': was {tag} (expected string)", self.tag_field).into()
+                }
+                Selected::Unhandled(tag) => format!(
+                    "field '{}': {}",
+                    self.tag_field,
+                    self.explain_unhandled(tag)
+                )
+                .into(),
+            }
+        }
+    }
+
+    impl<C: JsonMatcher> JsonMatcher for JsonTaggedMatcher<C> {
+        fn collect_mismatches(
+            &self,
+            actual: &Value,
+            path: &mut Vec<PathSegment>,
+            out: &mut Vec<JsonMismatch>,
+        ) {
+            if !self.duplicate_tags.is_empty() || !self.unhandled_variants().is_empty() {
+                return report_mismatch(self, actual, path, out);
+            }
+            let expected_tag = || {
+                format!(
+                    "one of {}",
+                    Self::quoted(self.branches.iter().map(|(tag, _)| tag))
+                )
+            };
+            let tag_path = || {
+                [
+                    path.as_slice(),
+                    &[PathSegment::Field(self.tag_field.clone())],
+                ]
+                .concat()
+            };
+            match self.select(actual) {
+                Selected::Branch(matcher) => matcher.collect_mismatches(actual, path, out),
+                Selected::NotObject => report_mismatch(self, actual, path, out),
+                Selected::MissingTag => out.push(JsonMismatch::new(
+                    tag_path(),
+                    expected_tag(),
+                    None,
+                    "was missing",
+                )),
+                Selected::TagNotString(tag) => out.push(JsonMismatch::new(
+                    tag_path(),
+                    expected_tag(),
+                    Some(tag),
+                    "expected string",
+                )),
+                Selected::Unhandled(tag) => out.push(JsonMismatch::new(
+                    tag_path(),
+                    expected_tag(),
+                    Some(&Value::from(tag)),
+                    self.explain_unhandled(tag),
+                )),
+            }
+        }
+    }
+}
@@ -7,7 +7,7 @@
 //! # use serde_json::json;
 //! assert_that!(
 //!     json!(["b", "a", json!("c")]),
-//!     j::unordered_elements_are!["a", json!("c"), starts_with("b")]
+//!     j::unordered_elements_are!["a", json!("c"), matches_regex("b.*")]
 //! );
 //! ```
 
@@ -47,7 +47,7 @@ macro_rules! __json_unordered_elements_are {
     ($(,)?) => {{
         $crate::matchers::__internal_unstable_do_not_depend_on_these::
         JsonUnorderedElementsAreMatcher::new(
-            vec![],
+            $crate::matchers::__internal_unstable_do_not_depend_on_these::Children::new().into_vec(),
             $crate::matchers::__internal_unstable_do_not_depend_on_these::Requirements::PerfectMatch,
         )
     }};
@@ -55,11 +55,9 @@ macro_rules! __json_unordered_elements_are {
     ($($matcher:expr),* $(,)?) => {{
         $crate::matchers::__internal_unstable_do_not_depend_on_these::
         JsonUnorderedElementsAreMatcher::new(
-            vec![
-                $(
-                    $crate::matchers::__internal_unstable_do_not_depend_on_these::IntoJsonMatcher::into_json_matcher($matcher)
-                ),*
-            ],
+            $crate::matchers::__internal_unstable_do_not_depend_on_these::Children::new()
+                $(.push($crate::__json_into_matcher!($matcher)))*
+                .into_vec(),
             $crate::matchers::__internal_unstable_do_not_depend_on_these::Requirements::PerfectMatch,
         )
     }};
@@ -99,11 +97,9 @@ macro_rules! __json_contains_each {
     ([$($matcher:expr),* $(,)?]) => {{
         $crate::matchers::__internal_unstable_do_not_depend_on_these::
         JsonUnorderedElementsAreMatcher::new(
-            vec![
-                $(
-                    $crate::matchers::__internal_unstable_do_not_depend_on_these::IntoJsonMatcher::into_json_matcher($matcher)
-                ),*
-            ],
+            $crate::matchers::__internal_unstable_do_not_depend_on_these::Children::new()
+                $(.push($crate::__json_into_matcher!($matcher)))*
+                .into_vec(),
             $crate::matchers::__internal_unstable_do_not_depend_on_these::Requirements::Superset,
         )
     }};
@@ -147,11 +143,9 @@ macro_rules! __json_contains_each {
 macro_rules! __json_is_contained_in {
     ([$($matcher:expr),* $(,)?]) => {{
         $crate::matchers::__internal_unstable_do_not_depend_on_these::JsonUnorderedElementsAreMatcher::new(
-            vec![
-                $(
-                    $crate::matchers::__internal_unstable_do_not_depend_on_these::IntoJsonMatcher::into_json_matcher($matcher)
-                ),*
-            ],
+            $crate::matchers::__internal_unstable_do_not_depend_on_these::Children::new()
+                $(.push($crate::__json_into_matcher!($matcher)))*
+                .into_vec(),
             $crate::matchers::__internal_unstable_do_not_depend_on_these::Requirements::Subset,
         )
     }};
@@ -163,21 +157,21 @@ macro_rules! __json_is_contained_in {
 #[doc(hidden)]
 pub mod internal {
     use crate::matcher_support::match_matrix::internal::{MatchMatrix, Requirements};
-    use crate::matchers::json_matcher::internal::JsonMatcher;
+    use crate::matchers::json_matcher::internal::{JsonMatcher, SharedMatcher};
     use googletest::description::Description;
     use googletest::matcher::{Matcher, MatcherBase, MatcherResult};
     use serde_json::Value;
 
     #[doc(hidden)]
-    #[derive(MatcherBase)]
-    pub struct JsonUnorderedElementsAreMatcher {
-        elements: Vec<Box<dyn JsonMatcher>>,
+    #[derive(Clone, MatcherBase)]
+    pub struct JsonUnorderedElementsAreMatcher<C = SharedMatcher> {
+        elements: Vec<C>,
         requirements: Requirements,
     }
-    impl JsonMatcher for JsonUnorderedElementsAreMatcher {}
+    impl<C: JsonMatcher> JsonMatcher for JsonUnorderedElementsAreMatcher<C> {}
 
-    impl JsonUnorderedElementsAreMatcher {
-        pub fn new(elements: Vec<Box<dyn JsonMatcher>>, requirements: Requirements) -> Self {
+    impl<C: JsonMatcher> JsonUnorderedElementsAreMatcher<C> {
+        pub fn new(elements: Vec<C>, requirements: Requirements) -> Self {
             Self {
                 elements,
                 requirements,
@@ -185,7 +179,7 @@ pub mod internal {
         }
     }
 
-    impl Matcher<&Value> for JsonUnorderedElementsAreMatcher {
+    impl<C: JsonMatcher> Matcher<&Value> for JsonUnorderedElementsAreMatcher<C> {
         fn matches(&self, actual: &Value) -> MatcherResult {
             let Value::Array(actual_array) = actual else {
                 return MatcherResult::NoMatch;
@@ -0,0 +1,224 @@
+//! Structured, non-panicking match results.
+//!
+//! [`check`] evaluates a JSON matcher against a value and returns a [`JsonMatchReport`] listing
+//! every mismatch with its concrete path, instead of a single rendered `Description`. Reports
+//! can be inspected programmatically or serialized to JSON, e.g. to annotate CI results.
+
+use crate::matcher_support::diff::snippet;
+use crate::matcher_support::path::{format_path, format_pointer};
+use crate::matchers::__internal_unstable_do_not_depend_on_these::JsonMatcher;
+use googletest::matcher::{Matcher, MatcherResult};
+use serde::{Serialize, Serializer};
+use serde_json::{Value, json};
+use std::fmt;
+
+pub use crate::matcher_support::path::PathSegment;
+
+/// Evaluates `matcher` against `actual` without panicking.
+///
+/// # Examples
+///
+/// ```rust
+/// # use googletest::prelude::*;
+/// # use googletest_json_serde::json as j;
+/// # use serde_json::json;
+/// let actual = json!({"user": {"id": 7, "name": "Bob"}});
+/// let report = j::check(&actual, &j::pat!({"user": {"id": 7, "name": "Ada"}}));
+///
+/// assert!(!report.is_match());
+/// let mismatch = &report.mismatches()[0];
+/// assert_eq!(mismatch.path_string(), "user.name");
+/// assert_eq!(mismatch.actual(), Some("\"Bob\""));
+/// assert_eq!(report.to_json()["mismatches"][0]["pointer"], json!("/user/name"));
+/// ```
+pub fn check<M>(actual: &Value, matcher: &M) -> JsonMatchReport
+where
+    M: JsonMatcher + ?Sized,
+{
+    let mut mismatches = Vec::new();
+    if matcher.matches(actual).is_no_match() {
+        matcher.collect_mismatches(actual, &mut Vec::new(), &mut mismatches);
+        if mismatches.is_empty() {
+            report_mismatch(matcher, actual, &[], &mut mismatches);
+        }
+    }
+    JsonMatchReport { mismatches }
+}
+
+/// The outcome of [`check`]: empty when the value matched.
+#[derive(Clone, Debug, PartialEq)]
+pub struct JsonMatchReport {
+    mismatches: Vec<JsonMismatch>,
+}
+
+impl JsonMatchReport {
+    /// Returns true if the value matched, i.e. there are no mismatches.
+    pub fn is_match(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+
+    /// Returns the mismatches in the order the matcher visited them.
+    pub fn mismatches(&self) -> &[JsonMismatch] {
+        &self.mismatches
+    }
+
+    /// Renders the report as `{"matched": bool, "mismatches": [...]}`.
+    pub fn to_json(&self) -> Value {
+        json!({
+            "matched": self.is_match(),
+            "mismatches": self.mismatches.iter().map(JsonMismatch::to_json).collect::<Vec<_>>(),
+        })
+    }
+}
+
+impl fmt::Display for JsonMatchReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.mismatches.len() {
+            0 => write!(f, "matched"),
+            1 => write!(f, "1 mismatch:"),
+            n => write!(f, "{n} mismatches:"),
+        }?;
+        for mismatch in &self.mismatches {
+            write!(f, "\n  {mismatch}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Serialize for JsonMatchReport {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_json().serialize(serializer)
+    }
+}
+
+/// A single failed expectation at a concrete location in the actual document.
+#[derive(Clone, Debug, PartialEq)]
+pub struct JsonMismatch {
+    path: Vec<PathSegment>,
+    expected: String,
+    actual: Option<String>,
+    explanation: String,
+}
+
+impl JsonMismatch {
+    /// Records that the expectation described by `expected` failed at `path`, e.g. from a
+    /// custom [`JsonMatcher::collect_mismatches`]. `actual` is shown as a short JSON snippet,
+    /// or omitted when the value is absent.
+    pub fn new(
+        path: Vec<PathSegment>,
+        expected: impl ToString,
+        actual: Option<&Value>,
+        explanation: impl ToString,
+    ) -> Self {
+        Self {
+            path,
+            expected: expected.to_string(),
+            actual: actual.map(snippet),
+            explanation: explanation.to_string(),
+        }
+    }
+
+    /// The same mismatch recorded at `path` instead.
+    pub(crate) fn relocated(mut self, path: Vec<PathSegment>) -> Self {
+        self.path = path;
+        self
+    }
+
+    /// The location of the mismatch; empty for the document root.
+    pub fn path(&self) -> &[PathSegment] {
+        &self.path
+    }
+
+    /// The location in dot notation, e.g. `orders.0.total`; empty for the document root.
+    pub fn path_string(&self) -> String {
+        format_path(&self.path)
+    }
+
+    /// The location as an RFC 6901 JSON Pointer, e.g. `/orders/0/total`.
+    pub fn pointer(&self) -> String {
+        format_pointer(&self.path)
+    }
+
+    /// What the failing matcher expected.
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+
+    /// A compact snippet of the actual value, or `None` when it was missing.
+    pub fn actual(&self) -> Option<&str> {
+        self.actual.as_deref()
+    }
+
+    /// Why the actual value did not match.
+    pub fn explanation(&self) -> &str {
+        &self.explanation
+    }
+
+    /// Renders the mismatch as a JSON object with `path`, `pointer`, `expected`, `actual`
+    /// and `explanation` keys.
+    pub fn to_json(&self) -> Value {
+        json!({
+            "path": self.path,
+            "pointer": self.pointer(),
+            "expected": self.expected,
+            "actual": self.actual,
+            "explanation": self.explanation,
+        })
+    }
+}
+
+impl fmt::Display for JsonMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let path = if self.path.is_empty() {
+            "(root)".to_string()
+        } else {
+            format!("`{}`", self.path_string())
+        };
+        write!(f, "{path}: expected {}", self.expected)?;
+        match &self.actual {
+            Some(actual) => write!(f, ", actual {actual}")?,
+            None => write!(f, ", actual missing")?,
+        }
+        if !self.explanation.is_empty() {
+            write!(f, " ({})", self.explanation)?;
+        }
+        Ok(())
+    }
+}
+
+impl Serialize for JsonMismatch {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_json().serialize(serializer)
+    }
+}
+
+impl Serialize for PathSegment {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            PathSegment::Field(name) => serializer.serialize_str(name),
+            PathSegment::Index(idx) => serializer.serialize_u64(*idx as u64),
+        }
+    }
+}
+
+/// Records `matcher` as failing at `path` when it does not match `actual`.
+///
+/// This is the default for [`JsonMatcher::collect_mismatches`]; composite matchers call it
+/// when they cannot attribute the failure to a more specific child.
+pub fn report_mismatch<M>(
+    matcher: &M,
+    actual: &Value,
+    path: &[PathSegment],
+    out: &mut Vec<JsonMismatch>,
+) where
+    M: for<'a> Matcher<&'a Value> + ?Sized,
+{
+    if matcher.matches(actual).is_no_match() {
+        out.push(JsonMismatch::new(
+            path.to_vec(),
+            matcher.describe(MatcherResult::Match),
+            Some(actual),
+            matcher.explain_match(actual),
+        ));
+    }
+}
@@ -0,0 +1,104 @@
+use googletest::Result;
+use googletest::prelude::*;
+use googletest_json_serde::json as j;
+use indoc::indoc;
+use serde_json::json;
+
+#[test]
+fn approx_eq_json_matches_within_absolute_tolerance() -> Result<()> {
+    verify_that!(
+        json!({"scores": [0.1 + 0.2, 0.7000004], "model": "v2"}),
+        j::approx_eq_json!(json!({"scores": [0.3, 0.7], "model": "v2"}), abs = 1e-6)
+    )
+}
+
+#[test]
+fn approx_eq_json_matches_within_relative_tolerance() -> Result<()> {
+    let expected = json!({"total": 1_000_000.0, "fee": 0.001});
+    verify_that!(
+        json!({"total": 1_000_000.5, "fee": 0.001}),
+        j::approx_eq_json!(&expected, rel = 1e-6)
+    )?;
+    verify_that!(
+        json!({"total": 1_000_000.5, "fee": 0.001}),
+        not(j::approx_eq_json!(&expected, abs = 1e-6, rel = 1e-9))
+    )
+}
+
+#[test]
+fn approx_eq_json_default_tolerance_absorbs_rounding() -> Result<()> {
+    verify_that!(json!([0.1 + 0.2]), j::approx_eq_json!(json!([0.3])))?;
+    verify_that!(json!([0.31]), not(j::approx_eq_json!(json!([0.3]))))
+}
+
+#[test]
+fn approx_eq_json_ignores_number_representation() -> Result<()> {
+    verify_that!(
+        json!({"count": 2.0, "ids": [1, 2]}),
+        j::approx_eq_json!(json!({"count": 2, "ids": [1.0, 2.0]}))
+    )
+}
+
+#[test]
+fn approx_eq_json_compares_non_numbers_exactly() -> Result<()> {
+    verify_that!(
+        json!({"id": "1", "tags": ["a"]}),
+        not(j::approx_eq_json!(
+            json!({"id": 1, "tags": ["a"]}),
+            abs = 1.0
+        ))
+    )?;
+    verify_that!(
+        json!({"tags": ["a", "b"]}),
+        not(j::approx_eq_json!(json!({"tags": ["a"]}), abs = 1.0))
+    )
+}
+
+#[test]
+fn approx_eq_json_explains_each_out_of_tolerance_path_with_delta() -> Result<()> {
+    let result = verify_that!(
+        json!({"scores": [0.5, 0.75], "bias": 1.25, "label": "cat", "extra": 1}),
+        j::approx_eq_json!(
+            json!({"scores": [0.5, 0.5], "bias": 1.0, "label": "dog"}),
+            abs = 1e-6
+        )
+    );
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(indoc!(
+            r#"
+            which differs from the expected JSON at 4 paths:
+                changed `bias`: expected 1.0, actual 1.25, delta 2.5e-1
+                added `extra`: actual 1
+                changed `label`: expected "dog", actual "cat"
+                changed `scores.1`: expected 0.5, actual 0.75, delta 2.5e-1
+            "#
+        ))))
+    )
+}
+
+#[test]
+fn approx_eq_json_describes_tolerances() -> Result<()> {
+    let result = verify_that!(
+        json!([1.5]),
+        j::approx_eq_json!(json!([1.0]), abs = 1e-6, rel = 1e-9)
+    );
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "Expected: is equal to JSON [1.0] with numbers within abs 1e-6 or rel 1e-9"
+        )))
+    )
+}
+
+#[test]
+fn approx_eq_json_composes_inside_pat_and_raw_input() -> Result<()> {
+    verify_that!(
+        json!({"id": 3, "embedding": [0.1 + 0.2, 0.5]}),
+        j::pat!({"id": 3, "embedding": j::approx_eq_json!(json!([0.3, 0.5]), abs = 1e-9)})
+    )?;
+    verify_that!(
+        r#"{"price": 9.990000001}"#,
+        j::parsed(j::approx_eq_json!(json!({"price": 9.99}), abs = 1e-6))
+    )
+}
@@ -0,0 +1,130 @@
+use googletest::Result;
+use googletest::prelude::*;
+use googletest_json_serde::json as j;
+use serde_json::json;
+
+#[test]
+fn field_array_pattern_matches_in_order() -> Result<()> {
+    verify_that!(
+        json!({"tags": ["a", "bee"]}),
+        j::pat!({"tags": ["a", starts_with("b")]})
+    )
+}
+
+#[test]
+fn field_array_pattern_requires_exact_length() -> Result<()> {
+    verify_that!(
+        json!({"tags": ["a", "bee", "c"]}),
+        not(j::pat!({"tags": ["a", starts_with("b")]}))
+    )
+}
+
+#[test]
+fn top_level_array_pattern() -> Result<()> {
+    verify_that!(json!([1, "x", null]), j::pat!([1, "x", j::is_null()]))?;
+    verify_that!(json!([]), j::pat!([]))?;
+    verify_that!(json!({"a": 1}), not(j::pat!([])))
+}
+
+#[test]
+fn prefix_pattern_matches_leading_elements() -> Result<()> {
+    let pattern = j::pat!([1, 2, ..]);
+    verify_that!(json!([1, 2]), &pattern)?;
+    verify_that!(json!([1, 2, 3]), &pattern)?;
+    verify_that!(json!([0, 1, 2]), not(&pattern))?;
+    verify_that!(json!([1]), not(&pattern))
+}
+
+#[test]
+fn suffix_pattern_matches_trailing_elements() -> Result<()> {
+    let pattern = j::pat!([.., "y", "z"]);
+    verify_that!(json!(["x", "y", "z"]), &pattern)?;
+    verify_that!(json!(["y", "z", "x"]), not(&pattern))
+}
+
+#[test]
+fn contains_pattern_matches_consecutive_elements_anywhere() -> Result<()> {
+    verify_that!(json!([1, 5, 9]), j::pat!([.., gt(4), ..]))?;
+    verify_that!(json!([1, 2, 3, 4]), j::pat!([.., 2, 3, ..]))?;
+    verify_that!(json!([1, 2, 9, 3]), not(j::pat!([.., 2, 3, ..])))?;
+    verify_that!(json!([]), j::pat!([.., ..]))
+}
+
+#[test]
+fn rest_only_pattern_matches_any_array() -> Result<()> {
+    verify_that!(json!([1, 2]), j::pat!([..]))?;
+    verify_that!(json!("x"), not(j::pat!([..])))
+}
+
+#[test]
+fn array_patterns_nest_with_objects() -> Result<()> {
+    let actual = json!({
+        "users": [{"id": 1, "roles": ["admin", "dev"]}, {"id": 2, "roles": []}],
+        "matrix": [[1, 2], [3, 4]]
+    });
+    verify_that!(
+        actual,
+        j::pat!({
+            "users": [{"id": 1, "roles": [.., "dev"]}, ..],
+            "matrix": [[1, ..], [.., 4]],
+        })
+    )
+}
+
+#[test]
+fn prefix_pattern_explains_mismatching_element() -> Result<()> {
+    let result = verify_that!(
+        json!({"tags": ["a", "x", "c"]}),
+        j::pat!({"tags": ["a", "b", ..]})
+    );
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "field 'tags': where element #1 is String(\"x\"), which isn't equal to String(\"b\")"
+        )))
+    )
+}
+
+#[test]
+fn suffix_pattern_explains_short_array() -> Result<()> {
+    let result = verify_that!(json!(["z"]), j::pat!([.., "y", "z"]));
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "whose size is 1, fewer than the 2 expected elements"
+        )))
+    )
+}
+
+#[test]
+fn contains_pattern_explains_missing_element() -> Result<()> {
+    let result = verify_that!(json!([1, 2]), j::pat!([.., gt(4), ..]));
+    verify_that!(
+        result,
+        err(displays_as(
+            contains_substring("has JSON array containing the consecutive elements")
+                .and(contains_substring("where no element matches"))
+        ))
+    )
+}
+
+#[test]
+fn array_pattern_rejects_non_arrays() -> Result<()> {
+    let result = verify_that!(json!({"tags": "a"}), j::pat!({"tags": ["a", ..]}));
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "field 'tags': where the type is not array"
+        )))
+    )
+}
+
+#[test]
+fn check_reports_array_pattern_elements_at_actual_index() {
+    let report = j::check(
+        &json!({"items": [0, 1, -2]}),
+        &j::pat!({"items": [.., gt(0), gt(0)]}),
+    );
+    assert_eq!(report.mismatches().len(), 1);
+    assert_eq!(report.mismatches()[0].pointer(), "/items/2");
+}
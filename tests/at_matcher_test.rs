@@ -0,0 +1,160 @@
+use googletest::Result;
+use googletest::prelude::*;
+use googletest_json_serde::json as j;
+use serde_json::{Value, json};
+
+fn orders() -> Value {
+    json!({
+        "orders": [
+            {"id": 1, "status": "open", "total": 12},
+            {"id": 2, "status": "closed", "total": 0},
+            {"id": 3, "status": "open", "total": 3, "coupon": "SPRING"}
+        ],
+        "owner": {"name": "Ada", "total": 99}
+    })
+}
+
+#[test]
+fn at_matches_filtered_nodes() -> Result<()> {
+    verify_that!(
+        orders(),
+        j::at!("$.orders[?(@.status == 'open')].total", j::each!(gt(0)))
+    )
+}
+
+#[test]
+fn at_matches_child_and_index_selectors() -> Result<()> {
+    verify_that!(orders(), j::at!("$.owner.name", j::elements_are!["Ada"]))?;
+    verify_that!(orders(), j::at!("$['orders'][-1].id", j::elements_are![3]))
+}
+
+#[test]
+fn at_matches_recursive_descent_and_wildcards() -> Result<()> {
+    verify_that!(orders(), j::at!("$..total", j::len!(4)))?;
+    verify_that!(
+        orders(),
+        j::at!("$.orders[*].id", j::elements_are![1, 2, 3])
+    )
+}
+
+#[test]
+fn at_matches_slices_and_unions() -> Result<()> {
+    verify_that!(orders(), j::at!("$.orders[0:2].id", j::elements_are![1, 2]))?;
+    verify_that!(
+        orders(),
+        j::at!("$.orders[::-2].id", j::elements_are![3, 1])
+    )?;
+    verify_that!(orders(), j::at!("$.orders[0,2].status", j::each!("open")))
+}
+
+#[test]
+fn at_matches_existence_and_compound_filters() -> Result<()> {
+    verify_that!(
+        orders(),
+        j::at!("$.orders[?(@.coupon)].id", j::elements_are![3])
+    )?;
+    verify_that!(
+        orders(),
+        j::at!(
+            "$.orders[?(@.status == 'open' && @.total < 10)].id",
+            j::elements_are![3]
+        )
+    )
+}
+
+#[test]
+fn at_passes_empty_selection_to_inner_matcher() -> Result<()> {
+    verify_that!(
+        orders(),
+        j::at!("$.orders[?(@.total > 100)]", j::is_empty_array())
+    )
+}
+
+#[test]
+fn at_composes_inside_pat() -> Result<()> {
+    verify_that!(
+        orders(),
+        j::pat!({
+            "orders": j::at!("$[*].status", j::contains_each!["closed"]),
+            ..
+        })
+    )
+}
+
+#[test]
+fn at_explains_resolved_paths() -> Result<()> {
+    let result = verify_that!(orders(), j::at!("$.orders[*].total", j::each!(gt(0))));
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "which selected 3 nodes at `$.orders[0].total`, `$.orders[1].total`, `$.orders[2].total`"
+        )))
+    )
+}
+
+#[test]
+fn at_explains_empty_selection() -> Result<()> {
+    let result = verify_that!(orders(), j::at!("$.missing", j::len!(1)));
+    verify_that!(
+        result,
+        err(displays_as(contains_substring("which selected no nodes")))
+    )
+}
+
+#[test]
+fn at_describes_query_and_inner_matcher() -> Result<()> {
+    let result = verify_that!(orders(), j::at!("$.owner.name", j::elements_are!["Bob"]));
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "Expected: has nodes at JSONPath `$.owner.name`, which"
+        )))
+    )
+}
+
+#[test]
+fn at_reports_invalid_query() -> Result<()> {
+    let result = verify_that!(orders(), j::at!("$.orders[", j::len!(0)));
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            r#"which is an Invalid JSONPath "$.orders[": expected an index, slice or quoted name at position 9"#
+        )))
+    )
+}
+
+#[test]
+fn at_explains_failing_elements_by_selected_path() -> Result<()> {
+    let result = verify_that!(orders(), j::at!("$.orders[*].total", j::each!(gt(0))));
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "where element #1 is `$.orders[1].total`"
+        )))
+    )
+}
+
+#[test]
+fn check_reports_at_failures_at_selected_paths() -> Result<()> {
+    let report = j::check(
+        &orders(),
+        &j::pat!({"orders": j::at!("$[*].total", j::each!(gt(0))), ..}),
+    );
+    let paths = report
+        .mismatches()
+        .iter()
+        .map(|m| m.path_string())
+        .collect::<Vec<_>>();
+    verify_that!(paths, elements_are![eq("orders.1.total")])
+}
+
+#[test]
+fn check_reports_selection_mismatches_at_the_query() -> Result<()> {
+    let report = j::check(&orders(), &j::at!("$..total", j::len!(3)));
+    let paths = report
+        .mismatches()
+        .iter()
+        .map(|m| m.path_string())
+        .collect::<Vec<_>>();
+    verify_that!(paths, elements_are![eq("")])
+}
@@ -0,0 +1,140 @@
+use googletest::Result;
+use googletest::prelude::*;
+use googletest_json_serde::json as j;
+use googletest_json_serde::report::PathSegment;
+use serde_json::json;
+
+#[test]
+fn check_reports_match() -> Result<()> {
+    let report = j::check(&json!({"id": 1}), &j::pat!({"id": 1}));
+    verify_that!(report.is_match(), eq(true))?;
+    verify_that!(report.mismatches(), is_empty())?;
+    verify_that!(report.to_string(), eq("matched"))
+}
+
+#[test]
+fn check_reports_nested_leaf_mismatch_with_path() -> Result<()> {
+    let actual = json!({"user": {"id": 7, "name": "Bob"}});
+    let report = j::check(&actual, &j::pat!({"user": {"id": 7, "name": "Ada"}}));
+    verify_that!(report.is_match(), eq(false))?;
+    verify_that!(report.mismatches().len(), eq(1))?;
+    let mismatch = &report.mismatches()[0];
+    verify_that!(
+        mismatch.path(),
+        eq(&[
+            PathSegment::Field("user".into()),
+            PathSegment::Field("name".into())
+        ][..])
+    )?;
+    verify_that!(mismatch.path_string(), eq("user.name"))?;
+    verify_that!(mismatch.pointer(), eq("/user/name"))?;
+    verify_that!(mismatch.expected(), contains_substring("Ada"))?;
+    verify_that!(mismatch.actual(), some(eq("\"Bob\"")))
+}
+
+#[test]
+fn check_reports_every_failing_field() -> Result<()> {
+    let actual = json!({"a": 1, "b": 2, "extra": true});
+    let report = j::check(&actual, &j::pat!({"a": 10, "b": 2, "c": 3}));
+    let paths = report
+        .mismatches()
+        .iter()
+        .map(|m| m.path_string())
+        .collect::<Vec<_>>();
+    verify_that!(paths, elements_are![eq("a"), eq("c"), eq("extra")])?;
+    verify_that!(report.mismatches()[1].actual(), none())?;
+    verify_that!(report.mismatches()[1].explanation(), eq("was missing"))?;
+    verify_that!(
+        report.mismatches()[2].explanation(),
+        eq("unexpected field 'extra' present")
+    )
+}
+
+#[test]
+fn check_attributes_array_elements() -> Result<()> {
+    let actual = json!({"items": [{"price": 3}, {"price": -1}, {"price": -2}]});
+    let report = j::check(
+        &actual,
+        &j::pat!({"items": j::each!(j::pat!({"price": gt(0)}))}),
+    );
+    let pointers = report
+        .mismatches()
+        .iter()
+        .map(|m| m.pointer())
+        .collect::<Vec<_>>();
+    verify_that!(
+        pointers,
+        elements_are![eq("/items/1/price"), eq("/items/2/price")]
+    )
+}
+
+#[test]
+fn check_attributes_ordered_elements() -> Result<()> {
+    let report = j::check(&json!([1, 2, 3]), &j::elements_are![1, 5, 3]);
+    verify_that!(report.mismatches().len(), eq(1))?;
+    verify_that!(report.mismatches()[0].pointer(), eq("/1"))
+}
+
+#[test]
+fn check_reports_length_mismatch_at_array() -> Result<()> {
+    let report = j::check(
+        &json!({"xs": [1]}),
+        &j::pat!({"xs": j::elements_are![1, 2]}),
+    );
+    verify_that!(report.mismatches().len(), eq(1))?;
+    verify_that!(report.mismatches()[0].path_string(), eq("xs"))
+}
+
+#[test]
+fn check_reports_root_type_mismatch() -> Result<()> {
+    let report = j::check(&json!([1]), &j::pat!({"a": 1}));
+    verify_that!(report.mismatches().len(), eq(1))?;
+    verify_that!(report.mismatches()[0].path(), is_empty())?;
+    verify_that!(report.mismatches()[0].actual(), some(eq("[1]")))
+}
+
+#[test]
+fn check_resolves_path_matchers() -> Result<()> {
+    let actual = json!({"orders": [{"total": 3}, {"total": 0}]});
+    let report = j::check(&actual, &j::has_path_with!("orders.*.total", gt(0)));
+    verify_that!(report.mismatches().len(), eq(1))?;
+    verify_that!(report.mismatches()[0].path_string(), eq("orders.1.total"))?;
+
+    let report = j::check(&actual, &j::has_path_with!("meta.id", 1));
+    verify_that!(report.mismatches()[0].path_string(), eq("meta.id"))?;
+    verify_that!(report.mismatches()[0].actual(), none())
+}
+
+#[test]
+fn check_skips_null_optional_fields() -> Result<()> {
+    let report = j::check(
+        &json!({"a": null, "b": 2}),
+        &j::pat!({"a": j::optional!(1), "b": j::optional!(3)}),
+    );
+    verify_that!(report.mismatches().len(), eq(1))?;
+    verify_that!(report.mismatches()[0].path_string(), eq("b"))
+}
+
+#[test]
+fn check_report_serializes_to_json() -> Result<()> {
+    let report = j::check(&json!({"xs": [0, 5]}), &j::pat!({"xs": j::each!(lt(3))}));
+    let rendered = serde_json::to_value(&report).unwrap();
+    verify_that!(rendered["matched"], eq(&json!(false)))?;
+    verify_that!(rendered["mismatches"][0]["path"], eq(&json!(["xs", 1])))?;
+    verify_that!(rendered["mismatches"][0]["pointer"], eq(&json!("/xs/1")))?;
+    verify_that!(rendered["mismatches"][0]["actual"], eq(&json!("5")))?;
+    verify_that!(rendered, eq(&report.to_json()))
+}
+
+#[test]
+fn check_report_displays_mismatches() -> Result<()> {
+    let report = j::check(&json!({"a": 1}), &j::pat!({"a": 2, "b": 3}));
+    verify_that!(
+        report.to_string(),
+        all![
+            starts_with("2 mismatches:\n  `a`: expected "),
+            contains_substring("`b`: expected "),
+            contains_substring("actual missing (was missing)")
+        ]
+    )
+}
@@ -0,0 +1,83 @@
+use googletest::Result;
+use googletest::prelude::*;
+use googletest_json_serde::json as j;
+use serde_json::{Map, Value, json};
+
+#[test]
+fn matches_option_values() -> Result<()> {
+    let body = Some(json!({"items": [1, 2, 3]}));
+    verify_that!(body, j::parsed(j::has_path_with!("items.1", 2)))?;
+    verify_that!(body, j::parsed(j::pat!({"items": j::len!(3)})))?;
+    verify_that!(Some(json!([1, 2])), j::parsed(j::elements_are![1, 2]))?;
+    verify_that!(None::<Value>, not(j::parsed(j::is_null())))
+}
+
+#[test]
+fn matches_borrowed_option_values() -> Result<()> {
+    let value = json!({"user": {"id": 7}});
+    verify_that!(value.get("user"), j::parsed(j::pat!({"id": 7})))?;
+    verify_that!(
+        value.get("team"),
+        not(j::parsed(j::optional!(j::is_object())))
+    )
+}
+
+#[test]
+fn explains_none() -> Result<()> {
+    let result = verify_that!(None::<Value>, j::parsed(j::len!(2)));
+    verify_that!(result, err(displays_as(contains_substring("was None"))))?;
+    let value = json!({});
+    let result = verify_that!(value.get("items"), j::parsed(j::elements_are![1]));
+    verify_that!(result, err(displays_as(contains_substring("was None"))))
+}
+
+#[test]
+fn matches_result_values() -> Result<()> {
+    let parsed: std::result::Result<Value, serde_json::Error> = serde_json::from_str("[1, 2]");
+    verify_that!(parsed, j::parsed(j::elements_are![1, 2]))?;
+    let failed: std::result::Result<Value, String> = Err("connection reset".into());
+    verify_that!(failed, not(j::parsed(j::is_array())))
+}
+
+#[test]
+fn explains_err() -> Result<()> {
+    let response: std::result::Result<Value, String> = Err("connection reset".into());
+    let result = verify_that!(response, j::parsed(j::pat!({"id": 7})));
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "was Err(\"connection reset\")"
+        )))
+    )
+}
+
+#[test]
+fn matches_maps_and_element_vectors() -> Result<()> {
+    let mut map = Map::new();
+    map.insert("id".into(), json!(7));
+    verify_that!(map, j::parsed(j::pat!({"id": 7})))?;
+    verify_that!(map, j::parsed(j::has_paths(&["id"])))?;
+
+    let elements = vec![json!(1), json!("two")];
+    verify_that!(elements, j::parsed(j::elements_are![1, "two"]))?;
+    verify_that!(elements.as_slice(), j::parsed(j::len!(2)))?;
+    verify_that!(elements, j::parsed(j::each!(j::is_not_null())))
+}
+
+#[test]
+fn explains_mismatches_inside_containers() -> Result<()> {
+    let result = verify_that!(Some(json!({"id": "7"})), j::parsed(j::pat!({"id": 7})));
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "field 'id': which isn't equal to Number(7)"
+        )))
+    )?;
+    let result = verify_that!(vec![json!(1), json!(3)], j::parsed(j::elements_are![1, 2]));
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "element #1 is Number(3), which isn't equal to Number(2)"
+        )))
+    )
+}
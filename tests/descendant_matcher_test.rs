@@ -0,0 +1,200 @@
+use googletest::Result;
+use googletest::prelude::*;
+use googletest_json_serde::json as j;
+use serde_json::json;
+
+const CARD: &str = ".*[0-9]{16}.*";
+
+#[test]
+fn no_descendant_passes_when_nothing_matches() -> Result<()> {
+    let response = json!({"user": {"name": "Ada", "cards": ["****1111"]}, "total": 3});
+    verify_that!(response, j::no_descendant!(matches_regex(CARD)))
+}
+
+#[test]
+fn no_descendant_lists_every_offending_path() -> Result<()> {
+    let response = json!({
+        "user": {"card": "4111111111111111"},
+        "payments": [{"note": "ok"}, {"note": "paid with 5500000000000004"}]
+    });
+    let result = verify_that!(response, j::no_descendant!(matches_regex(CARD)));
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "which has 2 matching descendants at `payments.1.note`, `user.card`"
+        )))
+    )
+}
+
+#[test]
+fn no_descendant_ignores_the_root() -> Result<()> {
+    verify_that!(json!({"a": 1}), j::no_descendant!(j::is_object()))?;
+    verify_that!(
+        json!({"a": {"b": 1}}),
+        not(j::no_descendant!(j::is_object()))
+    )
+}
+
+#[test]
+fn no_descendant_accepts_json_matchers() -> Result<()> {
+    let value = json!({"items": [{"price": 1}, {"price": -2}]});
+    verify_that!(value, not(j::no_descendant!(j::pat!({"price": lt(0)}))))?;
+    verify_that!(value, j::no_descendant!(j::pat!({"price": gt(5)})))
+}
+
+#[test]
+fn no_key_anywhere_finds_nested_keys() -> Result<()> {
+    let forbidden = j::no_key_anywhere(&["password", "secret"]);
+    verify_that!(json!({"user": {"name": "Ada"}}), &forbidden)?;
+    let result = verify_that!(
+        json!({
+            "user": {"name": "Ada", "password": "hunter2"},
+            "sessions": [{"id": 1}, {"id": 2, "secret": null}]
+        }),
+        &forbidden
+    );
+    verify_that!(
+        result,
+        err(displays_as(all!(
+            contains_substring("has none of the keys 'password', 'secret' at any depth"),
+            contains_substring(
+                "which has 2 forbidden keys at `sessions.1.secret`, `user.password`"
+            )
+        )))
+    )
+}
+
+#[test]
+fn no_key_anywhere_does_not_match_values() -> Result<()> {
+    verify_that!(
+        json!({"fields": ["password", {"label": "secret"}]}),
+        j::no_key_anywhere(&["password", "secret"])
+    )
+}
+
+#[test]
+fn deep_scans_work_inside_patterns() -> Result<()> {
+    let result = verify_that!(
+        json!({"id": 1, "profile": {"token": "abc"}}),
+        j::pat!({"id": 1, "profile": j::no_key_anywhere(&["token"])})
+    );
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "field 'profile': which has 1 forbidden key at `token`"
+        )))
+    )
+}
+
+#[test]
+fn check_reports_each_offender_at_its_full_path() {
+    let report = j::check(
+        &json!({"data": {"a": {"password": 1}, "b": [{"password": 2}]}}),
+        &j::pat!({"data": j::no_key_anywhere(&["password"])}),
+    );
+    let pointers = report
+        .mismatches()
+        .iter()
+        .map(|m| m.pointer())
+        .collect::<Vec<_>>();
+    assert_eq!(pointers, ["/data/a/password", "/data/b/0/password"]);
+
+    let report = j::check(
+        &json!(["ok", ["4111111111111111"]]),
+        &j::no_descendant!(matches_regex(CARD)),
+    );
+    assert_eq!(report.mismatches().len(), 1);
+    assert_eq!(report.mismatches()[0].path_string(), "1.0");
+}
+
+#[test]
+fn has_descendant_finds_nodes_at_any_depth() -> Result<()> {
+    let value = json!({"order": {"lines": [{"sku": "A-1"}, {"sku": "B-2", "gift": true}]}});
+    verify_that!(value, j::has_descendant!("B-2"))?;
+    verify_that!(value, j::has_descendant!(j::pat!({"gift": true, ..})))?;
+    let result = verify_that!(value, j::has_descendant!("C-3"));
+    verify_that!(
+        result,
+        err(displays_as(all!(
+            contains_substring("has a descendant that is equal to String(\"C-3\")"),
+            contains_substring("which has no matching descendants")
+        )))
+    )
+}
+
+#[test]
+fn has_descendant_does_not_match_the_root() -> Result<()> {
+    verify_that!(json!("x"), not(j::has_descendant!("x")))
+}
+
+#[test]
+fn all_leaves_checks_only_scalars() -> Result<()> {
+    let trimmed = j::predicate(|v| v.as_str().is_none_or(|s| s.trim() == s));
+    verify_that!(
+        json!({"name": "Ada", "tags": ["x", 1, null], "meta": {}}),
+        j::all_leaves!(trimmed.clone())
+    )?;
+    verify_that!(json!({"a": [[], {}]}), j::all_leaves!(j::is_string()))?;
+    verify_that!(json!(" x"), not(j::all_leaves!(trimmed)))
+}
+
+#[test]
+fn all_leaves_explains_each_failing_leaf() -> Result<()> {
+    let result = verify_that!(
+        json!({"name": " Ada", "tags": ["x", "y "]}),
+        j::all_leaves!(
+            j::predicate(|v| v.as_str().is_some_and(|s| s.trim() == s))
+                .with_description("is a trimmed string", "is not a trimmed string")
+        )
+    );
+    verify_that!(
+        result,
+        err(displays_as(all!(
+            contains_substring("has only scalar leaves that is a trimmed string"),
+            contains_substring("which has 2 failing leaves:"),
+            contains_substring("`name`: "),
+            contains_substring("`tags.1`: ")
+        )))
+    )
+}
+
+#[test]
+fn all_leaves_explains_single_failure_inline() -> Result<()> {
+    let result = verify_that!(json!({"tags": ["y", " y"]}), j::all_leaves!("y"));
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "which has 1 failing leaf at `tags.1`, which isn't equal to String(\"y\")"
+        )))
+    )
+}
+
+#[test]
+fn descendants_matching_counts_matches() -> Result<()> {
+    let value = json!({"items": [{"id": 1}, {"id": 2}, {"id": 3, "children": [{"id": 4}]}]});
+    verify_that!(
+        value,
+        j::descendants_matching!(j::pat!({"id": j::is_integer(), ..}), 4)
+    )?;
+    verify_that!(value, j::descendants_matching!(j::is_object(), ge(3)))?;
+    let result = verify_that!(value, j::descendants_matching!(j::is_array(), gt(2)));
+    verify_that!(
+        result,
+        err(displays_as(all!(
+            contains_substring(
+                "has a number of descendants that a JSON array, which is greater than 2"
+            ),
+            contains_substring("which has 2 matching descendants at `items`, `items.2.children`")
+        )))
+    )
+}
+
+#[test]
+fn check_reports_failing_leaves_at_their_paths() {
+    let report = j::check(
+        &json!({"data": {"a": 1, "b": ["ok", -2]}}),
+        &j::pat!({"data": j::all_leaves!(j::predicate(|v| v.as_i64().is_none_or(|n| n >= 0)))}),
+    );
+    assert_eq!(report.mismatches().len(), 1);
+    assert_eq!(report.mismatches()[0].pointer(), "/data/b/1");
+}
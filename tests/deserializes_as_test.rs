@@ -0,0 +1,100 @@
+use googletest::Result;
+use googletest::prelude::*;
+use googletest_json_serde::json as j;
+use serde::Deserialize;
+use serde_json::json;
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Address {
+    city: String,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct User {
+    id: u64,
+    name: String,
+    addresses: Vec<Address>,
+}
+
+#[test]
+fn deserializes_as_applies_native_matcher() -> Result<()> {
+    let value = json!({"id": 7, "name": "Ada", "addresses": [{"city": "Paris"}]});
+    verify_that!(
+        value,
+        j::deserializes_as::<User>(matches_pattern!(User {
+            id: eq(&7),
+            name: starts_with("A"),
+            addresses: len(eq(1)),
+        }))
+    )?;
+    verify_that!(
+        value,
+        not(j::deserializes_as::<User>(field!(User.id, eq(&8))))
+    )
+}
+
+#[test]
+fn deserializes_as_explains_serde_error_with_path() -> Result<()> {
+    let result = verify_that!(
+        json!({"id": 7, "name": "Ada", "addresses": [{"city": "Paris"}, {"city": 5}]}),
+        j::deserializes_as::<User>(anything())
+    );
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "which fails to deserialize as deserializes_as_test::User at `addresses.1.city`: invalid type: integer `5`, expected a string"
+        )))
+    )
+}
+
+#[test]
+fn deserializes_as_explains_missing_field_at_root() -> Result<()> {
+    let result = verify_that!(
+        json!({"id": 7, "addresses": []}),
+        j::deserializes_as::<User>(anything())
+    );
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "which fails to deserialize as deserializes_as_test::User: missing field `name`"
+        )))
+    )
+}
+
+#[test]
+fn deserializes_as_explains_inner_mismatch() -> Result<()> {
+    let result = verify_that!(
+        json!({"id": 7, "name": "Ada", "addresses": []}),
+        j::deserializes_as::<User>(field!(User.name, eq("Bob")))
+    );
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "deserializes as deserializes_as_test::User which has field `name`"
+        )))
+    )
+}
+
+#[test]
+fn deserializes_as_works_inside_patterns() -> Result<()> {
+    verify_that!(
+        json!({"data": {"city": "Oslo"}, "ok": true}),
+        j::pat!({
+            "data": j::deserializes_as::<Address>(field!(Address.city, eq("Oslo"))),
+            "ok": true
+        })
+    )
+}
+
+#[test]
+fn check_reports_deserialize_error_at_serde_path() {
+    let report = j::check(
+        &json!({"user": {"id": "7", "name": "Ada", "addresses": []}}),
+        &j::pat!({"user": j::deserializes_as::<User>(anything())}),
+    );
+    assert_eq!(report.mismatches().len(), 1);
+    let mismatch = &report.mismatches()[0];
+    assert_eq!(mismatch.pointer(), "/user/id");
+    assert_eq!(mismatch.actual(), Some("\"7\""));
+    assert!(mismatch.explanation().contains("invalid type"));
+}
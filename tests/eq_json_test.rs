@@ -0,0 +1,113 @@
+use googletest::Result;
+use googletest::prelude::*;
+use googletest_json_serde::json as j;
+use indoc::indoc;
+use serde_json::json;
+
+#[test]
+fn eq_json_matches_equal_documents() -> Result<()> {
+    verify_that!(
+        json!({"user": {"id": 7, "tags": ["a", "b"]}}),
+        j::eq_json!(json!({"user": {"id": 7, "tags": ["a", "b"]}}))
+    )
+}
+
+#[test]
+fn eq_json_matches_with_borrowed_expected() -> Result<()> {
+    let expected = json!({"ok": true});
+    verify_that!(json!({"ok": true}), j::eq_json!(&expected))
+}
+
+#[test]
+fn eq_json_rejects_different_documents() -> Result<()> {
+    verify_that!(json!({"ok": false}), not(j::eq_json!(json!({"ok": true}))))
+}
+
+#[test]
+fn eq_json_composes_inside_pat() -> Result<()> {
+    verify_that!(
+        json!({"meta": {"v": 1}, "id": 3}),
+        j::pat!({"meta": j::eq_json!(json!({"v": 1})), "id": 3})
+    )
+}
+
+#[test]
+fn eq_json_explains_single_changed_leaf() -> Result<()> {
+    let result = verify_that!(
+        json!({"user": {"id": 7, "name": "Bob"}}),
+        j::eq_json!(json!({"user": {"id": 7, "name": "Ada"}}))
+    );
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(indoc!(
+            r#"
+            which differs from the expected JSON at 1 path:
+                changed `user.name`: expected "Ada", actual "Bob"
+            "#
+        ))))
+    )
+}
+
+#[test]
+fn eq_json_explains_added_removed_and_changed() -> Result<()> {
+    let result = verify_that!(
+        json!({"items": [1, 2, 3], "name": "x", "extra": null}),
+        j::eq_json!(json!({"items": [1, 5], "name": "x", "id": 1}))
+    );
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(indoc!(
+            r#"
+            which differs from the expected JSON at 4 paths:
+                added `extra`: actual null
+                removed `id`: expected 1
+                changed `items.1`: expected 5, actual 2
+                added `items.2`: actual 3
+            "#
+        ))))
+    )
+}
+
+#[test]
+fn eq_json_explains_with_escaped_field_names() -> Result<()> {
+    let result = verify_that!(json!({"a.b": 1}), j::eq_json!(json!({"a.b": 2})));
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            r"changed `a\.b`: expected 2, actual 1"
+        )))
+    )
+}
+
+#[test]
+fn eq_json_explains_root_type_change() -> Result<()> {
+    let result = verify_that!(json!([1]), j::eq_json!(json!({"a": 1})));
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            r#"changed (root): expected {"a":1}, actual [1]"#
+        )))
+    )
+}
+
+#[test]
+fn eq_json_describes_expected_document() -> Result<()> {
+    let result = verify_that!(json!(1), j::eq_json!(json!({"a": 1})));
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            r#"Expected: is equal to JSON {"a":1}"#
+        )))
+    )
+}
+
+#[test]
+fn eq_json_not_describes_no_match() -> Result<()> {
+    let result = verify_that!(json!({"a": 1}), not(j::eq_json!(json!({"a": 1}))));
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            r#"isn't equal to JSON {"a":1}"#
+        )))
+    )
+}
@@ -0,0 +1,117 @@
+use googletest::Result;
+use googletest::prelude::*;
+use googletest_json_serde::json as j;
+use indoc::indoc;
+use serde_json::json;
+
+#[test]
+fn eq_json_unordered_ignores_order_at_every_level() -> Result<()> {
+    verify_that!(
+        json!({"users": [{"id": 2, "roles": ["b", "a"]}, {"id": 1, "roles": []}]}),
+        j::eq_json_unordered!(json!({
+            "users": [{"id": 1, "roles": []}, {"id": 2, "roles": ["a", "b"]}]
+        }))
+    )
+}
+
+#[test]
+fn eq_json_unordered_treats_arrays_as_multisets() -> Result<()> {
+    verify_that!(json!([1, 1, 2]), j::eq_json_unordered!(json!([1, 2, 1])))?;
+    verify_that!(
+        json!([1, 1, 2]),
+        not(j::eq_json_unordered!(json!([1, 2, 2])))
+    )?;
+    verify_that!(json!([1, 2]), not(j::eq_json_unordered!(json!([1, 2, 2]))))
+}
+
+#[test]
+fn eq_json_unordered_still_compares_objects_and_leaves_exactly() -> Result<()> {
+    verify_that!(
+        json!({"id": 1, "tags": ["a"], "extra": true}),
+        not(j::eq_json_unordered!(json!({"id": 1, "tags": ["a"]})))
+    )?;
+    verify_that!(
+        json!({"id": 1.0}),
+        not(j::eq_json_unordered!(json!({"id": 1})))
+    )
+}
+
+#[test]
+fn eq_json_unordered_at_paths_keeps_other_arrays_ordered() -> Result<()> {
+    let actual = json!({"tags": ["b", "a"], "steps": [1, 2], "orders": [{"lines": [2, 1]}]});
+    let expected = json!({"tags": ["a", "b"], "steps": [1, 2], "orders": [{"lines": [1, 2]}]});
+    verify_that!(
+        actual,
+        j::eq_json_unordered!(&expected).unordered_at(&["tags", "orders.*.lines"])
+    )?;
+    verify_that!(
+        actual,
+        not(j::eq_json_unordered!(&expected).unordered_at(&["tags"]))
+    )?;
+    verify_that!(
+        json!({"tags": ["a", "b"], "steps": [2, 1]}),
+        not(
+            j::eq_json_unordered!(json!({"tags": ["b", "a"], "steps": [1, 2]}))
+                .unordered_at(&["/tags"])
+        )
+    )
+}
+
+#[test]
+fn eq_json_unordered_explains_elements_without_counterpart() -> Result<()> {
+    let result = verify_that!(
+        json!({"users": [{"id": 3}, {"id": 1}], "tags": ["x", "a"], "name": "b"}),
+        j::eq_json_unordered!(json!({
+            "users": [{"id": 1}, {"id": 2}],
+            "tags": ["a", "x"],
+            "name": "a"
+        }))
+    );
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(indoc!(
+            r#"
+            which differs from the expected JSON at 3 paths:
+                changed `name`: expected "a", actual "b"
+                unmatched actual element #0 in `users`: {"id":3}
+                unmatched expected element #1 in `users`: {"id":2}
+            "#
+        ))))
+    )
+}
+
+#[test]
+fn eq_json_unordered_explains_nested_and_ordered_differences() -> Result<()> {
+    let result = verify_that!(
+        json!({"orders": [{"lines": [1, 2]}], "steps": [1, 3]}),
+        j::eq_json_unordered!(json!({"orders": [{"lines": [1, 2]}], "steps": [1, 2]}))
+            .unordered_at(&["orders"])
+    );
+    verify_that!(
+        result,
+        err(displays_as(all!(
+            contains_substring("ignoring the order of arrays at [\"orders\"]"),
+            contains_substring("changed `steps.1`: expected 2, actual 3")
+        )))
+    )
+}
+
+#[test]
+fn eq_json_unordered_reports_invalid_paths() -> Result<()> {
+    let result = verify_that!(
+        json!([1]),
+        j::eq_json_unordered!(json!([1])).unordered_at(&["a..b"])
+    );
+    verify_that!(
+        result,
+        err(displays_as(contains_substring("invalid paths")))
+    )
+}
+
+#[test]
+fn eq_json_unordered_composes_inside_pat() -> Result<()> {
+    verify_that!(
+        json!({"id": 7, "members": [{"id": 2}, {"id": 1}]}),
+        j::pat!({"id": 7, "members": j::eq_json_unordered!(json!([{"id": 1}, {"id": 2}]))})
+    )
+}
@@ -0,0 +1,136 @@
+use googletest::Result;
+use googletest::description::Description;
+use googletest::matcher::{Matcher, MatcherBase, MatcherResult};
+use googletest::prelude::*;
+use googletest_json_serde::extension::{
+    JsonMatcher, JsonPredicateMatcher, describe_json_type, testkit,
+};
+use googletest_json_serde::json as j;
+use serde_json::{Value, json};
+
+#[derive(MatcherBase)]
+struct IsOrderId;
+
+impl Matcher<&Value> for IsOrderId {
+    fn matches(&self, actual: &Value) -> MatcherResult {
+        actual
+            .as_str()
+            .and_then(|s| s.strip_prefix("ord_"))
+            .is_some_and(|rest| !rest.is_empty() && rest.chars().all(|c| c.is_ascii_digit()))
+            .into()
+    }
+
+    fn describe(&self, result: MatcherResult) -> Description {
+        match result {
+            MatcherResult::Match => "is an order id".into(),
+            MatcherResult::NoMatch => "isn't an order id".into(),
+        }
+    }
+
+    fn explain_match(&self, actual: &Value) -> Description {
+        match actual {
+            Value::String(s) => format!("which is {s:?}, missing the `ord_` prefix").into(),
+            other => describe_json_type(other),
+        }
+    }
+}
+
+impl JsonMatcher for IsOrderId {}
+
+/// A field that may be left out entirely, but must be an order id when present.
+#[derive(MatcherBase)]
+struct MaybeOrderId;
+
+impl Matcher<&Value> for MaybeOrderId {
+    fn matches(&self, actual: &Value) -> MatcherResult {
+        IsOrderId.matches(actual)
+    }
+
+    fn describe(&self, result: MatcherResult) -> Description {
+        IsOrderId.describe(result)
+    }
+
+    fn explain_match(&self, actual: &Value) -> Description {
+        IsOrderId.explain_match(actual)
+    }
+}
+
+impl JsonMatcher for MaybeOrderId {
+    fn allows_missing(&self) -> bool {
+        true
+    }
+}
+
+#[test]
+fn custom_matcher_composes_inside_macros() -> Result<()> {
+    verify_that!(
+        json!({"id": "ord_1", "related": ["ord_2", "ord_3"], "parent": null}),
+        j::pat!({
+            "id": IsOrderId,
+            "related": j::elements_are![IsOrderId, IsOrderId],
+            "parent": j::optional!(IsOrderId),
+        })
+    )
+}
+
+#[test]
+fn custom_matcher_allows_missing_field() -> Result<()> {
+    verify_that!(
+        json!({"id": "ord_1"}),
+        j::pat!({"id": IsOrderId, "parent": MaybeOrderId})
+    )?;
+    verify_that!(
+        json!({"id": "ord_1", "parent": "x"}),
+        not(j::pat!({"id": IsOrderId, "parent": MaybeOrderId}))
+    )
+}
+
+#[test]
+fn custom_matcher_explains_inside_pat() -> Result<()> {
+    let result = verify_that!(json!({"id": "1234"}), j::pat!({"id": IsOrderId}));
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            r#"field 'id': which is "1234", missing the `ord_` prefix"#
+        )))
+    )
+}
+
+#[test]
+fn custom_matcher_is_reported_by_check() -> Result<()> {
+    let report = j::check(
+        &json!({"ids": ["ord_1", 7]}),
+        &j::pat!({"ids": j::each!(IsOrderId)}),
+    );
+    verify_that!(report.mismatches().len(), eq(1))?;
+    verify_that!(report.mismatches()[0].pointer(), eq("/ids/1"))?;
+    verify_that!(report.mismatches()[0].expected(), eq("is an order id"))
+}
+
+#[test]
+fn testkit_renders_descriptions() -> Result<()> {
+    verify_that!(testkit::matches(&IsOrderId, &json!("ord_9")), eq(true))?;
+    verify_that!(testkit::matches(&IsOrderId, &json!("ord_")), eq(false))?;
+    verify_that!(testkit::describe_match(&IsOrderId), eq("is an order id"))?;
+    verify_that!(
+        testkit::describe_no_match(&IsOrderId),
+        eq("isn't an order id")
+    )?;
+    verify_that!(
+        testkit::explain(&IsOrderId, &json!(3)),
+        eq("which is a JSON number")
+    )
+}
+
+#[test]
+fn testkit_works_with_predicate_matchers() -> Result<()> {
+    let positive = JsonPredicateMatcher::new(
+        |v: &Value| v.as_i64().is_some_and(|n| n > 0),
+        "is positive",
+        "isn't positive",
+    )
+    .with_explain_fn(|v| format!("which is {v}").into());
+    verify_that!(testkit::describe_match(&positive), eq("is positive"))?;
+    verify_that!(testkit::describe_no_match(&positive), eq("isn't positive"))?;
+    verify_that!(testkit::explain(&positive, &json!(-3)), eq("which is -3"))
+}
@@ -1,5 +1,7 @@
 use googletest::prelude::*;
 use googletest_json_serde::json as j;
+use googletest_json_serde::report::PathSegment;
+use indoc::indoc;
 use serde_json::json;
 
 #[test]
@@ -122,3 +124,167 @@ fn has_path_with_not_describe_no_match() -> Result<()> {
         )))
     )
 }
+
+#[test]
+fn has_path_with_wildcard_matches_all_elements() -> Result<()> {
+    let value = json!({"items": [{"price": 1}, {"price": 2}]});
+    verify_that!(value, j::has_path_with!("items.*.price", gt(0)))
+}
+
+#[test]
+fn has_path_with_wildcard_fails_when_one_element_fails() -> Result<()> {
+    let value = json!({"items": [{"price": 1}, {"price": 0}]});
+    verify_that!(value, not(j::has_path_with!("items.*.price", gt(0))))
+}
+
+#[test]
+fn has_path_with_wildcard_for_any_matches_single_element() -> Result<()> {
+    let value = json!({"items": [{"price": 1}, {"price": 0}]});
+    verify_that!(value, j::has_path_with!("items.*.price", eq(0)).for_any())
+}
+
+#[test]
+fn has_path_with_wildcard_matches_object_keys() -> Result<()> {
+    let value = json!({"prices": {"eur": 3, "usd": 4}});
+    verify_that!(value, j::has_path_with!("prices.*", gt(2)))
+}
+
+#[test]
+fn has_path_with_recursive_wildcard_matches_any_depth() -> Result<()> {
+    let value = json!({"id": 1, "child": {"id": 2, "children": [{"id": 3}]}});
+    verify_that!(value, j::has_path_with!("**.id", j::is_integer()))
+}
+
+#[test]
+fn has_path_with_wildcard_rejects_when_nothing_selected() -> Result<()> {
+    let result = verify_that!(
+        json!({"items": []}),
+        j::has_path_with!("items.*.price", gt(0))
+    );
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "no paths match `items.*.price`"
+        )))
+    )
+}
+
+#[test]
+fn has_path_with_wildcard_lists_every_failed_path() -> Result<()> {
+    let result = verify_that!(
+        json!({"items": [{"price": 0}, {"price": 5}, {"price": -1}]}),
+        j::has_path_with!("items.*.price", gt(0))
+    );
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(indoc!(
+            "
+            2 of 3 paths matching `items.*.price` did not match:
+                `items.0.price`: which is less than or equal to 0
+                `items.2.price`: which is less than or equal to 0
+            "
+        ))))
+    )
+}
+
+#[test]
+fn has_path_with_wildcard_for_any_explains_when_none_match() -> Result<()> {
+    let result = verify_that!(
+        json!({"tags": ["a", "b"]}),
+        j::has_path_with!("tags.*", eq("c")).for_any()
+    );
+    verify_that!(
+        result,
+        err(displays_as(
+            contains_substring("none of the 2 paths matching `tags.*` matched")
+                .and(contains_substring("`tags.1`"))
+        ))
+    )
+}
+
+#[test]
+fn has_path_with_wildcard_describes_quantifier() -> Result<()> {
+    let result = verify_that!(json!({"a": [1]}), not(j::has_path_with!("a.*", eq(1))));
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "has paths matching `a.*` whose values do not all match"
+        )))
+    )
+}
+
+#[test]
+fn has_path_with_escaped_star_is_literal_field() -> Result<()> {
+    let value = json!({"a": {"*": 1, "b": 2}});
+    verify_that!(value, j::has_path_with!(r"a.\*", eq(1)))
+}
+
+#[test]
+fn has_path_with_accepts_json_pointer() -> Result<()> {
+    let value = json!({"user": {"addresses": [{"zip": "1234"}]}});
+    verify_that!(value, j::has_path_with!("/user/addresses/0/zip", "1234"))
+}
+
+#[test]
+fn has_path_with_accepts_explicit_pointer() -> Result<()> {
+    let value = json!({"a/b": {"m~n": 1}});
+    verify_that!(value, j::has_path_with!(j::pointer("/a~1b/m~0n"), eq(1)))
+}
+
+#[test]
+fn has_path_with_pointer_reads_numeric_tokens_as_object_keys() -> Result<()> {
+    let value = json!({"items": {"42": 1}, "list": [10, 20]});
+    verify_that!(value, j::has_path_with!("/items/42", eq(1)))?;
+    verify_that!(value, j::has_path_with!("/list/1", eq(20)))?;
+    verify_that!(value, j::has_path_with!("items.42", eq(1)))
+}
+
+#[test]
+fn check_reports_numeric_object_keys_as_fields() -> Result<()> {
+    let report = j::check(
+        &json!({"items": {"42": 1}}),
+        &j::has_path_with!("/items/42", eq(2)),
+    );
+    verify_that!(report.mismatches().len(), eq(1))?;
+    verify_that!(
+        report.mismatches()[0].path(),
+        eq(&[
+            PathSegment::Field("items".into()),
+            PathSegment::Field("42".into())
+        ])
+    )
+}
+
+#[test]
+fn has_path_with_pointer_reports_missing_path_in_pointer_syntax() -> Result<()> {
+    let result = verify_that!(
+        json!({"user": {"id": 1}}),
+        j::has_path_with!("/user/na.me", eq("Ada"))
+    );
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "missing path `/user/na.me`"
+        )))
+    )
+}
+
+#[test]
+fn has_path_with_reports_invalid_pointer_escape() -> Result<()> {
+    let result = verify_that!(json!({"a": 1}), j::has_path_with!("/a~2", eq(1)));
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "'~' must be followed by '0' or '1'"
+        )))
+    )
+}
+
+#[test]
+fn has_path_with_explicit_pointer_requires_leading_slash() -> Result<()> {
+    let result = verify_that!(json!({"a": 1}), j::has_path_with!(j::pointer("a"), eq(1)));
+    verify_that!(
+        result,
+        err(displays_as(contains_substring("must start with '/'")))
+    )
+}
@@ -0,0 +1,114 @@
+use googletest::Result;
+use googletest::prelude::*;
+use googletest_json_serde::extension::testkit;
+use googletest_json_serde::json as j;
+use serde_json::json;
+
+#[test]
+fn key_matcher_entry_matches_dynamic_keys() -> Result<()> {
+    let users = json!({
+        "version": 2,
+        "usr_1": {"name": "Ann", "age": 3},
+        "usr_2": {"name": "Bo"}
+    });
+    verify_that!(
+        users,
+        j::pat!({
+            "version": 2,
+            [matches_regex("usr_.*")]: j::pat!({"name": j::is_string(), ..}),
+        })
+    )
+}
+
+#[test]
+fn key_matcher_entry_requires_at_least_one_key() -> Result<()> {
+    let result = verify_that!(
+        json!({"version": 2}),
+        j::pat!({"version": 2, [starts_with("usr_")]: j::is_object()})
+    );
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "no field whose key starts with prefix \"usr_\""
+        )))
+    )
+}
+
+#[test]
+fn key_matcher_entry_names_failing_keys() -> Result<()> {
+    let result = verify_that!(
+        json!({"usr_1": {"name": "Ann"}, "usr_2": {"name": 7}}),
+        j::pat!({[starts_with("usr_")]: {"name": j::is_string()}})
+    );
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "field 'usr_2': field 'name': which is a JSON number"
+        )))
+    )
+}
+
+#[test]
+fn key_matcher_entry_covers_keys_in_strict_mode() -> Result<()> {
+    let pattern = j::pat!({"version": 2, [starts_with("usr_")]: j::is_object()});
+    verify_that!(json!({"version": 2, "usr_1": {}}), &pattern)?;
+    let result = verify_that!(json!({"version": 2, "usr_1": {}, "admin": {}}), &pattern);
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "unexpected field 'admin' present"
+        )))
+    )
+}
+
+#[test]
+fn key_matcher_entry_ignores_named_fields() -> Result<()> {
+    verify_that!(
+        json!({"usr_admin": true, "usr_1": 1}),
+        j::pat!({"usr_admin": true, [starts_with("usr_")]: j::is_integer()})
+    )
+}
+
+#[test]
+fn all_keys_requires_every_unnamed_key_to_match() -> Result<()> {
+    let pattern = j::pat!({"version": 2, [j::all_keys(starts_with("usr_"))]: j::is_object(), ..});
+    verify_that!(json!({"version": 2, "usr_1": {}, "usr_2": {}}), &pattern)?;
+    verify_that!(json!({"version": 2}), &pattern)?;
+    let result = verify_that!(json!({"version": 2, "usr_1": {}, "admin": {}}), &pattern);
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "field 'admin': key which does not start with \"usr_\""
+        )))
+    )
+}
+
+#[test]
+fn computed_key_is_still_an_exact_name() -> Result<()> {
+    let key = String::from("usr_1");
+    verify_that!(json!({"usr_1": 1}), j::pat!({[key.as_str()]: 1}))?;
+    verify_that!(json!({"usr_1": 1}), j::pat!({[&key]: 1}))?;
+    verify_that!(json!({"usr_2": 1}), not(j::pat!({[key]: 1})))
+}
+
+#[test]
+fn key_matcher_pattern_describes_entries() {
+    assert_eq!(
+        testkit::describe_no_match(&j::pat!({"v": 1, [j::all_keys(eq("a"))]: 1})),
+        "expected JSON object with fields:\n  'v': is equal to Number(1)\n  [every other key that is equal to \"a\"]: is equal to Number(1)"
+    );
+}
+
+#[test]
+fn check_reports_key_matcher_failures_per_key() {
+    let report = j::check(
+        &json!({"usr_1": {"age": 1}, "usr_2": {"age": -1}, "bot": {}}),
+        &j::pat!({[j::all_keys(starts_with("usr_"))]: {"age": gt(0)}}),
+    );
+    let pointers = report
+        .mismatches()
+        .iter()
+        .map(|m| m.pointer())
+        .collect::<Vec<_>>();
+    assert_eq!(pointers, vec!["/bot", "/usr_2/age"]);
+}
@@ -626,3 +626,48 @@ fn pat_matches_with_as_adapters() -> Result<()> {
         })
     )
 }
+
+const TENANT_KEY: &str = "tenant";
+
+#[test]
+fn pat_matches_computed_keys() -> Result<()> {
+    let id = 7;
+    let value = json!({"tenant": "acme", "x_7": {"enabled": true}, "count": 2});
+    verify_that!(
+        value,
+        j::pat!({
+            [TENANT_KEY]: "acme",
+            [format!("x_{id}")]: {"enabled": true},
+            "count": ge(1),
+        })
+    )
+}
+
+#[test]
+fn pat_computed_key_reports_missing_field() -> Result<()> {
+    let prefix = String::from("acme");
+    let result = verify_that!(
+        json!({"acme_id": 1}),
+        j::pat!({[format!("{prefix}_name")]: j::is_string(), ..})
+    );
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "field 'acme_name': was missing"
+        )))
+    )
+}
+
+#[test]
+fn pat_rejects_duplicate_keys() -> Result<()> {
+    let result = verify_that!(
+        json!({"tenant": "acme"}),
+        j::pat!({"tenant": "acme", [TENANT_KEY]: "acme"})
+    );
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "duplicate key 'tenant' in pattern"
+        )))
+    )
+}
@@ -0,0 +1,109 @@
+use googletest::Result;
+use googletest::prelude::*;
+use googletest_json_serde::json as j;
+use serde_json::json;
+
+#[test]
+fn matches_across_integer_and_float_representations() -> Result<()> {
+    verify_that!(json!(1), j::num_eq(1.0))?;
+    verify_that!(json!(1.0), j::num_eq(1))?;
+    verify_that!(
+        serde_json::from_str::<serde_json::Value>("1e0")?,
+        j::num_eq(1u8)
+    )?;
+    verify_that!(json!(-2.0), j::num_eq(-2i64))?;
+    verify_that!(json!(0.5), j::num_eq(0.5f32))
+}
+
+#[test]
+fn rejects_different_values_and_non_numbers() -> Result<()> {
+    verify_that!(json!(3.5), not(j::num_eq(3)))?;
+    verify_that!(json!(3), not(j::num_eq(3.5)))?;
+    verify_that!(json!("3"), not(j::num_eq(3)))?;
+    verify_that!(json!(null), not(j::num_eq(0)))
+}
+
+#[test]
+fn compares_large_integers_exactly() -> Result<()> {
+    verify_that!(json!(u64::MAX), j::num_eq(u64::MAX))?;
+    verify_that!(
+        json!(9007199254740993u64),
+        not(j::num_eq(9007199254740992.0))
+    )?;
+    verify_that!(json!(9007199254740992.0), j::num_eq(9007199254740992u64))
+}
+
+#[test]
+fn works_inside_patterns() -> Result<()> {
+    verify_that!(
+        json!({"qty": 2.0, "prices": [1, 2.5]}),
+        j::pat!({"qty": j::num_eq(2), "prices": [j::num_eq(1.0), j::num_eq(2.5)]})
+    )
+}
+
+#[test]
+fn honours_float_tolerance_from_options() -> Result<()> {
+    verify_that!(json!(0.1 + 0.2), not(j::num_eq(0.3)))?;
+    verify_that!(
+        json!([0.1 + 0.2]),
+        j::with_options!(
+            j::options().float_tolerance(1e-9),
+            j::elements_are![j::num_eq(0.3)]
+        )
+    )
+}
+
+#[test]
+fn explains_mismatches() -> Result<()> {
+    let result = verify_that!(json!({"qty": 2.5}), j::pat!({"qty": j::num_eq(2)}));
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "field 'qty': which isn't numerically equal to 2"
+        )))
+    )?;
+    let result = verify_that!(json!("2"), j::num_eq(2));
+    verify_that!(
+        result,
+        err(displays_as(all!(
+            contains_substring("Expected: is numerically equal to 2"),
+            contains_substring("which is a JSON string")
+        )))
+    )
+}
+
+#[test]
+#[should_panic(expected = "j::num_eq expects a finite number")]
+fn panics_on_non_finite_expectation() {
+    let _ = j::num_eq(f64::NAN);
+}
+
+#[test]
+fn coercing_options_apply_to_primitive_matchers() -> Result<()> {
+    verify_that!(json!(3.0), not(j::primitive!(eq(3))))?;
+    verify_that!(
+        json!({"count": 3.0, "limit": 65000.0}),
+        j::with_options!(
+            j::options().coercing_numbers(),
+            j::pat!({"count": j::primitive!(eq(3)), "limit": j::primitive!(eq(65000u16))})
+        )
+    )?;
+    verify_that!(
+        json!([3.5]),
+        not(j::with_options!(
+            j::options().coercing_numbers(),
+            j::elements_are![j::primitive!(eq(3))]
+        ))
+    )
+}
+
+#[test]
+fn coercing_options_compare_large_integers_exactly() -> Result<()> {
+    verify_that!(
+        json!([9007199254740993u64]),
+        not(j::with_options!(
+            j::options().coercing_numbers(),
+            j::elements_are![9007199254740992.0]
+        ))
+    )
+}
@@ -0,0 +1,191 @@
+use googletest::Result;
+use googletest::prelude::*;
+use googletest_json_serde::json as j;
+use serde_json::json;
+
+#[test]
+fn float_tolerance_applies_to_nested_literals() -> Result<()> {
+    let actual = json!({"totals": [0.1 + 0.2, 1.0 / 3.0]});
+    verify_that!(actual, not(j::pat!({"totals": [0.3, 0.333333333]})))?;
+    verify_that!(
+        actual,
+        j::with_options!(
+            j::options().float_tolerance(1e-6),
+            j::pat!({"totals": [0.3, 0.333333333]})
+        )
+    )?;
+    verify_that!(
+        actual,
+        not(j::with_options!(
+            j::options().float_tolerance(1e-12),
+            j::pat!({"totals": [0.3, 0.333333333]})
+        ))
+    )
+}
+
+#[test]
+fn coercing_numbers_equates_integers_and_floats() -> Result<()> {
+    let actual = json!({"count": 2.0, "ids": [1, 2]});
+    let pattern = j::pat!({"count": 2, "ids": j::unordered_elements_are![2.0, 1.0]});
+    verify_that!(actual, not(&pattern))?;
+    verify_that!(
+        actual,
+        j::with_options!(j::options().coercing_numbers(), pattern)
+    )?;
+    verify_that!(
+        json!([2.5]),
+        not(j::with_options!(
+            j::options().coercing_numbers(),
+            j::elements_are![2]
+        ))
+    )
+}
+
+#[test]
+fn float_tolerance_applies_to_integer_literals_against_floats() -> Result<()> {
+    verify_that!(
+        json!([1.0, 2.4]),
+        j::with_options!(j::options().float_tolerance(0.5), j::elements_are![1, 2])
+    )?;
+    verify_that!(
+        json!([1.0]),
+        not(j::with_options!(j::options(), j::elements_are![1]))
+    )?;
+    verify_that!(
+        json!([1, 3]),
+        j::with_options!(
+            j::options().float_tolerance(0.5),
+            j::elements_are![1.2, 3.0]
+        )
+    )?;
+    verify_that!(
+        json!([1]),
+        not(j::with_options!(
+            j::options().float_tolerance(0.5),
+            j::elements_are![1.6]
+        ))
+    )
+}
+
+#[test]
+fn with_options_accepts_literals_values_and_native_matchers() -> Result<()> {
+    let options = j::options().float_tolerance(1e-9);
+    verify_that!(json!(0.1 + 0.2), j::with_options!(options, 0.3))?;
+    verify_that!(
+        json!({"total": 0.1 + 0.2}),
+        j::with_options!(options, json!({"total": 0.3}))
+    )?;
+    verify_that!(json!(3), j::with_options!(options, gt(2)))
+}
+
+#[test]
+fn allowing_extra_fields_relaxes_every_nested_pattern() -> Result<()> {
+    let actual = json!({"id": 1, "etag": "x", "items": [{"sku": "A", "qty": 1}]});
+    let pattern = j::pat!({"id": 1, "items": j::elements_are![j::pat!({"sku": "A"})]});
+    verify_that!(actual, not(&pattern))?;
+    verify_that!(
+        actual,
+        j::with_options!(j::options().allowing_extra_fields(), pattern)
+    )
+}
+
+#[test]
+fn allowing_extra_fields_keeps_rest_matchers() -> Result<()> {
+    verify_that!(
+        json!({"id": 1, "extra": "x"}),
+        not(j::with_options!(
+            j::options().allowing_extra_fields(),
+            j::pat!({"id": 1, ..: j::is_number()})
+        ))
+    )
+}
+
+#[test]
+fn treating_null_as_missing_in_patterns_and_literals() -> Result<()> {
+    let actual = json!({"id": 1, "deleted_at": null, "meta": {"note": null, "v": 2}});
+    let options = j::options().treating_null_as_missing();
+    verify_that!(
+        actual,
+        j::with_options!(options, j::pat!({"id": 1, "meta": json!({"v": 2})}))
+    )?;
+    verify_that!(
+        actual,
+        j::with_options!(
+            options,
+            j::pat!({"id": 1, "deleted_at": j::absent(), "meta": j::pat!({"v": 2})})
+        )
+    )?;
+    verify_that!(
+        actual,
+        not(j::with_options!(
+            options,
+            j::pat!({"id": 1, "deleted_at": j::present_null(), ..})
+        ))
+    )
+}
+
+#[test]
+fn nested_options_merge_over_outer_ones() -> Result<()> {
+    let actual = json!({"a": {"x": 1, "y": 2}, "b": {"x": 1.0000001, "y": 2}});
+    let relaxed = j::options().allowing_extra_fields().float_tolerance(1e-6);
+    verify_that!(
+        actual,
+        j::with_options!(
+            relaxed,
+            j::pat!({
+                "a": j::pat!({"x": 1}),
+                "b": j::with_options!(j::options().coercing_numbers(), j::pat!({"x": 1})),
+            })
+        )
+    )?;
+    verify_that!(
+        actual,
+        not(j::with_options!(
+            relaxed,
+            j::pat!({
+                "a": j::pat!({"x": 1}),
+                "b": j::with_options!(j::options().float_tolerance(1e-9), j::pat!({"x": 1})),
+            })
+        ))
+    )
+}
+
+#[test]
+fn options_end_with_the_matcher() -> Result<()> {
+    let actual = json!({"id": 1, "extra": true});
+    verify_that!(
+        actual,
+        j::with_options!(j::options().allowing_extra_fields(), j::pat!({"id": 1}))
+    )?;
+    verify_that!(actual, not(j::pat!({"id": 1})))
+}
+
+#[test]
+fn description_lists_the_options() -> Result<()> {
+    let result = verify_that!(
+        json!({"price": 9.99}),
+        j::with_options!(
+            j::options().float_tolerance(0.001).coercing_numbers(),
+            j::pat!({"price": 10})
+        )
+    );
+    verify_that!(
+        result,
+        err(displays_as(all!(
+            contains_substring("(with float tolerance 0.001, integers equal to floats)"),
+            contains_substring("field 'price': which isn't equal to Number(10)")
+        )))
+    )
+}
+
+#[test]
+fn check_honours_options() {
+    let actual = json!({"items": [{"sku": "A", "qty": 1}, {"sku": "B"}]});
+    let matcher = j::with_options!(
+        j::options().allowing_extra_fields(),
+        j::pat!({"items": j::each!(j::pat!({"qty": 1}))}),
+    );
+    let report = j::check(&actual, &matcher);
+    assert_eq!(report.mismatches().len(), 1);
+    assert_eq!(report.mismatches()[0].pointer(), "/items/1/qty");
+}
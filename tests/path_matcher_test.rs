@@ -130,3 +130,125 @@ fn has_only_paths_explains_non_object() -> Result<()> {
         err(displays_as(contains_substring("which is a JSON null")))
     )
 }
+
+#[test]
+fn has_paths_supports_wildcards() -> Result<()> {
+    verify_that!(
+        json!({"items": [{"id": 1}], "meta": {"deep": {"version": 2}}}),
+        j::has_paths(&["items.*.id", "**.version"])
+    )
+}
+
+#[test]
+fn has_paths_reports_unmatched_wildcard_pattern() -> Result<()> {
+    let result = verify_that!(json!({"items": []}), j::has_paths(&["items.*.id"]));
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "missing paths [\"items.*.id\"]"
+        )))
+    )
+}
+
+#[test]
+fn has_only_paths_supports_wildcards() -> Result<()> {
+    verify_that!(
+        json!({"ids": [1, 2, 3], "ok": true}),
+        j::has_only_paths(&["ids", "ids.*", "ok"])
+    )
+}
+
+#[test]
+fn has_only_paths_with_recursive_wildcard_covers_subtree() -> Result<()> {
+    verify_that!(
+        json!({"user": {"id": 1, "address": {"city": "x"}}}),
+        j::has_only_paths(&["user", "user.**"])
+    )
+}
+
+#[test]
+fn has_only_paths_reports_extra_paths_outside_wildcards() -> Result<()> {
+    let result = verify_that!(
+        json!({"ids": [1], "extra": true}),
+        j::has_only_paths(&["ids", "ids.*"])
+    );
+    verify_that!(
+        result,
+        err(displays_as(contains_substring("extra paths [\"extra\"]")))
+    )
+}
+
+#[test]
+fn has_paths_accepts_json_pointers() -> Result<()> {
+    verify_that!(
+        json!({"user": {"addresses": [{"zip": "1"}]}}),
+        j::has_paths(&["/user/addresses/0/zip", "user.addresses"])
+    )
+}
+
+#[test]
+fn has_paths_accepts_explicit_pointers() -> Result<()> {
+    verify_that!(
+        json!({"a/b": {"c": 1}}),
+        j::has_paths(&[j::pointer("/a~1b"), j::pointer("/a~1b/c")])
+    )
+}
+
+#[test]
+fn numeric_pointer_tokens_select_object_keys() -> Result<()> {
+    let value = json!({"items": {"42": 1}, "list": [{"0": true}]});
+    verify_that!(value, j::has_paths(&["/items/42", "/list/0/0"]))?;
+    verify_that!(
+        value,
+        j::has_only_paths(&["/items", "/items/42", "/list", "/list/0", "/list/0/0"])
+    )?;
+    verify_that!(value, not(j::has_paths(&["/items/042"])))
+}
+
+#[test]
+fn has_paths_reports_missing_pointer_in_pointer_syntax() -> Result<()> {
+    let result = verify_that!(json!({"user": {}}), j::has_paths(&["/user/a~1b", "user"]));
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "missing paths [\"/user/a~1b\"]"
+        )))
+    )
+}
+
+#[test]
+fn has_only_paths_reports_extra_paths_in_pointer_syntax() -> Result<()> {
+    let result = verify_that!(json!({"id": 1, "x.y": true}), j::has_only_paths(&["/id"]));
+    verify_that!(
+        result,
+        err(displays_as(contains_substring("extra paths [\"/x.y\"]")))
+    )
+}
+
+#[test]
+fn has_only_paths_reports_extra_paths_in_dot_notation_when_syntaxes_mix() -> Result<()> {
+    let actual = json!({"id": 1, "name": "x", "meta": {"etag": "y"}});
+    for paths in [["/id", "name"], ["name", "/id"]] {
+        let result = verify_that!(actual, j::has_only_paths(&paths));
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(
+                "extra paths [\"meta\", \"meta.etag\"]"
+            )))
+        )?;
+    }
+    Ok(())
+}
+
+#[test]
+fn empty_path_lists_need_the_path_type_spelled_out() -> Result<()> {
+    verify_that!(json!({"id": 1}), j::has_paths::<&str>(&[]))?;
+    verify_that!(json!({}), j::has_only_paths::<&str>(&[]))?;
+    verify_that!(json!({"id": 1}), not(j::has_only_paths::<&str>(&[])))
+}
+
+#[test]
+fn has_paths_accepts_owned_strings() -> Result<()> {
+    let paths = vec![String::from("id"), String::from("/name")];
+    verify_that!(json!({"id": 1, "name": "x"}), j::has_paths(&paths))
+}
@@ -0,0 +1,117 @@
+use googletest::Result;
+use googletest::prelude::*;
+use googletest_json_serde::json as j;
+use serde_json::json;
+
+#[test]
+fn absent_accepts_only_missing_fields() -> Result<()> {
+    let pattern = j::pat!({"id": 1, "deleted_at": j::absent()});
+    verify_that!(json!({"id": 1}), &pattern)?;
+    verify_that!(json!({"id": 1, "deleted_at": null}), not(&pattern))?;
+    verify_that!(json!({"id": 1, "deleted_at": "2024"}), not(&pattern))
+}
+
+#[test]
+fn absent_explains_present_field() -> Result<()> {
+    let result = verify_that!(
+        json!({"deleted_at": null}),
+        j::pat!({"deleted_at": j::absent()})
+    );
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "field 'deleted_at': which is present"
+        )))
+    )
+}
+
+#[test]
+fn present_null_requires_field_and_null() -> Result<()> {
+    let pattern = j::pat!({"nickname": j::present_null()});
+    verify_that!(json!({"nickname": null}), &pattern)?;
+    verify_that!(json!({"nickname": "Bob"}), not(&pattern))?;
+    let result = verify_that!(json!({}), &pattern);
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "field 'nickname': was missing"
+        )))
+    )
+}
+
+#[test]
+fn nullable_requires_presence() -> Result<()> {
+    let pattern = j::pat!({"nickname": j::nullable!(starts_with("B"))});
+    verify_that!(json!({"nickname": null}), &pattern)?;
+    verify_that!(json!({"nickname": "Bob"}), &pattern)?;
+    verify_that!(json!({"nickname": "Al"}), not(&pattern))?;
+    let result = verify_that!(json!({}), &pattern);
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "field 'nickname': was missing"
+        )))
+    )
+}
+
+#[test]
+fn missing_or_rejects_null() -> Result<()> {
+    let pattern = j::pat!({"email": j::missing_or!(j::is_string())});
+    verify_that!(json!({}), &pattern)?;
+    verify_that!(json!({"email": "a@b.c"}), &pattern)?;
+    let result = verify_that!(json!({"email": null}), &pattern);
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "field 'email': which is null"
+        )))
+    )
+}
+
+#[test]
+fn missing_or_explains_inner_mismatch() -> Result<()> {
+    let result = verify_that!(
+        json!({"email": 3}),
+        j::pat!({"email": j::missing_or!(j::is_string())})
+    );
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "field 'email': which is a JSON number"
+        )))
+    )
+}
+
+#[test]
+fn presence_matchers_describe_allowed_states() -> Result<()> {
+    verify_that!(json!(1), not(j::nullable!(j::is_string())))?;
+    let result = verify_that!(json!(null), j::missing_or!(j::is_string()));
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "Expected: is absent or a JSON string"
+        )))
+    )
+}
+
+#[test]
+fn check_reports_null_for_missing_or() {
+    let report = j::check(
+        &json!({"patch": {"email": null, "name": "x"}}),
+        &j::pat!({"patch": {"email": j::missing_or!(j::is_string()), "name": j::nullable!(j::is_string())}}),
+    );
+    assert_eq!(report.mismatches().len(), 1);
+    assert_eq!(report.mismatches()[0].pointer(), "/patch/email");
+    assert_eq!(report.mismatches()[0].explanation(), "which is null");
+}
+
+#[test]
+fn missing_or_negation_describes_present_values() -> Result<()> {
+    let result = verify_that!(json!(1), not(j::missing_or!(j::is_number())));
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "Expected: is present and which is not a JSON number"
+        )))
+    )
+}
@@ -0,0 +1,126 @@
+use googletest::Result;
+use googletest::prelude::*;
+use googletest_json_serde::json as j;
+use indoc::indoc;
+
+#[test]
+fn matches_json_text() -> Result<()> {
+    let body = r#"{"id": 7, "name": "Ada"}"#;
+    verify_that!(
+        body,
+        j::parsed(j::pat!({"id": 7, "name": starts_with("A")}))
+    )?;
+    verify_that!(body.to_string(), j::parsed(j::pat!({"id": 7, ..})))?;
+    verify_that!("[1, 2, 3]", j::parsed(j::elements_are![1, 2, 3]))?;
+    verify_that!("null", j::parsed(j::is_null()))
+}
+
+#[test]
+fn matches_json_bytes() -> Result<()> {
+    let body = br#"{"id": 7}"#;
+    verify_that!(body, j::parsed(j::pat!({"id": 7})))?;
+    verify_that!(body.as_slice(), j::parsed(j::pat!({"id": 7})))?;
+    verify_that!(body.to_vec(), j::parsed(j::pat!({"id": j::is_integer()})))
+}
+
+#[test]
+fn explains_mismatches_of_the_parsed_value() -> Result<()> {
+    let result = verify_that!(r#"{"id": "7"}"#, j::parsed(j::pat!({"id": 7})));
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "field 'id': which isn't equal to Number(7)"
+        )))
+    )
+}
+
+#[test]
+fn invalid_json_does_not_match() -> Result<()> {
+    verify_that!(r#"{"id": 7"#, not(j::parsed(j::pat!({"id": 7}))))?;
+    verify_that!(b"\xff", not(j::parsed(j::is_not_null())))
+}
+
+#[test]
+fn parse_error_shows_location_and_caret() -> Result<()> {
+    let result = verify_that!(r#"{"id" 7}"#, j::parsed(j::pat!({"id": 7})));
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(indoc! {r#"
+            which is not valid JSON: expected `:` at line 1, column 7
+                {"id" 7}
+                      ^
+        "#})))
+    )
+}
+
+#[test]
+fn parse_error_points_into_the_offending_line() -> Result<()> {
+    let body = indoc! {r#"
+        {
+          "id": 7,
+          "name": "Ada"
+          "role": "admin"
+        }
+    "#};
+    let result = verify_that!(body, j::parsed(j::pat!({"id": 7, ..})));
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(indoc! {r#"
+            which is not valid JSON: expected `,` or `}` at line 4, column 3
+                  "role": "admin"
+                  ^
+        "#})))
+    )
+}
+
+#[test]
+fn parse_error_snippet_is_shortened_around_the_column() -> Result<()> {
+    let body = format!("[{}oops, {}2]", "1, ".repeat(30), "2, ".repeat(30));
+    let result = verify_that!(body, j::parsed(j::is_array()));
+    verify_that!(
+        result,
+        err(displays_as(all!(
+            contains_substring("which is not valid JSON: expected value at line 1, column 92"),
+            contains_substring(format!(
+                "\n    ... {}oops, {}2...\n",
+                "1, ".repeat(13),
+                "2, ".repeat(11)
+            )),
+            contains_substring(format!("\n    {}^", " ".repeat(43)))
+        )))
+    )
+}
+
+#[test]
+fn parse_error_on_empty_input() -> Result<()> {
+    let result = verify_that!("", j::parsed(j::is_object()));
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "which is not valid JSON: EOF while parsing a value at line 1, column 0"
+        )))
+    )
+}
+
+#[test]
+fn parsed_describes_the_inner_matcher() -> Result<()> {
+    let result = verify_that!("42", j::parsed(j::is_string()));
+    verify_that!(
+        result,
+        err(displays_as(all!(
+            contains_substring("Expected: a JSON string"),
+            contains_substring("which is a JSON number")
+        )))
+    )
+}
+
+#[cfg(feature = "bytes")]
+#[test]
+fn matches_bytes_buffers() -> Result<()> {
+    let body = bytes::Bytes::from_static(br#"{"id": 7}"#);
+    verify_that!(body, j::parsed(j::pat!({"id": 7})))?;
+    verify_that!(
+        bytes::BytesMut::from(&body[..]),
+        j::parsed(j::pat!({"id": 7}))
+    )
+}
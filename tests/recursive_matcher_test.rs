@@ -0,0 +1,124 @@
+use googletest::Result;
+use googletest::prelude::*;
+use googletest_json_serde::extension::JsonMatcher;
+use googletest_json_serde::json as j;
+use serde_json::{Value, json};
+
+fn category() -> impl JsonMatcher + Clone {
+    j::recursive(|node| j::pat!({"id": j::is_integer(), "children": j::each!(node), ..}))
+}
+
+fn nested(levels: usize) -> Value {
+    (0..levels).fold(
+        json!({"id": 0, "children": []}),
+        |child, id| json!({"id": id + 1, "children": [child]}),
+    )
+}
+
+#[test]
+fn recursive_matches_trees_of_any_shape() -> Result<()> {
+    let tree = json!({
+        "id": 1,
+        "name": "root",
+        "children": [
+            {"id": 2, "children": []},
+            {"id": 3, "children": [{"id": 4, "children": []}]}
+        ]
+    });
+    verify_that!(tree, category())?;
+    verify_that!(json!({"id": 1, "children": []}), category())
+}
+
+#[test]
+fn recursive_explains_the_failing_nested_path() -> Result<()> {
+    let tree = json!({
+        "id": 1,
+        "children": [{"id": 2, "children": [{"id": "3", "children": []}]}]
+    });
+    let result = verify_that!(tree, category());
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "field 'children': element #0 ({\"children\":[],\"id\":\"3\"}) did not match: field 'id': which is a JSON string"
+        )))
+    )
+}
+
+#[test]
+fn check_reports_nested_mismatch_path() {
+    let tree = json!({
+        "id": 1,
+        "children": [{"id": 2, "children": [{"id": 3}]}]
+    });
+    let report = j::check(&tree, &category());
+    assert_eq!(report.mismatches().len(), 1);
+    assert_eq!(
+        report.mismatches()[0].pointer(),
+        "/children/0/children/0/children"
+    );
+}
+
+#[test]
+fn max_depth_limits_nesting() -> Result<()> {
+    let limited = j::recursive(|node| j::pat!({"id": j::is_integer(), "children": j::each!(node)}))
+        .max_depth(3);
+    verify_that!(nested(3), &limited)?;
+    let result = verify_that!(nested(4), &limited);
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "which is nested more than 3 levels deep, the maximum depth"
+        )))
+    )
+}
+
+#[test]
+fn max_depth_does_not_affect_clones() -> Result<()> {
+    let unlimited =
+        j::recursive(|node| j::pat!({"id": j::is_integer(), "children": j::each!(node)}));
+    let limited = unlimited.clone().max_depth(1);
+    verify_that!(nested(5), not(&limited))?;
+    verify_that!(nested(5), &unlimited)
+}
+
+#[test]
+fn default_depth_limit_prevents_stack_overflow() -> Result<()> {
+    let deep = (0..200).fold(json!([]), |inner, _| json!([inner]));
+    verify_that!(deep, not(j::recursive(|node| j::each!(node))))?;
+    verify_that!(
+        (0..100).fold(json!([]), |inner, _| json!([inner])),
+        j::recursive(|node| j::each!(node))
+    )
+}
+
+#[test]
+fn recursive_handle_can_be_used_more_than_once() -> Result<()> {
+    let expr = j::recursive(|node| {
+        j::pat!({
+            "op": "add",
+            "left": j::optional!(node.clone()),
+            "right": j::optional!(node)
+        })
+    });
+    verify_that!(
+        json!({"op": "add", "left": {"op": "add"}, "right": {"op": "add", "left": {"op": "add"}}}),
+        &expr
+    )?;
+    verify_that!(json!({"op": "add", "left": {"op": "sub"}}), not(&expr))
+}
+
+#[test]
+fn recursive_matcher_is_send_and_sync() {
+    fn assert_send_sync<T: Send + Sync>(_: &T) {}
+    assert_send_sync(&j::recursive(|node| j::each!(node)));
+}
+
+#[test]
+fn recursive_description_refers_back_to_itself() {
+    let description = googletest_json_serde::extension::testkit::describe_no_match(&category());
+    assert!(
+        description
+            .contains("'children': JSON array where each element matches the recursive pattern"),
+        "{description}"
+    );
+}
@@ -0,0 +1,84 @@
+use googletest::Result;
+use googletest::prelude::*;
+use googletest_json_serde::extension::testkit;
+use googletest_json_serde::json as j;
+use serde_json::json;
+
+#[test]
+fn rest_matcher_constrains_unlisted_fields() -> Result<()> {
+    let pattern = j::pat!({"id": 1, .. : j::is_string()});
+    verify_that!(json!({"id": 1}), &pattern)?;
+    verify_that!(json!({"id": 1, "a": "x", "b": "y"}), &pattern)?;
+    verify_that!(json!({"id": 1, "a": "x", "b": null}), not(&pattern))
+}
+
+#[test]
+fn rest_matcher_does_not_apply_to_listed_fields() -> Result<()> {
+    verify_that!(
+        json!({"id": 1, "name": "x"}),
+        j::pat!({"id": gt(0), .. : j::is_string()})
+    )
+}
+
+#[test]
+fn rest_matcher_accepts_nested_patterns() -> Result<()> {
+    verify_that!(
+        json!({"kind": "map", "a": {"v": 1}, "b": {"v": 2, "w": 0}}),
+        j::pat!({"kind": "map", .. : {"v": gt(0), ..}})
+    )?;
+    verify_that!(json!({"a": [1, 2], "b": [1]}), j::pat!({.. : [1, ..]}))
+}
+
+#[test]
+fn rest_matcher_explains_failing_fields() -> Result<()> {
+    let result = verify_that!(
+        json!({"id": 1, "a": "x", "b": null}),
+        j::pat!({"id": 1, .. : j::is_not_null()})
+    );
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "field 'b': which is a JSON null"
+        )))
+    )
+}
+
+#[test]
+fn rest_matcher_skips_keys_covered_by_key_matchers() -> Result<()> {
+    verify_that!(
+        json!({"id": 1, "x-trace": "abc", "count": 2}),
+        j::pat!({"id": 1, [starts_with("x-")]: j::is_string(), .. : j::is_integer()})
+    )
+}
+
+#[test]
+fn extra_fields_must_have_prefix() -> Result<()> {
+    let pattern = j::pat!({"id": 1, [j::all_keys(starts_with("x-"))]: j::is_number()});
+    verify_that!(json!({"id": 1, "x-a": 1, "x-b": 2}), &pattern)?;
+    verify_that!(json!({"id": 1, "y-a": 1}), not(&pattern))
+}
+
+#[test]
+fn rest_macro_matches_every_field() -> Result<()> {
+    verify_that!(json!({}), j::rest!(j::is_string()))?;
+    verify_that!(json!({"a": "x"}), j::rest!(starts_with("x")))?;
+    verify_that!(json!(["x"]), not(j::rest!(j::is_string())))
+}
+
+#[test]
+fn rest_macro_describes_constraint() {
+    assert_eq!(
+        testkit::describe_no_match(&j::pat!({"id": 1, .. : j::is_string()})),
+        "expected JSON object with fields:\n  'id': is equal to Number(1)\n  ..: a JSON string"
+    );
+}
+
+#[test]
+fn check_reports_rest_failures_at_field_path() {
+    let report = j::check(
+        &json!({"meta": {"id": 1, "tag": 2}}),
+        &j::pat!({"meta": {"id": 1, .. : j::is_string()}}),
+    );
+    assert_eq!(report.mismatches().len(), 1);
+    assert_eq!(report.mismatches()[0].pointer(), "/meta/tag");
+}
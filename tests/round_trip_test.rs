@@ -0,0 +1,120 @@
+use googletest::Result;
+use googletest::prelude::*;
+use googletest_json_serde::json as j;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+#[derive(Debug, Deserialize, Serialize)]
+struct Profile {
+    bio: String,
+    #[serde(default)]
+    tags: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct User {
+    id: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nickname: Option<String>,
+    profile: Profile,
+}
+
+#[test]
+fn round_trips_accepts_stable_documents() -> Result<()> {
+    verify_that!(
+        json!({"id": 7, "nickname": "ada", "profile": {"bio": "hi", "tags": ["x"]}}),
+        j::round_trips::<User>()
+    )
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct Price {
+    amount: f64,
+}
+
+#[test]
+fn round_trips_compares_numbers_by_value() -> Result<()> {
+    verify_that!(json!({"amount": 10}), j::round_trips::<Price>())?;
+    verify_that!(json!({"amount": 10.5}), j::round_trips::<Price>())
+}
+
+#[test]
+fn round_trips_lists_lossy_paths() -> Result<()> {
+    let result = verify_that!(
+        json!({"id": 7, "role": "admin", "profile": {"bio": "hi"}}),
+        j::round_trips::<User>()
+    );
+    verify_that!(
+        result,
+        err(displays_as(all!(
+            contains_substring("round-trips through round_trip_test::User unchanged"),
+            contains_substring(
+                "which changes after a round trip through round_trip_test::User at 2 paths:"
+            ),
+            contains_substring("added `profile.tags`: actual []"),
+            contains_substring("removed `role`: expected \"admin\"")
+        )))
+    )
+}
+
+#[test]
+fn round_trips_explains_deserialize_failure() -> Result<()> {
+    let result = verify_that!(
+        json!({"id": 7, "profile": {"bio": 3}}),
+        j::round_trips::<User>()
+    );
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "which fails to deserialize as round_trip_test::User at `profile.bio`"
+        )))
+    )
+}
+
+#[test]
+fn round_trips_can_ignore_paths() -> Result<()> {
+    let value = json!({"id": 7, "meta": {"etag": "x"}, "profile": {"bio": "hi", "tags": []}});
+    verify_that!(value, not(j::round_trips::<User>()))?;
+    verify_that!(value, j::round_trips::<User>().ignoring_paths(&["meta"]))?;
+    verify_that!(
+        json!({"id": 7, "profile": {"bio": "hi"}}),
+        j::round_trips::<User>().ignoring_paths(&["**.tags"])
+    )
+}
+
+#[test]
+fn round_trips_reports_invalid_ignored_paths() -> Result<()> {
+    let result = verify_that!(
+        json!({"id": 7, "profile": {"bio": "hi", "tags": []}}),
+        j::round_trips::<User>().ignoring_paths(&["meta\\"])
+    );
+    verify_that!(
+        result,
+        err(displays_as(contains_substring("invalid paths")))
+    )
+}
+
+#[test]
+fn round_trips_can_treat_null_as_missing() -> Result<()> {
+    let value = json!({"id": 7, "nickname": null, "profile": {"bio": "hi", "tags": []}});
+    let result = verify_that!(value, j::round_trips::<User>());
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "removed `nickname`: expected null"
+        )))
+    )?;
+    verify_that!(value, j::round_trips::<User>().treating_null_as_missing())
+}
+
+#[test]
+fn check_reports_each_lossy_path() {
+    let report = j::check(
+        &json!({"users": [{"id": 7, "extra": 1, "profile": {"bio": "hi", "tags": []}}]}),
+        &j::pat!({"users": j::each!(j::round_trips::<User>())}),
+    );
+    assert_eq!(report.mismatches().len(), 1);
+    let mismatch = &report.mismatches()[0];
+    assert_eq!(mismatch.pointer(), "/users/0/extra");
+    assert_eq!(mismatch.actual(), Some("1"));
+}
@@ -0,0 +1,67 @@
+use googletest::Result;
+use googletest::prelude::*;
+use googletest_json_serde::json as j;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct User {
+    user_id: u64,
+    display_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nickname: Option<String>,
+}
+
+fn ada() -> User {
+    User {
+        user_id: 7,
+        display_name: "Ada".to_string(),
+        nickname: None,
+    }
+}
+
+#[test]
+fn serialized_checks_renames_and_skipped_fields() -> Result<()> {
+    verify_that!(
+        ada(),
+        j::serialized(j::pat!({
+            "userId": 7,
+            "displayName": "Ada",
+            "nickname": j::absent()
+        }))
+    )
+}
+
+#[test]
+fn serialized_explains_json_form_and_mismatch() -> Result<()> {
+    let result = verify_that!(ada(), j::serialized(j::pat!({"user_id": 7, ..})));
+    verify_that!(
+        result,
+        err(displays_as(all!(
+            contains_substring("serializes to JSON which"),
+            contains_substring(r#"which serializes to {"displayName":"Ada","userId":7}"#),
+            contains_substring("field 'user_id': was missing")
+        )))
+    )
+}
+
+#[test]
+fn serialized_explains_serialization_failure() -> Result<()> {
+    let mut map = BTreeMap::new();
+    map.insert((1, 2), "tuple keys are not valid JSON object keys");
+    let result = verify_that!(map, j::serialized(j::is_object()));
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "which fails to serialize to JSON: key must be a string"
+        )))
+    )
+}
+
+#[test]
+fn serialized_accepts_any_json_matcher() -> Result<()> {
+    verify_that!(vec![1, 2, 3], j::serialized(j::len!(3)))?;
+    verify_that!("hi", j::serialized(j::is_string()))?;
+    verify_that!(ada(), j::serialized(j::has_paths(&["userId"])))
+}
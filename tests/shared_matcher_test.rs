@@ -0,0 +1,107 @@
+use googletest::Result;
+use googletest::prelude::*;
+use googletest_json_serde::extension::{JsonMatcher, JsonObjectMatcher};
+use googletest_json_serde::json as j;
+use serde_json::json;
+use std::sync::LazyLock;
+
+static ADDRESS: LazyLock<JsonObjectMatcher> =
+    LazyLock::new(|| j::pat!({"city": matches_regex("S.*"), "zip": j::is_string(), ..}));
+
+fn assert_shareable<M: JsonMatcher + Clone + Send + Sync>(_: &M) {}
+
+fn assert_send_sync<M: JsonMatcher + Send + Sync>(_: &M) {}
+
+fn assert_cloneable<M: JsonMatcher + Clone>(_: &M) {}
+
+#[test]
+fn matchers_are_send_sync_and_clone() {
+    assert_shareable(&j::pat!({"a": 1, "b": j::each!(j::is_string())}));
+    assert_shareable(&j::elements_are![eq(1), j::is_null()]);
+    assert_shareable(&j::unordered_elements_are![1, 2]);
+    assert_shareable(&j::each!(gt(0)));
+    assert_shareable(&j::optional!(j::is_number()));
+    assert_shareable(&j::has_path_with!("a.b", 1));
+    assert_shareable(&j::at!("$..id", j::len!(2)));
+    assert_shareable(&j::predicate(|v| v.is_i64()));
+    assert_shareable(&j::has_paths(&["a"]));
+    assert_send_sync(&j::as_string(eq("x")));
+    assert_send_sync(&j::primitive!(gt(1)));
+}
+
+#[test]
+fn pattern_with_string_matchers_is_clone() {
+    assert_cloneable(
+        &j::pat!({"name": starts_with("A"), "tags": j::as_array(contains(j::as_string(eq("x"))))}),
+    );
+    assert_cloneable(&j::elements_are![ends_with("z")]);
+    assert_cloneable(&j::pat!({[starts_with("x-")]: j::is_string(), "id": 1}));
+}
+
+#[test]
+fn pattern_with_string_matchers_matches() -> Result<()> {
+    let pattern = j::pat!({"name": starts_with("A"), "nick": not(contains_substring("x")), ..});
+    verify_that!(json!({"name": "Ada", "nick": "ad"}), &pattern)?;
+    verify_that!(json!({"name": "Bob", "nick": "bo"}), not(&pattern))
+}
+
+#[test]
+fn pattern_with_thread_safe_string_matchers_matches_on_other_threads() -> Result<()> {
+    let pattern = j::pat!({"name": matches_regex("A.*"), "nick": not(matches_regex(".*x.*")), ..});
+    std::thread::scope(|scope| {
+        scope
+            .spawn(|| verify_that!(json!({"name": "Ada", "nick": "ad"}), &pattern))
+            .join()
+            .unwrap()
+    })?;
+    verify_that!(json!({"name": "Bob", "nick": "bo"}), not(&pattern))
+}
+
+#[test]
+fn static_pattern_is_reused_inside_other_patterns() -> Result<()> {
+    let actual = json!({
+        "billing": {"city": "Springfield", "zip": "12345"},
+        "shipping": {"city": "Shelbyville", "zip": "54321", "note": "back door"}
+    });
+    verify_that!(
+        actual,
+        j::pat!({"billing": &*ADDRESS, "shipping": &*ADDRESS})
+    )?;
+    verify_that!(json!({"city": "Ogdenville", "zip": "1"}), not(&*ADDRESS))
+}
+
+#[test]
+fn cloned_matcher_behaves_like_the_original() -> Result<()> {
+    let original = j::pat!({"id": gt(0), "tags": j::each!(j::is_string())});
+    let copy = original.clone();
+    let actual = json!({"id": 3, "tags": ["a"]});
+    verify_that!(actual, original)?;
+    verify_that!(actual, copy)?;
+    verify_that!(json!({"id": 0, "tags": []}), not(ADDRESS.clone()))
+}
+
+#[test]
+fn static_pattern_is_shared_across_threads() {
+    std::thread::scope(|scope| {
+        for i in 0..4 {
+            scope.spawn(move || {
+                let actual = json!({"city": format!("Shire {i}"), "zip": "0000"});
+                assert_that!(actual, &*ADDRESS);
+            });
+        }
+    });
+}
+
+#[test]
+fn shared_pattern_reports_failures_at_each_use() -> Result<()> {
+    let result = verify_that!(
+        json!({"billing": {"city": "Springfield", "zip": 12345}}),
+        j::pat!({"billing": &*ADDRESS})
+    );
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "field 'zip': which is a JSON number"
+        )))
+    )
+}
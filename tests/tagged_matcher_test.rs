@@ -0,0 +1,141 @@
+use googletest::Result;
+use googletest::prelude::*;
+use googletest_json_serde::json as j;
+use serde_json::json;
+
+#[test]
+fn tagged_dispatches_on_discriminator() -> Result<()> {
+    let event = j::tagged!("type", {
+        "created" => j::pat!({"type": "created", "id": j::is_integer(), "name": j::is_string()}),
+        "deleted" => j::pat!({"id": j::is_integer(), ..}),
+    });
+    verify_that!(json!({"type": "created", "id": 1, "name": "Ada"}), &event)?;
+    verify_that!(json!({"type": "deleted", "id": 2}), &event)?;
+    verify_that!(json!({"type": "deleted", "id": "2"}), not(&event))?;
+    verify_that!(json!({"type": "created", "id": 1}), not(&event))
+}
+
+#[test]
+fn tagged_explains_selected_branch_failure() -> Result<()> {
+    let result = verify_that!(
+        json!({"type": "deleted", "id": "2"}),
+        j::tagged!("type", {
+            "created" => j::pat!({"name": j::is_string(), ..}),
+            "deleted" => j::pat!({"id": j::is_integer(), ..}),
+        })
+    );
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "field 'id': which is a JSON string"
+        )))
+    )
+}
+
+#[test]
+fn tagged_reports_tag_without_branch() -> Result<()> {
+    let result = verify_that!(
+        json!({"type": "renamed"}),
+        j::tagged!("type", {
+            "created" => j::pat!({..}),
+            "deleted" => j::pat!({..}),
+        })
+    );
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "field 'type': 'renamed' has no branch, expected one of 'created', 'deleted'"
+        )))
+    )
+}
+
+#[test]
+fn tagged_reports_missing_or_non_string_tag() -> Result<()> {
+    let event = j::tagged!("kind", { "a" => j::pat!({..}) });
+    let result = verify_that!(json!({"id": 1}), &event);
+    verify_that!(
+        result,
+        err(displays_as(contains_substring("field 'kind': was missing")))
+    )?;
+    let result = verify_that!(json!({"kind": 1}), &event);
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "field 'kind': was 1 (expected string)"
+        )))
+    )?;
+    verify_that!(json!([1]), not(&event))
+}
+
+#[test]
+fn known_variants_requires_every_branch() -> Result<()> {
+    let result = verify_that!(
+        json!({"type": "created"}),
+        j::tagged!("type", { "created" => j::pat!({..}) })
+            .known_variants(&["created", "deleted", "archived"])
+    );
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "which has no branches for known variants 'deleted', 'archived'"
+        )))
+    )
+}
+
+#[test]
+fn known_variants_reports_unknown_tags() -> Result<()> {
+    let event = j::tagged!("type", {
+        "created" => j::pat!({..}),
+        "deleted" => j::pat!({..}),
+    })
+    .known_variants(&["created", "deleted"]);
+    verify_that!(json!({"type": "deleted"}), &event)?;
+    let result = verify_that!(json!({"type": "exploded"}), &event);
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "field 'type': 'exploded' is an unknown variant, expected one of 'created', 'deleted'"
+        )))
+    )
+}
+
+#[test]
+fn tagged_rejects_duplicate_tags() -> Result<()> {
+    let event = j::tagged!("type", {
+        "created" => j::pat!({..}),
+        "deleted" => j::pat!({..}),
+        "created" => j::pat!({"id": 1, ..}),
+    });
+    let result = verify_that!(json!({"type": "deleted"}), &event);
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "duplicate branch for tag 'created'"
+        )))
+    )
+}
+
+#[test]
+fn tagged_describes_branches() {
+    let event = j::tagged!("type", { "created" => j::is_object() });
+    let description = googletest_json_serde::extension::testkit::describe_no_match(&event);
+    assert!(
+        description.contains("tagged by field 'type'") && description.contains("'created': "),
+        "{description}"
+    );
+}
+
+#[test]
+fn check_reports_tag_and_branch_paths() {
+    let event = j::tagged!("type", { "created" => j::pat!({"type": "created", "id": 1}) });
+    let report = j::check(
+        &json!({"events": [{"type": "created", "id": 2}, {"type": "nope"}]}),
+        &j::pat!({"events": j::each!(event)}),
+    );
+    let pointers = report
+        .mismatches()
+        .iter()
+        .map(|m| m.pointer())
+        .collect::<Vec<_>>();
+    assert_eq!(pointers, ["/events/0/id", "/events/1/type"]);
+}